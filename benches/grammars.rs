@@ -0,0 +1,149 @@
+//! Benchmarks for a set of canonical grammars, intended to catch performance regressions in common combinators.
+//!
+//! See `benches/json.rs` for a JSON grammar compared against other parser libraries.
+
+use chumsky::{formats, prelude::*};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+mod utils;
+
+fn arithmetic<'src, E: extra::ParserExtra<'src, &'src str>>(
+) -> impl Parser<'src, &'src str, i64, E> + Clone {
+    recursive(|expr| {
+        let atom = text::int(10)
+            .from_str::<i64>()
+            .unwrapped()
+            .or(expr.delimited_by(just('('), just(')')))
+            .padded();
+
+        let unary = just('-')
+            .padded()
+            .repeated()
+            .foldr(atom, |_, x: i64| x.wrapping_neg());
+
+        let product = unary.clone().foldl(
+            choice((just('*'), just('/'))).then(unary).repeated(),
+            |a, (op, b)| match op {
+                '*' => a.wrapping_mul(b),
+                _ => a.checked_div(b).unwrap_or(0),
+            },
+        );
+
+        product.clone().foldl(
+            choice((just('+'), just('-'))).then(product).repeated(),
+            |a, (op, b)| match op {
+                '+' => a.wrapping_add(b),
+                _ => a.wrapping_sub(b),
+            },
+        )
+    })
+}
+
+#[derive(Debug)]
+#[allow(dead_code)]
+enum Stmt<'src> {
+    Let(&'src str, Expr<'src>),
+    Print(Expr<'src>),
+}
+
+#[derive(Debug)]
+#[allow(dead_code)]
+enum Expr<'src> {
+    Num(&'src str),
+    Var(&'src str),
+    Call(&'src str, Vec<Expr<'src>>),
+    Add(Box<Expr<'src>>, Box<Expr<'src>>),
+}
+
+fn toy_language<'src, E: extra::ParserExtra<'src, &'src str>>(
+) -> impl Parser<'src, &'src str, Vec<Stmt<'src>>, E> {
+    let ident = text::ascii::ident().padded();
+
+    let expr = recursive(|expr| {
+        let call = ident
+            .then(
+                expr.clone()
+                    .separated_by(just(','))
+                    .allow_trailing()
+                    .collect()
+                    .delimited_by(just('('), just(')')),
+            )
+            .map(|(f, args)| Expr::Call(f, args));
+
+        let atom = text::int(10)
+            .padded()
+            .map(Expr::Num)
+            .or(call)
+            .or(ident.map(Expr::Var))
+            .or(expr.delimited_by(just('('), just(')')).padded());
+
+        atom.clone()
+            .foldl(just('+').ignore_then(atom).repeated(), |a, b| {
+                Expr::Add(Box::new(a), Box::new(b))
+            })
+    });
+
+    let stmt = text::ascii::keyword("let")
+        .padded()
+        .ignore_then(ident)
+        .then_ignore(just('='))
+        .then(expr.clone())
+        .map(|(name, e)| Stmt::Let(name, e))
+        .or(text::ascii::keyword("print")
+            .padded()
+            .ignore_then(expr)
+            .map(Stmt::Print))
+        .then_ignore(just(';').padded());
+
+    stmt.repeated().collect()
+}
+
+fn bench_grammars(c: &mut Criterion) {
+    let arith_src = "(1 + 2 * -3) / (4 - 5) + ".repeat(500) + "0";
+    let toy_src = "let x = foo(1, bar(2, 3), y + 4,); print x + (y + z);\n".repeat(500);
+
+    let mut group = c.benchmark_group("grammars");
+
+    let arith = arithmetic::<extra::Default>();
+    group.bench_function("arithmetic", |b| {
+        b.iter(|| black_box(arith.parse(black_box(arith_src.as_str()))).unwrap())
+    });
+
+    let arith_rich = arithmetic::<extra::Err<Rich<char>>>();
+    group.bench_function("arithmetic_rich", |b| {
+        b.iter(|| black_box(arith_rich.parse(black_box(arith_src.as_str()))).unwrap())
+    });
+
+    let toy = toy_language::<extra::Default>();
+    group.bench_function("toy_language", |b| {
+        b.iter(|| black_box(toy.parse(black_box(toy_src.as_str()))).unwrap())
+    });
+
+    let toy_rich = toy_language::<extra::Err<Rich<char>>>();
+    group.bench_function("toy_language_rich", |b| {
+        b.iter(|| black_box(toy_rich.parse(black_box(toy_src.as_str()))).unwrap())
+    });
+
+    let versions = [
+        "0.1.0",
+        "1.0.0-alpha.7",
+        "2.14.3+build.20240101",
+        "10.20.30-rc.1.x-y-z+sha.5114f85",
+    ];
+    let semver_src = versions.join("\n").repeat(250);
+    let semver = formats::semver::<extra::Default>()
+        .separated_by(text::newline())
+        .collect::<Vec<_>>();
+    group.bench_function("semver", |b| {
+        b.iter(|| black_box(semver.parse(black_box(semver_src.as_str()))).unwrap())
+    });
+
+    group.finish();
+}
+
+criterion_group!(
+    name = benches;
+    config = utils::make_criterion();
+    targets = bench_grammars
+);
+criterion_main!(benches);
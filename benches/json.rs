@@ -88,6 +88,13 @@ fn bench_json(c: &mut Criterion) {
         }
     });
 
+    c.bench_function("json_chumsky_formats", {
+        use ::chumsky::{formats::json, prelude::*};
+        let json = json::value::<extra::Err<EmptyErr>>(json::Options::STRICT);
+        let src = std::str::from_utf8(JSON).unwrap();
+        move |b| b.iter(|| black_box(json.parse(black_box(src))).into_result().unwrap())
+    });
+
     c.bench_function("json_serde_json", {
         use serde_json::{from_slice, Value};
         move |b| b.iter(|| black_box(from_slice::<Value>(black_box(JSON)).unwrap()))
@@ -347,7 +354,7 @@ mod nom {
         terminated(value, space)(i)
     }
 
-    pub fn json(i: &[u8]) -> IResult<&[u8], JsonZero, (&[u8], nom::error::ErrorKind)> {
+    pub fn json(i: &[u8]) -> IResult<&[u8], JsonZero<'_>, (&[u8], nom::error::ErrorKind)> {
         root(i)
     }
 }
@@ -473,7 +480,7 @@ mod pest {
     #[grammar = "benches/json.pest"]
     struct JsonParser;
 
-    pub fn parse(file: &str) -> Result<JsonZero, Error<Rule>> {
+    pub fn parse(file: &str) -> Result<JsonZero<'_>, Error<Rule>> {
         let json = JsonParser::parse(Rule::json, file)?.next().unwrap();
 
         use pest::iterators::Pair;
@@ -0,0 +1,64 @@
+use chumsky::prelude::*;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn bench_separated(c: &mut Criterion) {
+    let array = text::int::<_, extra::Default>(10)
+        .separated_by(just(','))
+        .collect::<Vec<_>>()
+        .delimited_by(just('['), just(']'));
+
+    let array_src = format!(
+        "[{}]",
+        (0..10_000)
+            .map(|i| i.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    );
+
+    c.bench_function("separated_json_array", |b| {
+        b.iter(|| {
+            black_box(array.parse(black_box(array_src.as_str())))
+                .into_result()
+                .unwrap();
+        })
+    });
+
+    let field = none_of::<_, _, extra::Default>(",\n").repeated().to_slice();
+    let csv = field
+        .separated_by(just(','))
+        .allow_trailing()
+        .collect::<Vec<_>>()
+        .separated_by(just('\n'))
+        .collect::<Vec<_>>();
+
+    let csv_src = "alpha,beta,gamma,42,3.14,\n".repeat(2_000);
+
+    c.bench_function("separated_csv_rows", |b| {
+        b.iter(|| {
+            black_box(csv.parse(black_box(csv_src.as_str())))
+                .into_result()
+                .unwrap();
+        })
+    });
+
+    // Many short lists, each of which ends by failing to find a separator
+    let pairs = text::int::<_, extra::Default>(10)
+        .separated_by(just(','))
+        .collect::<Vec<_>>()
+        .delimited_by(just('['), just(']'))
+        .separated_by(just(';'))
+        .collect::<Vec<_>>();
+
+    let pairs_src = "[1,2];[3];[4,5,6];".repeat(2_000) + "[]";
+
+    c.bench_function("separated_short_lists", |b| {
+        b.iter(|| {
+            black_box(pairs.parse(black_box(pairs_src.as_str())))
+                .into_result()
+                .unwrap();
+        })
+    });
+}
+
+criterion_group!(benches, bench_separated);
+criterion_main!(benches);
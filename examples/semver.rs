@@ -0,0 +1,41 @@
+//! This is an example of using the ready-made semantic version parser in `chumsky::formats`.
+//! Run it with the following command:
+//! cargo run --example semver -- 1.0.0-alpha.7+build.5 01.2.3
+
+use ariadne::{Color, Label, Report, ReportKind, Source};
+use chumsky::{formats, prelude::*};
+use std::env;
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let semver = formats::semver_spanned::<extra::Err<Rich<char>>>();
+
+    for src in &args {
+        match semver.parse(src).into_result() {
+            Ok((version, spans)) => {
+                println!("{src}:");
+                println!("  major: {} at {}", version.major, spans.major);
+                println!("  minor: {} at {}", version.minor, spans.minor);
+                println!("  patch: {} at {}", version.patch, spans.patch);
+                if let Some(span) = spans.pre {
+                    println!("  pre-release: {:?} at {span}", version.pre);
+                }
+                if let Some(span) = spans.build {
+                    println!("  build: {:?} at {span}", version.build);
+                }
+            }
+            Err(errs) => errs.into_iter().for_each(|e| {
+                Report::build(ReportKind::Error, (), e.span().start)
+                    .with_message(e.to_string())
+                    .with_label(
+                        Label::new(e.span().into_range())
+                            .with_message(e.reason().to_string())
+                            .with_color(Color::Red),
+                    )
+                    .finish()
+                    .print(Source::from(src))
+                    .unwrap()
+            }),
+        }
+    }
+}
@@ -0,0 +1,150 @@
+//! The `FromChars` derive.
+
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{Data, DeriveInput, Error, Expr, Fields, LitStr, Result};
+
+/// A way of lexing one variant of the enum.
+enum Candidate {
+    Token(LitStr),
+    Parser(Expr),
+}
+
+pub fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "`FromChars` can only be derived for enums",
+        ));
+    };
+    if let Some(param) = input
+        .generics
+        .type_params()
+        .map(ToTokens::to_token_stream)
+        .chain(input.generics.const_params().map(ToTokens::to_token_stream))
+        .next()
+    {
+        return Err(Error::new_spanned(
+            param,
+            "`FromChars` cannot be derived for enums with type or const parameters",
+        ));
+    }
+    let mut lifetimes = input.generics.lifetimes();
+    let src = lifetimes.next().map(|param| param.lifetime.clone());
+    if let Some(param) = lifetimes.next() {
+        return Err(Error::new_spanned(
+            param,
+            "`FromChars` cannot be derived for enums with more than one lifetime parameter",
+        ));
+    }
+
+    let mut tokens = Vec::new();
+    let mut parsers = Vec::new();
+    for variant in &data.variants {
+        let name = &variant.ident;
+        for attr in &variant.attrs {
+            let candidate = if attr.path().is_ident("token") {
+                let lit = attr.parse_args::<LitStr>()?;
+                if lit.value().is_empty() {
+                    return Err(Error::new_spanned(lit, "tokens cannot be empty"));
+                }
+                if let Some((other, _)) = tokens
+                    .iter()
+                    .find(|(other, _): &&(LitStr, _)| other.value() == lit.value())
+                {
+                    return Err(Error::new_spanned(
+                        lit,
+                        format!("token {} is already used", other.to_token_stream()),
+                    ));
+                }
+                Candidate::Token(lit)
+            } else if attr.path().is_ident("parser") {
+                Candidate::Parser(attr.parse_args()?)
+            } else {
+                continue;
+            };
+
+            match (candidate, &variant.fields) {
+                (Candidate::Token(lit), Fields::Unit) => tokens.push((lit, name)),
+                (Candidate::Token(lit), _) => {
+                    return Err(Error::new_spanned(
+                        lit,
+                        "`#[token(...)]` can only be used on variants without fields",
+                    ))
+                }
+                (Candidate::Parser(expr), Fields::Unit) => {
+                    parsers.push(quote!(::chumsky::Parser::map(#expr, |_| Self::#name)))
+                }
+                (Candidate::Parser(expr), Fields::Unnamed(fields)) if fields.unnamed.len() == 1 => {
+                    parsers.push(quote!(::chumsky::Parser::map(#expr, Self::#name)))
+                }
+                (Candidate::Parser(expr), _) => {
+                    return Err(Error::new_spanned(
+                        expr,
+                        "`#[parser(...)]` can only be used on variants with no fields or a single unnamed field",
+                    ))
+                }
+            }
+        }
+    }
+    if tokens.is_empty() && parsers.is_empty() {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "no variants have a `#[token(...)]` or `#[parser(...)]` attribute",
+        ));
+    }
+
+    let (src_param, src) = match src {
+        Some(src) => (None, src),
+        None => (
+            Some(quote!('src,)),
+            syn::Lifetime::new("'src", proc_macro2::Span::call_site()),
+        ),
+    };
+    // Tokens come first so that they take priority over parsers that match the same amount of input
+    let candidates = tokens
+        .iter()
+        .map(|(lit, name)| quote!(::chumsky::Parser::map(::chumsky::primitive::just(#lit), |_| Self::#name)))
+        .chain(parsers)
+        .map(|parser| quote!(::chumsky::__private::item::<&#src str, E, _, _>(#parser)))
+        .collect::<Vec<_>>();
+    let idx = (0..candidates.len())
+        .map(syn::Index::from)
+        .collect::<Vec<_>>();
+    let lits = tokens.iter().map(|(lit, _)| lit);
+    let names = tokens.iter().map(|(_, name)| name);
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// A parser that lexes a single token of this type, choosing whichever of the variants matches the most
+            /// input.
+            #[allow(clippy::all)]
+            pub fn lexer<#src_param E>() -> impl ::chumsky::Parser<#src, &#src str, Self, E> + ::core::clone::Clone
+            where
+                E: ::chumsky::extra::ParserExtra<#src, &#src str>,
+            {
+                use ::chumsky::prelude::*;
+
+                let candidates = (#(#candidates,)*);
+                ::chumsky::primitive::custom(move |inp| {
+                    let mut best = None;
+                    #(::chumsky::__private::try_longest(inp, &candidates.#idx, #idx, &mut best);)*
+                    match best {
+                        #(Some((#idx, _)) => inp.parse(&candidates.#idx),)*
+                        _ => Err(::chumsky::__private::longest_error(inp)),
+                    }
+                })
+            }
+
+            /// Find the variant of this type that has the given text as its token, if any.
+            pub fn from_chars(text: &str) -> ::core::option::Option<Self> {
+                match text {
+                    #(#lits => ::core::option::Option::Some(Self::#names),)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,439 @@
+//! The `grammar!` macro.
+
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parenthesized,
+    parse::{Parse, ParseStream},
+    token, Attribute, Error, Expr, Ident, Lit, Result, Token, Type, Visibility,
+};
+
+/// The maximum number of alternatives that `choice` accepts as a single tuple.
+const MAX_CHOICE: usize = 26;
+
+/// A whole grammar: a header naming the input and extra types, followed by rules.
+pub struct Grammar {
+    input: Type,
+    extra: Option<Type>,
+    rules: Vec<Rule>,
+}
+
+/// `pub name -> Type = alternatives ;`
+struct Rule {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    name: Ident,
+    ty: Type,
+    body: Alts,
+}
+
+/// `alt | alt | ...`
+struct Alts(Vec<Alt>);
+
+/// `item item ... => action`
+struct Alt {
+    span: Span,
+    items: Vec<Item>,
+    action: Option<Expr>,
+}
+
+/// `binding: atom postfix*`
+struct Item {
+    binding: Option<Ident>,
+    atom: Atom,
+    postfix: Vec<Postfix>,
+}
+
+/// What the expansion of a grammar's rules needs to know about the grammar.
+struct Context<'a> {
+    input: TokenStream,
+    extra: TokenStream,
+    rules: &'a [Rule],
+}
+
+enum Atom {
+    Lit(Lit),
+    Range(Lit, Lit),
+    Rule(Ident),
+    Group(Alts),
+    Parser(TokenStream),
+}
+
+enum Postfix {
+    Many,
+    Some,
+    Opt,
+}
+
+impl Parse for Grammar {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut input_ty = None;
+        let mut extra_ty = None;
+        while input.peek(Token![type]) {
+            input.parse::<Token![type]>()?;
+            let name = input.parse::<Ident>()?;
+            input.parse::<Token![=]>()?;
+            let ty = input.parse::<Type>()?;
+            input.parse::<Token![;]>()?;
+            let slot = match name.to_string().as_str() {
+                "Input" => &mut input_ty,
+                "Extra" => &mut extra_ty,
+                _ => {
+                    return Err(Error::new(
+                        name.span(),
+                        "expected `type Input = ...;` or `type Extra = ...;`",
+                    ))
+                }
+            };
+            if slot.replace(ty).is_some() {
+                return Err(Error::new(
+                    name.span(),
+                    format!("`{name}` is declared twice"),
+                ));
+            }
+        }
+        let input_ty = input_ty.ok_or_else(|| {
+            Error::new(
+                input.span(),
+                "grammars must start by declaring their input type with `type Input = ...;`",
+            )
+        })?;
+
+        let mut rules = Vec::<Rule>::new();
+        while !input.is_empty() {
+            let rule = input.parse::<Rule>()?;
+            if rules.iter().any(|r| r.name == rule.name) {
+                return Err(Error::new(
+                    rule.name.span(),
+                    format!("rule `{}` is defined twice", rule.name),
+                ));
+            }
+            rules.push(rule);
+        }
+        if rules.is_empty() {
+            return Err(Error::new(
+                input.span(),
+                "grammars must have at least one rule",
+            ));
+        }
+
+        Ok(Self {
+            input: input_ty,
+            extra: extra_ty,
+            rules,
+        })
+    }
+}
+
+impl Parse for Rule {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        let name = input.parse()?;
+        input.parse::<Token![->]>()?;
+        let ty = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let body = input.parse()?;
+        input.parse::<Token![;]>()?;
+        Ok(Self {
+            attrs,
+            vis,
+            name,
+            ty,
+            body,
+        })
+    }
+}
+
+impl Parse for Alts {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut alts = vec![input.parse::<Alt>()?];
+        while input.peek(Token![|]) {
+            input.parse::<Token![|]>()?;
+            alts.push(input.parse()?);
+        }
+        Ok(Self(alts))
+    }
+}
+
+impl Parse for Alt {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let span = input.span();
+        let mut items = Vec::new();
+        while !input.is_empty()
+            && !input.peek(Token![|])
+            && !input.peek(Token![;])
+            && !input.peek(Token![=>])
+        {
+            items.push(input.parse()?);
+        }
+        if items.is_empty() {
+            return Err(Error::new(
+                span,
+                "expected at least one item in alternative",
+            ));
+        }
+
+        let action = if input.peek(Token![=>]) {
+            input.parse::<Token![=>]>()?;
+            // The action extends to the next top-level `|` or `;`, so actions that need these must use parentheses
+            let mut tokens = TokenStream::new();
+            while !input.is_empty() && !input.peek(Token![|]) && !input.peek(Token![;]) {
+                tokens.extend([input.parse::<TokenTree>()?]);
+            }
+            Some(syn::parse2(tokens)?)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            span,
+            items,
+            action,
+        })
+    }
+}
+
+impl Parse for Item {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let binding = if input.peek(Ident) && input.peek2(Token![:]) && !input.peek2(Token![::]) {
+            let binding = input.parse()?;
+            input.parse::<Token![:]>()?;
+            Some(binding)
+        } else {
+            None
+        };
+
+        let atom = if input.peek(Lit) {
+            let lit = input.parse::<Lit>()?;
+            if input.peek(Token![..=]) {
+                input.parse::<Token![..=]>()?;
+                Atom::Range(lit, input.parse()?)
+            } else {
+                Atom::Lit(lit)
+            }
+        } else if input.peek(Ident) {
+            Atom::Rule(input.parse()?)
+        } else if input.peek(token::Paren) {
+            let content;
+            parenthesized!(content in input);
+            Atom::Group(content.parse()?)
+        } else if input.peek(token::Brace) {
+            let content;
+            syn::braced!(content in input);
+            Atom::Parser(content.parse()?)
+        } else {
+            return Err(input.error(
+                "expected a literal, a rule name, a `( ... )` group or a `{ ... }` parser expression",
+            ));
+        };
+
+        let mut postfix = Vec::new();
+        loop {
+            if input.peek(Token![*]) {
+                input.parse::<Token![*]>()?;
+                postfix.push(Postfix::Many);
+            } else if input.peek(Token![+]) {
+                input.parse::<Token![+]>()?;
+                postfix.push(Postfix::Some);
+            } else if input.peek(Token![?]) {
+                input.parse::<Token![?]>()?;
+                postfix.push(Postfix::Opt);
+            } else {
+                break;
+            }
+        }
+
+        Ok(Self {
+            binding,
+            atom,
+            postfix,
+        })
+    }
+}
+
+impl Alts {
+    fn expand(&self, cx: &Context) -> Result<TokenStream> {
+        let alts = self
+            .0
+            .iter()
+            .map(|alt| alt.expand(cx))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(choice(alts))
+    }
+}
+
+/// Combine alternatives with `choice`, nesting calls when there are more alternatives than one tuple supports.
+fn choice(mut alts: Vec<TokenStream>) -> TokenStream {
+    if alts.len() == 1 {
+        return alts.remove(0);
+    }
+    if alts.len() > MAX_CHOICE {
+        let rest = alts.split_off(MAX_CHOICE - 1);
+        alts.push(choice(rest));
+    }
+    quote!(::chumsky::primitive::choice((#(#alts,)*)))
+}
+
+impl Alt {
+    fn expand(&self, cx: &Context) -> Result<TokenStream> {
+        let parsers = self
+            .items
+            .iter()
+            .map(|item| item.expand(cx))
+            .collect::<Result<Vec<_>>>()?;
+        let parser = parsers
+            .into_iter()
+            .reduce(|a, b| quote!(::chumsky::Parser::then(#a, #b)))
+            .expect("alternatives have at least one item");
+
+        let names = (0..self.items.len())
+            .map(|i| format_ident!("__item{}", i, span = self.span))
+            .collect::<Vec<_>>();
+        let pattern = |pats: Vec<TokenStream>| {
+            pats.into_iter()
+                .reduce(|a, b| quote!((#a, #b)))
+                .expect("alternatives have at least one item")
+        };
+
+        Ok(match &self.action {
+            Some(action) => {
+                let pats = self
+                    .items
+                    .iter()
+                    .map(|item| match &item.binding {
+                        Some(binding) => binding.to_token_stream(),
+                        None => quote!(_),
+                    })
+                    .collect();
+                let pat = pattern(pats);
+                quote!(::chumsky::Parser::map(#parser, |#pat| #action))
+            }
+            None if self.items.len() == 1 => parser,
+            // Flatten the nested tuples produced by `then` so that the output of a sequence is a plain tuple
+            None => {
+                let pat = pattern(names.iter().map(ToTokens::to_token_stream).collect());
+                quote!(::chumsky::Parser::map(#parser, |#pat| (#(#names,)*)))
+            }
+        })
+    }
+}
+
+impl Item {
+    fn expand(&self, cx: &Context) -> Result<TokenStream> {
+        let mut parser = match &self.atom {
+            Atom::Lit(lit) => quote!(::chumsky::primitive::just(#lit)),
+            Atom::Range(start, end) => quote!(::chumsky::primitive::one_of(#start..=#end)),
+            Atom::Rule(name) => {
+                if !cx.rules.iter().any(|r| r.name == *name) {
+                    return Err(Error::new(
+                        name.span(),
+                        format!(
+                            "no rule named `{name}` in this grammar; use `{{ ... }}` to refer to other parsers"
+                        ),
+                    ));
+                }
+                quote!(::core::clone::Clone::clone(&#name))
+            }
+            Atom::Group(alts) => alts.expand(cx)?,
+            Atom::Parser(expr) => quote!((#expr)),
+        };
+        // Pin the input and extra types of each item so that the types of their outputs are known when type-checking
+        // actions
+        let Context { input, extra, .. } = cx;
+        parser = quote!(::chumsky::__private::item::<#input, #extra, _, _>(#parser));
+        for postfix in &self.postfix {
+            parser = match postfix {
+                Postfix::Many => quote! {
+                    ::chumsky::IterParser::collect::<::chumsky::__private::Vec<_>>(
+                        ::chumsky::Parser::repeated(#parser)
+                    )
+                },
+                Postfix::Some => quote! {
+                    ::chumsky::IterParser::collect::<::chumsky::__private::Vec<_>>(
+                        ::chumsky::Parser::repeated(#parser).at_least(1)
+                    )
+                },
+                Postfix::Opt => quote!(::chumsky::Parser::or_not(#parser)),
+            };
+        }
+        Ok(parser)
+    }
+}
+
+impl Grammar {
+    pub fn expand(&self) -> Result<TokenStream> {
+        let input = &self.input;
+        let extra = match &self.extra {
+            Some(extra) => extra.to_token_stream(),
+            None => quote!(::chumsky::extra::Default),
+        };
+        let cx = Context {
+            input: input.to_token_stream(),
+            extra: extra.clone(),
+            rules: &self.rules,
+        };
+        let helper = format_ident!("__chumsky_grammar_{}", self.rules[0].name);
+        let handles = (0..self.rules.len())
+            .map(|i| format_ident!("__rule{}", i))
+            .collect::<Vec<_>>();
+        let names = self.rules.iter().map(|r| &r.name).collect::<Vec<_>>();
+        let rule_tys = self
+            .rules
+            .iter()
+            .map(|r| {
+                let ty = &r.ty;
+                quote! {
+                    ::chumsky::recursive::Recursive<
+                        ::chumsky::recursive::Indirect<'src, 'src, #input, #ty, #extra>
+                    >
+                }
+            })
+            .collect::<Vec<_>>();
+        let bodies = self
+            .rules
+            .iter()
+            .map(|r| r.body.expand(&cx))
+            .collect::<Result<Vec<_>>>()?;
+
+        let rule_fns = self.rules.iter().enumerate().map(|(i, rule)| {
+            let Rule {
+                attrs,
+                vis,
+                name,
+                ty,
+                ..
+            } = rule;
+            let idx = syn::Index::from(i);
+            quote! {
+                #(#attrs)*
+                #vis fn #name<'src>() -> impl ::chumsky::Parser<'src, #input, #ty, #extra> + ::core::clone::Clone {
+                    let rules = #helper();
+                    let parser = ::core::clone::Clone::clone(&rules.#idx);
+                    // Rules only refer to one another weakly, so keep all of them alive alongside this one
+                    ::chumsky::Parser::map(parser, move |out: #ty| {
+                        let _ = &rules;
+                        out
+                    })
+                }
+            }
+        });
+
+        Ok(quote! {
+            #[doc(hidden)]
+            #[allow(non_snake_case, unused_imports, unused_variables, clippy::all)]
+            fn #helper<'src>() -> (#(#rule_tys,)*) {
+                use ::chumsky::prelude::*;
+
+                #(let mut #handles = ::chumsky::recursive::Recursive::declare().with_name(stringify!(#names));)*
+                {
+                    #(let #names = ::chumsky::__private::downgrade(&#handles);)*
+                    #(#handles.define(#bodies);)*
+                }
+                (#(#handles,)*)
+            }
+
+            #(#rule_fns)*
+        })
+    }
+}
@@ -0,0 +1,137 @@
+//! Procedural macros for [chumsky](https://docs.rs/chumsky).
+//!
+//! This crate should not be used directly: enable the `macros` feature of chumsky and use the macros re-exported from
+//! there instead.
+
+use syn::{parse_macro_input, DeriveInput, Error};
+
+mod from_chars;
+mod grammar;
+
+/// Generate parsers from a BNF-like grammar.
+///
+/// A grammar starts by declaring the type of the input that its parsers accept with `type Input = ...;` and,
+/// optionally, their [`ParserExtra`](https://docs.rs/chumsky/latest/chumsky/extra/trait.ParserExtra.html) with
+/// `type Extra = ...;` (by default, `extra::Default`). Both types may refer to the `'src` lifetime.
+///
+/// Then come the rules. Each rule is written as `name -> OutputType = alternatives;` and becomes a function, with
+/// the same name and visibility, that returns a parser for that rule. Rules may refer to one another (and
+/// themselves) freely, in any order.
+///
+/// Alternatives are separated by `|` and tried in order, like [`choice`]. Each alternative is a sequence of items,
+/// optionally followed by `=> expr`, an action that turns the outputs of the items into the output of the rule.
+/// Items may be bound to a name with `name: item` to make their output available to the action. An alternative
+/// without an action outputs the output of its item or, if it has several, a tuple of their outputs.
+///
+/// Items may be:
+///
+/// - A literal, like `'a'` or `"let"`, which is parsed with [`just`]
+/// - A range of literals, like `'0'..='9'`, which is parsed with [`one_of`]
+/// - The name of a rule in the grammar
+/// - A group of alternatives in parentheses, like `('+' | '-')`
+/// - Any parser expression in braces, like `{ text::int(10) }`. The chumsky prelude is in scope.
+///
+/// Followed by any number of `*` (zero or more, collected into a `Vec`), `+` (one or more, collected into a `Vec`) or
+/// `?` (optional, outputting an `Option`).
+///
+/// The generated code is plain chumsky combinators, behaving exactly as if they had been written out by hand, with
+/// each rule being a [`Recursive`] parser.
+///
+/// [`choice`]: https://docs.rs/chumsky/latest/chumsky/primitive/fn.choice.html
+/// [`just`]: https://docs.rs/chumsky/latest/chumsky/primitive/fn.just.html
+/// [`one_of`]: https://docs.rs/chumsky/latest/chumsky/primitive/fn.one_of.html
+/// [`Recursive`]: https://docs.rs/chumsky/latest/chumsky/recursive/struct.Recursive.html
+///
+/// # Examples
+///
+/// ```
+/// use chumsky::{grammar, prelude::*};
+///
+/// grammar! {
+///     type Input = &'src str;
+///     type Extra = extra::Err<Simple<'src, char>>;
+///
+///     /// Parses a sum of products.
+///     pub expr -> i64 = a: product "+" b: expr => a + b
+///                     | product;
+///     product -> i64 = a: atom "*" b: product => a * b
+///                    | atom;
+///     atom -> i64 = n: { text::int(10) } => n.parse().unwrap()
+///                 | "(" e: expr ")" => e;
+/// }
+///
+/// assert_eq!(expr().parse("2*(3+4)+1").into_result(), Ok(15));
+/// assert!(expr().parse("2*").has_errors());
+/// ```
+///
+/// Actions have access to the outputs of the items they bind. Above, `n.parse()` infers its target type from the rule's
+/// output type; when an action's result doesn't pin the type down like this, add a turbofish such as `n.parse::<i64>()`.
+#[proc_macro]
+pub fn grammar(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    parse_macro_input!(input as grammar::Grammar)
+        .expand()
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Derive a lexer for an enum of tokens.
+///
+/// Variants may be annotated with any number of `#[token("...")]` attributes, giving the text of the token, and with
+/// `#[parser(...)]` attributes, giving a parser for the variant. Parsers for variants with a single field produce the
+/// value of the field, while parsers for variants without fields may produce anything. Variants without either
+/// attribute are ignored.
+///
+/// This generates two inherent methods:
+///
+/// - `lexer()`, which returns a parser for a single token from a `&str`. Each of the variants is tried and the one
+///   that matches the most input wins, so that keywords and identifiers don't need to be carefully ordered. When a
+///   token matches as much input as a parser, the token wins.
+///
+/// - `from_chars(text)`, which finds the variant with the given token, if any. This is useful for keyword tables.
+///
+/// If the enum has a lifetime parameter, it is used as the lifetime of the input.
+///
+/// # Examples
+///
+/// ```
+/// use chumsky::{prelude::*, FromChars};
+///
+/// #[derive(Clone, Debug, PartialEq, FromChars)]
+/// enum Token<'src> {
+///     #[token("let")]
+///     Let,
+///     #[token("=")]
+///     Eq,
+///     #[token("==")]
+///     EqEq,
+///     #[parser(text::int(10).from_str().unwrapped())]
+///     Int(u64),
+///     #[parser(text::ascii::ident())]
+///     Ident(&'src str),
+/// }
+///
+/// let lexer = Token::lexer::<extra::Err<Rich<char>>>()
+///     .padded()
+///     .repeated()
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(
+///     lexer.parse("let letter == 42 = let").into_result(),
+///     Ok(vec![
+///         Token::Let,
+///         Token::Ident("letter"),
+///         Token::EqEq,
+///         Token::Int(42),
+///         Token::Eq,
+///         Token::Let,
+///     ]),
+/// );
+/// assert_eq!(Token::from_chars("=="), Some(Token::EqEq));
+/// assert_eq!(Token::from_chars("letter"), None);
+/// ```
+#[proc_macro_derive(FromChars, attributes(token, parser))]
+pub fn derive_from_chars(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    from_chars::expand(parse_macro_input!(input as DeriveInput))
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
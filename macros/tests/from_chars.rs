@@ -0,0 +1,42 @@
+use chumsky::{prelude::*, FromChars};
+
+#[derive(Clone, Debug, PartialEq, FromChars)]
+enum Op {
+    #[token("+")]
+    #[token("plus")]
+    Add,
+    #[token("+=")]
+    AddAssign,
+    #[token("-")]
+    Sub,
+    #[parser(text::whitespace().at_least(1))]
+    Space,
+    Unlexed,
+}
+
+#[test]
+fn longest_match() {
+    let lexer = Op::lexer::<extra::Err<Simple<char>>>()
+        .repeated()
+        .collect::<Vec<_>>();
+    assert_eq!(
+        lexer.parse("+ +=plus  -").into_result(),
+        Ok(vec![
+            Op::Add,
+            Op::Space,
+            Op::AddAssign,
+            Op::Add,
+            Op::Space,
+            Op::Sub,
+        ]),
+    );
+    assert!(lexer.parse("+*").has_errors());
+    assert_ne!(Op::Unlexed, Op::Space);
+}
+
+#[test]
+fn keyword_table() {
+    assert_eq!(Op::from_chars("plus"), Some(Op::Add));
+    assert_eq!(Op::from_chars("+="), Some(Op::AddAssign));
+    assert_eq!(Op::from_chars(" "), None);
+}
@@ -0,0 +1,44 @@
+use chumsky::{grammar, prelude::*};
+
+#[derive(Debug, PartialEq)]
+enum Value {
+    Num(u32),
+    List(Vec<Value>),
+}
+
+grammar! {
+    type Input = &'src str;
+    type Extra = extra::Err<Rich<'src, char>>;
+
+    value -> Value = n: { text::int(10) } => Value::Num(n.parse().unwrap())
+                   | "[" items: (value ","?)* "]" => Value::List(items.into_iter().map(|(v, _)| v).collect());
+    ident -> (char, Vec<char>) = ('a'..='z' | '_') ('a'..='z' | '0'..='9' | '_')*;
+    digits -> Vec<char> = '0'..='9'+;
+    sign -> Option<char> = ('+' | '-')?;
+}
+
+#[test]
+fn recursive_rules() {
+    assert_eq!(
+        value().parse("[1,[2,3,],[]]").into_result(),
+        Ok(Value::List(vec![
+            Value::Num(1),
+            Value::List(vec![Value::Num(2), Value::Num(3)]),
+            Value::List(vec![]),
+        ])),
+    );
+    assert!(value().parse("[1,").has_errors());
+}
+
+#[test]
+fn sequences_and_postfix_operators() {
+    assert_eq!(
+        ident().parse("a_1").into_result(),
+        Ok(('a', vec!['_', '1']))
+    );
+    assert!(ident().parse("1a").has_errors());
+    assert_eq!(digits().parse("42").into_result(), Ok(vec!['4', '2']));
+    assert!(digits().parse("").has_errors());
+    assert_eq!(sign().parse("-").into_result(), Ok(Some('-')));
+    assert_eq!(sign().parse("").into_result(), Ok(None));
+}
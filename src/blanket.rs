@@ -13,6 +13,11 @@ where
         M::invoke(*self, inp)
     }
 
+    #[inline(always)]
+    fn fails_on_peek(&self, inp: &mut InputRef<'src, '_, I, E>) -> bool {
+        (*self).fails_on_peek(inp)
+    }
+
     go_extra!(O);
 }
 
@@ -5,9 +5,11 @@
 //! Although it's *sometimes* useful to be able to name their type, most of these parsers are much easier to work with
 //! when accessed through their respective methods on [`Parser`].
 
-use inspector::Inspector;
+use inspector::{Inspector, Interner, NodeInspector};
 
 use super::*;
+#[cfg(feature = "memoization")]
+use crate::input::UserMemoKey;
 
 /// The type of a lazy parser.
 pub type Lazy<'src, A, I, E> =
@@ -166,6 +168,7 @@ where
     where
         I: 'src;
 
+    #[inline(always)]
     fn make_iter<M: Mode>(
         &self,
         inp: &mut InputRef<'src, '_, I, E>,
@@ -177,6 +180,7 @@ where
         Ok((A::make_iter(&self.parser, inp)?, cfg))
     }
 
+    #[inline(always)]
     fn next<M: Mode>(
         &self,
         inp: &mut InputRef<'src, '_, I, E>,
@@ -223,6 +227,50 @@ where
     go_extra!(I::Slice);
 }
 
+/// See [`Parser::intern`].
+pub struct Intern<A, O> {
+    pub(crate) parser: A,
+    #[allow(dead_code)]
+    pub(crate) phantom: EmptyPhantom<O>,
+}
+
+impl<A: Copy, O> Copy for Intern<A, O> {}
+impl<A: Clone, O> Clone for Intern<A, O> {
+    fn clone(&self) -> Self {
+        Self {
+            parser: self.parser.clone(),
+            phantom: EmptyPhantom::new(),
+        }
+    }
+}
+
+impl<'src, A, I, O, E> Parser<'src, I, <E::State as Interner<I::Slice>>::Symbol, E> for Intern<A, O>
+where
+    A: Parser<'src, I, O, E>,
+    I: SliceInput<'src>,
+    E: ParserExtra<'src, I>,
+    E::State: Interner<I::Slice>,
+{
+    #[inline(always)]
+    fn go<M: Mode>(
+        &self,
+        inp: &mut InputRef<'src, '_, I, E>,
+    ) -> PResult<M, <E::State as Interner<I::Slice>>::Symbol>
+    where
+        Self: Sized,
+    {
+        let before = inp.cursor();
+        self.parser.go::<Check>(inp)?;
+
+        Ok(M::bind(|| {
+            let slice = inp.slice_since(&before..);
+            inp.state().intern(slice)
+        }))
+    }
+
+    go_extra!(<E::State as Interner<I::Slice>>::Symbol);
+}
+
 /// See [`Parser::filter`].
 pub struct Filter<A, F> {
     pub(crate) parser: A,
@@ -370,6 +418,33 @@ where
     go_extra!(O);
 }
 
+/// See [`Parser::node`].
+#[derive(Copy, Clone)]
+pub struct Node<A, K> {
+    pub(crate) parser: A,
+    pub(crate) kind: K,
+}
+
+impl<'src, I, O, E, A, K> Parser<'src, I, O, E> for Node<A, K>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    E::State: NodeInspector<'src, I, K>,
+    A: Parser<'src, I, O, E>,
+{
+    #[inline(always)]
+    fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, O> {
+        let before = inp.cursor();
+        inp.state().on_enter(&self.kind);
+        let res = self.parser.go::<M>(inp);
+        let span = res.is_ok().then(|| inp.span_since(&before));
+        inp.state().on_exit(&self.kind, span);
+        res
+    }
+
+    go_extra!(O);
+}
+
 impl<'src, I, O, E, A, OA, F> IterParser<'src, I, O, E> for MapWith<A, OA, F>
 where
     I: Input<'src>,
@@ -407,19 +482,69 @@ where
     }
 }
 
+/// A function that can be called with the elements of a tuple as separate arguments. See [`Parser::map_group`].
+///
+/// This is implemented for functions and closures of up to 12 arguments, for both flat tuples (like those produced by
+/// [`group`]) and the left-nested tuples produced by chains of [`Parser::then`] (like `((a, b), c)`). The `M` parameter
+/// is only used to distinguish between these implementations, and can be ignored.
+pub trait GroupFn<Args, O, M> {
+    /// Call this function with the elements of the given tuple.
+    fn call_group(&self, args: Args) -> O;
+}
+
+macro_rules! impl_group_fn {
+    // Left-nested tuples, like `((A, B), C)`
+    (@nested [$($X:ident)*] ($args:ty, $pat:pat)) => {
+        #[allow(non_snake_case)]
+        impl<Func, Out, $($X),*> GroupFn<$args, Out, fn($($X),*) -> Out> for Func
+        where
+            Func: Fn($($X),*) -> Out,
+        {
+            #[inline(always)]
+            fn call_group(&self, $pat: $args) -> Out {
+                self($($X),*)
+            }
+        }
+    };
+    (@nested [$($X:ident)*] ($args:ty, $pat:pat) $next:ident $($rest:ident)*) => {
+        impl_group_fn!(@nested [$($X)*] ($args, $pat));
+        impl_group_fn!(@nested [$($X)* $next] (($args, $next), ($pat, $next)) $($rest)*);
+    };
+    // Flat tuples, like `(A, B, C)`
+    (@flat [$($X:ident)*]) => {
+        #[allow(non_snake_case)]
+        impl<Func, Out, $($X),*> GroupFn<($($X,)*), Out, fn($($X),*) -> Out> for Func
+        where
+            Func: Fn($($X),*) -> Out,
+        {
+            #[inline(always)]
+            fn call_group(&self, ($($X,)*): ($($X,)*)) -> Out {
+                self($($X),*)
+            }
+        }
+    };
+    (@flat [$($X:ident)*] $next:ident $($rest:ident)*) => {
+        impl_group_fn!(@flat [$($X)*]);
+        impl_group_fn!(@flat [$($X)* $next] $($rest)*);
+    };
+    ($a:ident $b:ident $c:ident $($rest:ident)*) => {
+        impl_group_fn!(@nested [$a $b] (($a, $b), ($a, $b)) $c $($rest)*);
+        impl_group_fn!(@flat [$a $b $c] $($rest)*);
+    };
+}
+
+impl_group_fn!(A_ B_ C_ D_ E_ F_ G_ H_ I_ J_ K_ L_);
+
 /// See [`Parser::map_group`].
-#[cfg(feature = "nightly")]
-pub struct MapGroup<A, OA, F> {
+pub struct MapGroup<A, OA, F, M> {
     pub(crate) parser: A,
     pub(crate) mapper: F,
     #[allow(dead_code)]
-    pub(crate) phantom: EmptyPhantom<OA>,
+    pub(crate) phantom: EmptyPhantom<(OA, M)>,
 }
 
-#[cfg(feature = "nightly")]
-impl<A: Copy, OA, F: Copy> Copy for MapGroup<A, OA, F> {}
-#[cfg(feature = "nightly")]
-impl<A: Clone, OA, F: Clone> Clone for MapGroup<A, OA, F> {
+impl<A: Copy, OA, F: Copy, M> Copy for MapGroup<A, OA, F, M> {}
+impl<A: Clone, OA, F: Clone, M> Clone for MapGroup<A, OA, F, M> {
     fn clone(&self) -> Self {
         Self {
             parser: self.parser.clone(),
@@ -429,32 +554,28 @@ impl<A: Clone, OA, F: Clone> Clone for MapGroup<A, OA, F> {
     }
 }
 
-#[cfg(feature = "nightly")]
-impl<'src, I, O, E, A, OA, F> Parser<'src, I, O, E> for MapGroup<A, OA, F>
+impl<'src, I, O, E, A, OA, F, MF> Parser<'src, I, O, E> for MapGroup<A, OA, F, MF>
 where
     I: Input<'src>,
     E: ParserExtra<'src, I>,
     A: Parser<'src, I, OA, E>,
-    F: Fn<OA, Output = O>,
-    OA: Tuple,
+    F: GroupFn<OA, O, MF>,
 {
     #[inline(always)]
     fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, O> {
         let out = self.parser.go::<M>(inp)?;
-        Ok(M::map(out, |out| self.mapper.call(out)))
+        Ok(M::map(out, |out| self.mapper.call_group(out)))
     }
 
     go_extra!(O);
 }
 
-#[cfg(feature = "nightly")]
-impl<'src, I, O, E, A, OA, F> IterParser<'src, I, O, E> for MapGroup<A, OA, F>
+impl<'src, I, O, E, A, OA, F, MF> IterParser<'src, I, O, E> for MapGroup<A, OA, F, MF>
 where
     I: Input<'src>,
     E: ParserExtra<'src, I>,
     A: IterParser<'src, I, OA, E>,
-    F: Fn<OA, Output = O>,
-    OA: Tuple,
+    F: GroupFn<OA, O, MF>,
 {
     type IterState<M: Mode>
         = A::IterState<M>
@@ -476,7 +597,7 @@ where
         state: &mut Self::IterState<M>,
     ) -> IPResult<M, O> {
         match self.parser.next::<M>(inp, state) {
-            Ok(Some(o)) => Ok(Some(M::map(o, |o| self.mapper.call(o)))),
+            Ok(Some(o)) => Ok(Some(M::map(o, |o| self.mapper.call_group(o)))),
             Ok(None) => Ok(None),
             Err(()) => Err(()),
         }
@@ -509,7 +630,8 @@ where
     #[inline(always)]
     fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, I::Span> {
         let before = inp.cursor();
-        self.parser.go::<M>(inp)?;
+        // The output of the inner parser is discarded, so there's no need to generate it
+        self.parser.go::<Check>(inp)?;
         Ok(M::bind(|| inp.span_since(&before)))
     }
 
@@ -563,6 +685,54 @@ where
     go_extra!(O);
 }
 
+/// See [`Parser::filter_map`].
+pub struct FilterMap<A, OA, F> {
+    pub(crate) parser: A,
+    pub(crate) mapper: F,
+    #[allow(dead_code)]
+    pub(crate) phantom: EmptyPhantom<OA>,
+}
+
+impl<A: Copy, OA, F: Copy> Copy for FilterMap<A, OA, F> {}
+impl<A: Clone, OA, F: Clone> Clone for FilterMap<A, OA, F> {
+    fn clone(&self) -> Self {
+        Self {
+            parser: self.parser.clone(),
+            mapper: self.mapper.clone(),
+            phantom: EmptyPhantom::new(),
+        }
+    }
+}
+
+impl<'src, I, O, E, A, OA, F> Parser<'src, I, O, E> for FilterMap<A, OA, F>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    A: Parser<'src, I, OA, E>,
+    F: Fn(OA) -> Option<O>,
+{
+    #[inline(always)]
+    fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, O> {
+        let before = inp.cursor();
+        let out = self.parser.go::<Emit>(inp)?;
+        let old_alt = inp.errors.alt.take();
+        match (self.mapper)(out) {
+            Some(out) => {
+                inp.errors.alt = old_alt;
+                Ok(M::bind(|| out))
+            }
+            None => {
+                let span = inp.span_since(&before);
+                let found = inp.token_at(&before.inner);
+                inp.add_alt_err(&before.inner, E::Error::expected_found(None, found, span));
+                Err(())
+            }
+        }
+    }
+
+    go_extra!(O);
+}
+
 /// See [`Parser::try_map_with`].
 pub struct TryMapWith<A, OA, F> {
     pub(crate) parser: A,
@@ -605,6 +775,140 @@ where
     go_extra!(O);
 }
 
+impl<A, OA, F> TryMap<A, OA, F> {
+    /// Provide a cheaper way to perform the validation of this parser when its output is not needed.
+    ///
+    /// By default, [`Parser::try_map`] must generate the output of the parser it wraps in order to pass it to the
+    /// mapping function, even when the output of the mapping function is going to be discarded (such as when using
+    /// [`Parser::check`], or when the parser is part of a larger pattern that ignores its output). If the validation
+    /// can be performed without that output (for example, by inspecting the slice of input that was parsed), this
+    /// function allows you to provide a checking function to be used instead in such cases, avoiding the cost of
+    /// generating the output.
+    ///
+    /// The checking function must accept exactly the inputs that the mapping function accepts. The mapping function
+    /// should not have side effects, since it is not invoked when the checking function is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let byte = text::digits::<_, extra::Err<Rich<char>>>(10)
+    ///     .collect::<String>()
+    ///     .try_map(|s, span| s.parse::<u8>().map_err(|e| Rich::custom(span, e)))
+    ///     // Validate the digits directly, without collecting them into a `String`
+    ///     .with_check(|e| {
+    ///         let s: &str = e.slice();
+    ///         s.parse::<u8>().map(|_| ()).map_err(|err| Rich::custom(e.span(), err))
+    ///     });
+    ///
+    /// assert_eq!(byte.parse("255").into_result(), Ok(255));
+    /// assert!(byte.parse("256").has_errors());
+    /// assert!(byte.check("256").has_errors());
+    /// assert!(!byte.ignored().parse("42").has_errors());
+    /// ```
+    pub fn with_check<'src, I, E, C>(self, check: C) -> TryMapCheck<Self, C>
+    where
+        I: Input<'src>,
+        E: ParserExtra<'src, I>,
+        A: Parser<'src, I, OA, E>,
+        C: Fn(&mut MapExtra<'src, '_, I, E>) -> Result<(), E::Error>,
+    {
+        TryMapCheck {
+            parser: self,
+            check,
+        }
+    }
+}
+
+impl<A, OA, F> TryMapWith<A, OA, F> {
+    /// Provide a cheaper way to perform the validation of this parser when its output is not needed.
+    ///
+    /// See [`TryMap::with_check`] for more information.
+    pub fn with_check<'src, I, E, C>(self, check: C) -> TryMapCheck<Self, C>
+    where
+        I: Input<'src>,
+        E: ParserExtra<'src, I>,
+        A: Parser<'src, I, OA, E>,
+        C: Fn(&mut MapExtra<'src, '_, I, E>) -> Result<(), E::Error>,
+    {
+        TryMapCheck {
+            parser: self,
+            check,
+        }
+    }
+}
+
+/// See [`TryMap::with_check`] and [`TryMapWith::with_check`].
+#[derive(Copy, Clone)]
+pub struct TryMapCheck<P, C> {
+    pub(crate) parser: P,
+    pub(crate) check: C,
+}
+
+impl<'src, I, O, E, A, OA, F, C> Parser<'src, I, O, E> for TryMapCheck<TryMap<A, OA, F>, C>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    A: Parser<'src, I, OA, E>,
+    F: Fn(OA, I::Span) -> Result<O, E::Error>,
+    C: Fn(&mut MapExtra<'src, '_, I, E>) -> Result<(), E::Error>,
+{
+    #[inline(always)]
+    fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, O> {
+        M::choose(
+            inp,
+            |inp| self.parser.go::<Emit>(inp),
+            |inp| {
+                let before = inp.cursor();
+                self.parser.parser.go::<Check>(inp)?;
+                let old_alt = inp.errors.alt.take();
+                match (self.check)(&mut MapExtra::new(&before, inp)) {
+                    Ok(()) => {
+                        inp.errors.alt = old_alt;
+                        Ok(())
+                    }
+                    Err(err) => {
+                        inp.add_alt_err(&before.inner, err);
+                        Err(())
+                    }
+                }
+            },
+        )
+    }
+
+    go_extra!(O);
+}
+
+impl<'src, I, O, E, A, OA, F, C> Parser<'src, I, O, E> for TryMapCheck<TryMapWith<A, OA, F>, C>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    A: Parser<'src, I, OA, E>,
+    F: Fn(OA, &mut MapExtra<'src, '_, I, E>) -> Result<O, E::Error>,
+    C: Fn(&mut MapExtra<'src, '_, I, E>) -> Result<(), E::Error>,
+{
+    #[inline(always)]
+    fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, O> {
+        M::choose(
+            inp,
+            |inp| self.parser.go::<Emit>(inp),
+            |inp| {
+                let before = inp.cursor();
+                self.parser.parser.go::<Check>(inp)?;
+                match (self.check)(&mut MapExtra::new(&before, inp)) {
+                    Ok(()) => Ok(()),
+                    Err(err) => {
+                        inp.add_alt_err(&inp.cursor().inner, err);
+                        Err(())
+                    }
+                }
+            },
+        )
+    }
+
+    go_extra!(O);
+}
+
 /// See [`Parser::to`].
 pub struct To<A, OA, O> {
     pub(crate) parser: A,
@@ -800,42 +1104,87 @@ where
 {
     #[inline(always)]
     fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, O> {
-        let before = inp.cursor();
-        // TODO: Don't use address, since this might not be constant?
-        let key = (
-            I::cursor_location(&before.inner),
-            &self.parser as *const _ as *const () as usize,
-        );
+        go_memoized::<M, _, _, _, _>(&self.parser, inp, None)
+    }
 
-        match inp.memos.entry(key) {
-            hashbrown::hash_map::Entry::Occupied(o) => {
-                if let Some(err) = o.get() {
-                    let err = err.clone();
-                    inp.add_alt_err(&before.inner /*&err.pos*/, err.err);
-                } else {
-                    let err_span = inp.span_since(&before);
-                    inp.add_alt(None, None, err_span);
-                }
-                return Err(());
-            }
-            hashbrown::hash_map::Entry::Vacant(v) => {
-                v.insert(None);
-            }
-        }
+    go_extra!(O);
+}
 
-        let res = self.parser.go::<M>(inp);
+/// See [`Parser::memoize_by_key`].
+#[cfg(feature = "memoization")]
+#[derive(Copy, Clone)]
+pub struct MemoizeByKey<A, F> {
+    pub(crate) parser: A,
+    pub(crate) key: F,
+}
 
-        if res.is_err() {
-            let alt = inp.take_alt();
-            inp.memos.insert(key, alt);
-        } else {
-            inp.memos.remove(&key);
+#[cfg(feature = "memoization")]
+impl<'src, I, E, A, F, K, O> Parser<'src, I, O, E> for MemoizeByKey<A, F>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    E::Error: Clone,
+    A: Parser<'src, I, O, E>,
+    F: Fn(&E::Context, &E::State) -> K,
+    K: Hash + Eq + 'static,
+{
+    #[inline(always)]
+    fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, O> {
+        let key = UserMemoKey::new((self.key)(inp.ctx, inp.state));
+        go_memoized::<M, _, _, _, _>(&self.parser, inp, Some(key))
+    }
+
+    go_extra!(O);
+}
+
+#[cfg(feature = "memoization")]
+#[inline(always)]
+fn go_memoized<'src, M, I, E, A, O>(
+    parser: &A,
+    inp: &mut InputRef<'src, '_, I, E>,
+    user_key: Option<UserMemoKey>,
+) -> PResult<M, O>
+where
+    M: Mode,
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    E::Error: Clone,
+    A: Parser<'src, I, O, E>,
+{
+    let before = inp.cursor();
+    // TODO: Don't use address, since this might not be constant?
+    let key = (
+        I::cursor_location(&before.inner),
+        parser as *const _ as *const () as usize,
+        user_key,
+    );
+
+    match inp.memos.entry(key.clone()) {
+        hashbrown::hash_map::Entry::Occupied(o) => {
+            if let Some(err) = o.get() {
+                let err = err.clone();
+                inp.add_alt_err(&before.inner /*&err.pos*/, err.err);
+            } else {
+                let err_span = inp.span_since(&before);
+                inp.add_alt(None, None, err_span);
+            }
+            return Err(());
         }
+        hashbrown::hash_map::Entry::Vacant(v) => {
+            v.insert(None);
+        }
+    }
 
-        res
+    let res = parser.go::<M>(inp);
+
+    if res.is_err() {
+        let alt = inp.take_alt();
+        inp.memos.insert(key, alt);
+    } else {
+        inp.memos.remove(&key);
     }
 
-    go_extra!(O);
+    res
 }
 
 /// See [`Parser::then`].
@@ -1214,11 +1563,35 @@ where
     go_extra!(O);
 }
 
+/// See [`Parser::with_fresh_state`].
+#[derive(Copy, Clone)]
+pub struct WithFreshState<A, F> {
+    pub(crate) parser: A,
+    pub(crate) make_state: F,
+}
+
+impl<'src, I, O, E, A, F, State> Parser<'src, I, O, E> for WithFreshState<A, F>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    A: Parser<'src, I, O, extra::Full<E::Error, State, E::Context>>,
+    F: Fn() -> State,
+    State: 'src + Inspector<'src, I>,
+{
+    #[inline(always)]
+    fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, O> {
+        inp.with_state(&mut (self.make_state)(), |inp| self.parser.go::<M>(inp))
+    }
+
+    go_extra!(O);
+}
+
 /// See [`Parser::delimited_by`].
 pub struct DelimitedBy<A, B, C, OB, OC> {
     pub(crate) parser: A,
     pub(crate) start: B,
     pub(crate) end: C,
+    pub(crate) report_unclosed: bool,
     #[allow(dead_code)]
     pub(crate) phantom: EmptyPhantom<(OB, OC)>,
 }
@@ -1230,34 +1603,324 @@ impl<A: Clone, B: Clone, C: Clone, OB, OC> Clone for DelimitedBy<A, B, C, OB, OC
             parser: self.parser.clone(),
             start: self.start.clone(),
             end: self.end.clone(),
+            report_unclosed: self.report_unclosed,
             phantom: EmptyPhantom::new(),
         }
     }
 }
 
-impl<'src, I, E, A, B, C, OA, OB, OC> Parser<'src, I, OA, E> for DelimitedBy<A, B, C, OB, OC>
-where
-    I: Input<'src>,
-    E: ParserExtra<'src, I>,
-    A: Parser<'src, I, OA, E>,
-    B: Parser<'src, I, OB, E>,
-    C: Parser<'src, I, OC, E>,
-{
-    #[inline(always)]
-    fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, OA> {
-        self.start.go::<Check>(inp)?;
-        let a = self.parser.go::<M>(inp)?;
-        self.end.go::<Check>(inp)?;
-        Ok(a)
-    }
-
-    go_extra!(OA);
-}
-
-/// See [`Parser::padded_by`].
-pub struct PaddedBy<A, B, OB> {
-    pub(crate) parser: A,
+impl<'src, A, B, C, OB, OC> DelimitedBy<A, B, C, OB, OC> {
+    /// When the closing delimiter is missing, attach the span of the opening delimiter to the error (see
+    /// [`Error::with_unclosed_delimiter`]) so that it can point out where the unclosed delimiter was opened.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let list = text::int::<_, extra::Err<Rich<char>>>(10)
+    ///     .separated_by(just(','))
+    ///     .collect::<Vec<_>>()
+    ///     .delimited_by(just('['), just(']'))
+    ///     .report_unclosed();
+    ///
+    /// let errs = list.parse("[1,2").into_errors();
+    /// assert_eq!(errs[0].unclosed_delimiter(), Some(&(0..1).into()));
+    /// assert_eq!(
+    ///     errs[0].to_string(),
+    ///     "found end of input expected ',', or ']' (unclosed delimiter opened at 0..1)",
+    /// );
+    /// ```
+    pub fn report_unclosed(self) -> Self {
+        Self {
+            report_unclosed: true,
+            ..self
+        }
+    }
+
+    /// Like [`DelimitedBy::report_unclosed`], but additionally recover from a missing closing delimiter by pretending
+    /// that it was present, provided that either the end of input has been reached or the `sync` pattern (which is
+    /// not consumed) matches.
+    ///
+    /// The error is still emitted, but parsing continues as if the delimiter had been closed. Use [`end`] as `sync`
+    /// to only recover at the end of input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let call = text::ascii::ident::<_, extra::Err<Rich<char>>>()
+    ///     .then(
+    ///         text::int(10)
+    ///             .separated_by(just(','))
+    ///             .collect::<Vec<_>>()
+    ///             .delimited_by(just('('), just(')'))
+    ///             .recover_unclosed(just(';')),
+    ///     )
+    ///     .then_ignore(just(';'));
+    ///
+    /// let (out, errs) = call.parse("foo(1,2;").into_output_errors();
+    /// assert_eq!(out, Some(("foo", vec!["1", "2"])));
+    /// assert_eq!(errs.len(), 1);
+    /// assert_eq!(errs[0].unclosed_delimiter(), Some(&(3..4).into()));
+    ///
+    /// // Recovery does not occur if the input continues with something unexpected
+    /// let (out, _) = call.parse("foo(1,2 3);").into_output_errors();
+    /// assert_eq!(out, None);
+    /// ```
+    pub fn recover_unclosed<D, OD>(self, sync: D) -> RecoverUnclosed<Self, D, OD> {
+        RecoverUnclosed {
+            parser: self.report_unclosed(),
+            sync,
+            phantom: EmptyPhantom::new(),
+        }
+    }
+
+    /// Also output the spans of the opening and closing delimiters alongside the output of the inner parser, which is
+    /// useful for features like formatters or bracket matching in editors.
+    ///
+    /// The output type of this parser is `(OA, I::Span, I::Span)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let block = text::ascii::ident::<_, extra::Err<Simple<char>>>()
+    ///     .padded()
+    ///     .delimited_by(just('{'), just('}'))
+    ///     .with_delimiter_spans();
+    ///
+    /// assert_eq!(
+    ///     block.parse("{ foo }").into_result(),
+    ///     Ok(("foo", (0..1).into(), (6..7).into())),
+    /// );
+    /// ```
+    pub fn with_delimiter_spans(self) -> WithDelimiterSpans<Self> {
+        WithDelimiterSpans { parser: self }
+    }
+
+    #[inline(always)]
+    fn go_unclosed<I, E, OA, M: Mode>(
+        &self,
+        inp: &mut InputRef<'src, '_, I, E>,
+        recover: impl FnMut(&mut InputRef<'src, '_, I, E>) -> bool,
+    ) -> PResult<M, OA>
+    where
+        I: Input<'src>,
+        E: ParserExtra<'src, I>,
+        A: Parser<'src, I, OA, E>,
+        B: Parser<'src, I, OB, E>,
+        C: Parser<'src, I, OC, E>,
+    {
+        self.go_spans::<I, E, OA, M>(inp, recover)
+            .map(|out| M::map(out, |(a, _, _)| a))
+    }
+
+    // Reports an unclosed delimiter if `report_unclosed` is set, recovering if `recover` returns `true`. The span of a
+    // recovered closing delimiter is the empty span at the point of recovery.
+    #[inline(always)]
+    fn go_spans<I, E, OA, M: Mode>(
+        &self,
+        inp: &mut InputRef<'src, '_, I, E>,
+        mut recover: impl FnMut(&mut InputRef<'src, '_, I, E>) -> bool,
+    ) -> PResult<M, (OA, I::Span, I::Span)>
+    where
+        I: Input<'src>,
+        E: ParserExtra<'src, I>,
+        A: Parser<'src, I, OA, E>,
+        B: Parser<'src, I, OB, E>,
+        C: Parser<'src, I, OC, E>,
+    {
+        let before = inp.cursor();
+        self.start.go::<Check>(inp)?;
+        let after_start = inp.cursor();
+        let a = self.parser.go::<M>(inp)?;
+        let before_end = inp.save();
+        match self.end.go::<Check>(inp) {
+            Ok(()) => {
+                let opened_at = inp.span(&before..&after_start);
+                let closed_at = inp.span_since(before_end.cursor());
+                Ok(M::map(a, |a| (a, opened_at, closed_at)))
+            }
+            Err(()) if !self.report_unclosed => Err(()),
+            Err(()) => {
+                inp.rewind(before_end);
+                let alt = inp.take_alt();
+                let opened_at = inp.span(&before..&after_start);
+                let alt =
+                    alt.map(|alt| Located::at(alt.pos, alt.err.with_unclosed_delimiter(opened_at)));
+                if recover(inp) {
+                    if let Some(alt) = alt {
+                        inp.emit(None, alt.err);
+                    }
+                    let opened_at = inp.span(&before..&after_start);
+                    let closed_at = inp.span_since(&inp.cursor());
+                    Ok(M::map(a, |a| (a, opened_at, closed_at)))
+                } else {
+                    inp.errors.alt = alt;
+                    Err(())
+                }
+            }
+        }
+    }
+}
+
+impl<'src, I, E, A, B, C, OA, OB, OC> Parser<'src, I, OA, E> for DelimitedBy<A, B, C, OB, OC>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    A: Parser<'src, I, OA, E>,
+    B: Parser<'src, I, OB, E>,
+    C: Parser<'src, I, OC, E>,
+{
+    #[inline(always)]
+    fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, OA> {
+        if self.report_unclosed {
+            return self.go_unclosed::<I, E, OA, M>(inp, |_| false);
+        }
+        self.start.go::<Check>(inp)?;
+        let a = self.parser.go::<M>(inp)?;
+        self.end.go::<Check>(inp)?;
+        Ok(a)
+    }
+
+    go_extra!(OA);
+}
+
+/// See [`DelimitedBy::recover_unclosed`].
+pub struct RecoverUnclosed<A, D, OD> {
+    pub(crate) parser: A,
+    pub(crate) sync: D,
+    #[allow(dead_code)]
+    pub(crate) phantom: EmptyPhantom<OD>,
+}
+
+impl<A: Copy, D: Copy, OD> Copy for RecoverUnclosed<A, D, OD> {}
+impl<A: Clone, D: Clone, OD> Clone for RecoverUnclosed<A, D, OD> {
+    fn clone(&self) -> Self {
+        Self {
+            parser: self.parser.clone(),
+            sync: self.sync.clone(),
+            phantom: EmptyPhantom::new(),
+        }
+    }
+}
+
+impl<A, D, OD> RecoverUnclosed<A, D, OD> {
+    #[inline(always)]
+    fn at_sync<'src, I, E>(&self, inp: &mut InputRef<'src, '_, I, E>) -> bool
+    where
+        I: Input<'src>,
+        E: ParserExtra<'src, I>,
+        D: Parser<'src, I, OD, E>,
+    {
+        let before = inp.save();
+        let alt = inp.take_alt();
+        let at_sync = inp.peek_maybe().is_none() || self.sync.go::<Check>(inp).is_ok();
+        inp.rewind(before);
+        inp.errors.alt = alt;
+        at_sync
+    }
+}
+
+impl<'src, I, E, A, B, C, D, OA, OB, OC, OD> Parser<'src, I, OA, E>
+    for RecoverUnclosed<DelimitedBy<A, B, C, OB, OC>, D, OD>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    A: Parser<'src, I, OA, E>,
+    B: Parser<'src, I, OB, E>,
+    C: Parser<'src, I, OC, E>,
+    D: Parser<'src, I, OD, E>,
+{
+    #[inline(always)]
+    fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, OA> {
+        self.parser
+            .go_unclosed::<I, E, OA, M>(inp, |inp| self.at_sync(inp))
+    }
+
+    go_extra!(OA);
+}
+
+impl<A, D, OD> RecoverUnclosed<A, D, OD> {
+    /// Also output the spans of the opening and closing delimiters, as with [`DelimitedBy::with_delimiter_spans`].
+    ///
+    /// When recovery occurs, the span of the missing closing delimiter is the empty span at the point where it was
+    /// expected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let block = text::ascii::ident::<_, extra::Err<Rich<char>>>()
+    ///     .padded()
+    ///     .delimited_by(just('{'), just('}'))
+    ///     .recover_unclosed(end())
+    ///     .with_delimiter_spans();
+    ///
+    /// let (out, errs) = block.parse("{ foo ").into_output_errors();
+    /// assert_eq!(out, Some(("foo", (0..1).into(), (6..6).into())));
+    /// assert_eq!(errs.len(), 1);
+    /// ```
+    pub fn with_delimiter_spans(self) -> WithDelimiterSpans<Self> {
+        WithDelimiterSpans { parser: self }
+    }
+}
+
+/// See [`DelimitedBy::with_delimiter_spans`] and [`RecoverUnclosed::with_delimiter_spans`].
+#[derive(Copy, Clone)]
+pub struct WithDelimiterSpans<A> {
+    pub(crate) parser: A,
+}
+
+impl<'src, I, E, A, B, C, OA, OB, OC> Parser<'src, I, (OA, I::Span, I::Span), E>
+    for WithDelimiterSpans<DelimitedBy<A, B, C, OB, OC>>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    A: Parser<'src, I, OA, E>,
+    B: Parser<'src, I, OB, E>,
+    C: Parser<'src, I, OC, E>,
+{
+    #[inline(always)]
+    fn go<M: Mode>(
+        &self,
+        inp: &mut InputRef<'src, '_, I, E>,
+    ) -> PResult<M, (OA, I::Span, I::Span)> {
+        self.parser.go_spans::<I, E, OA, M>(inp, |_| false)
+    }
+
+    go_extra!((OA, I::Span, I::Span));
+}
+
+impl<'src, I, E, A, B, C, D, OA, OB, OC, OD> Parser<'src, I, (OA, I::Span, I::Span), E>
+    for WithDelimiterSpans<RecoverUnclosed<DelimitedBy<A, B, C, OB, OC>, D, OD>>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    A: Parser<'src, I, OA, E>,
+    B: Parser<'src, I, OB, E>,
+    C: Parser<'src, I, OC, E>,
+    D: Parser<'src, I, OD, E>,
+{
+    #[inline(always)]
+    fn go<M: Mode>(
+        &self,
+        inp: &mut InputRef<'src, '_, I, E>,
+    ) -> PResult<M, (OA, I::Span, I::Span)> {
+        self.parser
+            .parser
+            .go_spans::<I, E, OA, M>(inp, |inp| self.parser.at_sync(inp))
+    }
+
+    go_extra!((OA, I::Span, I::Span));
+}
+
+/// See [`Parser::padded_by`], [`Parser::padded_by_leading`], and [`Parser::padded_by_trailing`].
+pub struct PaddedBy<A, B, OB> {
+    pub(crate) parser: A,
     pub(crate) padding: B,
+    pub(crate) leading: bool,
+    pub(crate) trailing: bool,
     #[allow(dead_code)]
     pub(crate) phantom: EmptyPhantom<OB>,
 }
@@ -1268,6 +1931,8 @@ impl<A: Clone, B: Clone, OB> Clone for PaddedBy<A, B, OB> {
         Self {
             parser: self.parser.clone(),
             padding: self.padding.clone(),
+            leading: self.leading,
+            trailing: self.trailing,
             phantom: EmptyPhantom::new(),
         }
     }
@@ -1282,9 +1947,49 @@ where
 {
     #[inline(always)]
     fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, OA> {
-        self.padding.go::<Check>(inp)?;
+        if self.leading {
+            self.padding.go::<Check>(inp)?;
+        }
         let a = self.parser.go::<M>(inp)?;
-        self.padding.go::<Check>(inp)?;
+        if self.trailing {
+            self.padding.go::<Check>(inp)?;
+        }
+        Ok(a)
+    }
+
+    go_extra!(OA);
+}
+
+/// See [`Parser::lexeme_with`].
+pub struct LexemeWith<A, B, OB> {
+    pub(crate) parser: A,
+    pub(crate) trivia: B,
+    #[allow(dead_code)]
+    pub(crate) phantom: EmptyPhantom<OB>,
+}
+
+impl<A: Copy, B: Copy, OB> Copy for LexemeWith<A, B, OB> {}
+impl<A: Clone, B: Clone, OB> Clone for LexemeWith<A, B, OB> {
+    fn clone(&self) -> Self {
+        Self {
+            parser: self.parser.clone(),
+            trivia: self.trivia.clone(),
+            phantom: EmptyPhantom::new(),
+        }
+    }
+}
+
+impl<'src, I, E, A, B, OA, OB> Parser<'src, I, OA, E> for LexemeWith<A, B, OB>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    A: Parser<'src, I, OA, E>,
+    B: Parser<'src, I, OB, E>,
+{
+    #[inline(always)]
+    fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, OA> {
+        let a = self.parser.go::<M>(inp)?;
+        self.trivia.go::<Check>(inp)?;
         Ok(a)
     }
 
@@ -1295,6 +2000,73 @@ where
 #[derive(Copy, Clone)]
 pub struct Or<A, B> {
     pub(crate) choice: crate::primitive::Choice<(A, B)>,
+    pub(crate) committed: bool,
+    #[cfg(debug_assertions)]
+    pub(crate) check_ambiguity: Option<Location<'static>>,
+}
+
+impl<A, B> Or<A, B> {
+    /// Do not attempt the second parser if the first parser failed after consuming input.
+    ///
+    /// By default, [`Parser::or`] has pure PEG semantics: if the first parser fails, the input is rewound and the
+    /// second parser is attempted, no matter how far the first parser got. A committed `or` instead treats the first
+    /// parser as having 'committed' to its branch once it has successfully parsed at least one token, and fails
+    /// immediately if it subsequently fails. This is similar to the behaviour of parser combinator libraries like
+    /// `nom` or `parsec`.
+    ///
+    /// Committing early can result in much better error messages (the error from the branch that was clearly
+    /// intended is reported, rather than an error from the alternative), and avoids repeatedly re-parsing the same
+    /// input in grammars with many long, overlapping alternatives.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let call = text::ascii::ident::<_, extra::Err<Rich<char>>>()
+    ///     .then_ignore(just("()"))
+    ///     .map(|name: &str| format!("call {name}"));
+    /// let var = text::ascii::ident().map(|name: &str| format!("var {name}"));
+    ///
+    /// // A regular `or` backtracks and tries the second branch...
+    /// assert_eq!(call.clone().or(var.clone()).parse("foo").into_result(), Ok("var foo".to_string()));
+    ///
+    /// // ...while a committed `or` does not, since `call` consumed input before failing
+    /// assert!(call.clone().or(var.clone()).committed().parse("foo").has_errors());
+    /// assert_eq!(call.or(var).committed().parse("bar()").into_result(), Ok("call bar".to_string()));
+    /// ```
+    pub fn committed(self) -> Self {
+        Self {
+            committed: true,
+            ..self
+        }
+    }
+
+    /// In debug builds, panic if both parsers match the input at the same position, but consume different amounts of
+    /// it.
+    ///
+    /// An `or` always picks the first parser that succeeds, so if both parsers can match the same input the result
+    /// silently depends on their order. This is a common source of bugs, such as a keyword parser that matches only the
+    /// prefix of an identifier. When enabled, the second parser is also tried whenever the first succeeds, which makes
+    /// parsing slower, so this is intended for use while developing a grammar. In release builds, this does nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use chumsky::prelude::*;
+    /// let keyword = just::<_, _, extra::Default>("in").to_slice();
+    /// let ident = text::ascii::ident();
+    ///
+    /// // Panics in debug builds: `in` matches the start of `int`, but `ident` matches all of it
+    /// keyword.or(ident).check_ambiguity().parse("int");
+    /// ```
+    #[track_caller]
+    pub fn check_ambiguity(self) -> Self {
+        Self {
+            #[cfg(debug_assertions)]
+            check_ambiguity: Some(*Location::caller()),
+            ..self
+        }
+    }
 }
 
 impl<'src, I, O, E, A, B> Parser<'src, I, O, E> for Or<A, B>
@@ -1306,7 +2078,68 @@ where
 {
     #[inline(always)]
     fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, O> {
-        self.choice.go::<M>(inp)
+        #[cfg(debug_assertions)]
+        if let Some(location) = self.check_ambiguity {
+            let (a, b) = &self.choice.parsers;
+            let before = inp.save();
+            let old_alt = inp.take_alt();
+            if let Ok(out) = a.go::<M>(inp) {
+                let after = inp.save();
+                inp.rewind(before.clone());
+                let b_end = b
+                    .go::<Check>(inp)
+                    .ok()
+                    .map(|()| I::cursor_location(inp.cursor().inner()));
+                inp.rewind(after.clone());
+                inp.errors.alt = old_alt;
+
+                let start = I::cursor_location(before.cursor().inner());
+                let a_end = I::cursor_location(after.cursor().inner());
+                if let Some(b_end) = b_end.filter(|b_end| *b_end != a_end) {
+                    panic!(
+                        "ambiguous `or` at {location}: both parsers match the input at offset {start}, but the first \
+                         ends at offset {a_end} while the second ends at offset {b_end}",
+                    );
+                }
+                return Ok(out);
+            }
+            // Fall through to parse normally, so that errors are reported in the usual way
+            inp.rewind(before);
+            inp.errors.alt = old_alt;
+        }
+
+        if !self.committed {
+            return self.choice.go::<M>(inp);
+        }
+
+        let (a, b) = &self.choice.parsers;
+        let before = inp.save();
+        let start = I::cursor_location(before.cursor().inner());
+
+        let old_alt = inp.take_alt();
+        let res = a.go::<M>(inp);
+        let new_alt = inp.take_alt();
+
+        // The first parser consumed input if it got past the starting token, either by advancing the cursor or by
+        // generating an error beyond the start
+        let consumed = I::cursor_location(inp.cursor().inner()) > start
+            || new_alt
+                .as_ref()
+                .map_or(false, |alt| I::cursor_location(&alt.pos) > start);
+
+        inp.errors.alt = old_alt;
+        if let Some(alt) = new_alt {
+            inp.add_alt_err(&alt.pos, alt.err);
+        }
+
+        match res {
+            Ok(out) => Ok(out),
+            Err(()) if consumed => Err(()),
+            Err(()) => {
+                inp.rewind(before);
+                b.go::<M>(inp)
+            }
+        }
     }
 
     go_extra!(O);
@@ -1315,8 +2148,8 @@ where
 /// Configuration for [`Parser::repeated`], used in [`ConfigParser::configure`].
 #[derive(Default)]
 pub struct RepeatedCfg {
-    at_least: Option<usize>,
-    at_most: Option<usize>,
+    pub(crate) at_least: Option<usize>,
+    pub(crate) at_most: Option<usize>,
 }
 
 impl RepeatedCfg {
@@ -1340,6 +2173,32 @@ impl RepeatedCfg {
     }
 }
 
+// An unbounded repetition of a parser that succeeds without consuming input would never terminate. This is a bug in
+// the grammar, so debug builds panic with the location of the combinator. Release builds fail with an error instead of
+// hanging.
+macro_rules! ensure_progress {
+    ($inp:expr, $before:expr, $name:literal, $location:expr) => {
+        let before = $before;
+        if $inp.cursor() == *before {
+            #[cfg(debug_assertions)]
+            panic!(
+                concat!("found ", $name, " combinator making no progress at {}"),
+                $location
+            );
+            #[cfg(not(debug_assertions))]
+            {
+                let span = $inp.span_since(before);
+                let found = $inp.peek_maybe();
+                $inp.add_alt_err(
+                    &before.inner,
+                    E::Error::custom(found, span, concat!($name, " combinator made no progress")),
+                );
+                return Err(());
+            }
+        }
+    };
+}
+
 /// See [`Parser::repeated`].
 pub struct Repeated<A, OA, I, E> {
     pub(crate) parser: A,
@@ -1440,44 +2299,319 @@ where
     A: Parser<'src, I, OA, E>,
 {
     #[inline(always)]
-    #[allow(clippy::nonminimal_bool)] // TODO: Remove this, lint is currently buggy
+    #[allow(clippy::nonminimal_bool)] // TODO: Remove this, lint is currently buggy
+    fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, ()> {
+        if self.at_most == !0 && self.at_least == 0 {
+            loop {
+                let before = inp.save();
+                match self.parser.go::<Check>(inp) {
+                    Ok(()) => {}
+                    Err(()) => {
+                        inp.rewind(before);
+                        break Ok(M::bind(|| ()));
+                    }
+                }
+                ensure_progress!(inp, before.cursor(), "Repeated", self.location);
+            }
+        } else {
+            let mut state = self.make_iter::<Check>(inp)?;
+            loop {
+                #[cfg(debug_assertions)]
+                let before = inp.cursor();
+                match self.next::<Check>(inp, &mut state) {
+                    Ok(Some(())) => {}
+                    Ok(None) => break Ok(M::bind(|| ())),
+                    // TODO: Technically we should be rewinding here: as-is, this is invalid since errorring parsers
+                    // are permitted to leave input state unspecified. Really, unwinding should occur *here* and not in
+                    // `next`.
+                    Err(()) => break Err(()),
+                }
+                #[cfg(debug_assertions)]
+                debug_assert!(
+                    before != inp.cursor(),
+                    "found Repeated combinator making no progress at {}",
+                    self.location,
+                );
+            }
+        }
+    }
+
+    go_extra!(());
+}
+
+impl<'src, A, O, I, E> IterParser<'src, I, O, E> for Repeated<A, O, I, E>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    A: Parser<'src, I, O, E>,
+{
+    type IterState<M: Mode> = usize;
+
+    #[inline(always)]
+    fn make_iter<M: Mode>(
+        &self,
+        _inp: &mut InputRef<'src, '_, I, E>,
+    ) -> PResult<Emit, Self::IterState<M>> {
+        Ok(0)
+    }
+
+    #[inline(always)]
+    fn next<M: Mode>(
+        &self,
+        inp: &mut InputRef<'src, '_, I, E>,
+        count: &mut Self::IterState<M>,
+    ) -> IPResult<M, O> {
+        if *count as u64 >= self.at_most {
+            return Ok(None);
+        }
+
+        let before = inp.save();
+        match self.parser.go::<M>(inp) {
+            Ok(item) => {
+                if self.at_most == !0 {
+                    ensure_progress!(inp, before.cursor(), "Repeated", self.location);
+                }
+                *count += 1;
+                Ok(Some(item))
+            }
+            Err(()) => {
+                inp.rewind(before);
+                if *count >= self.at_least {
+                    Ok(None)
+                } else {
+                    Err(())
+                }
+            }
+        }
+    }
+}
+
+impl<'src, A, O, I, E> ConfigIterParser<'src, I, O, E> for Repeated<A, O, I, E>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    A: Parser<'src, I, O, E>,
+{
+    type Config = RepeatedCfg;
+
+    #[inline(always)]
+    fn next_cfg<M: Mode>(
+        &self,
+        inp: &mut InputRef<'src, '_, I, E>,
+        count: &mut Self::IterState<M>,
+        cfg: &Self::Config,
+    ) -> IPResult<M, O> {
+        let at_most = cfg.at_most.map(|x| x as u64).unwrap_or(self.at_most);
+        let at_least = cfg.at_least.unwrap_or(self.at_least);
+
+        if *count as u64 >= at_most {
+            return Ok(None);
+        }
+
+        let before = inp.save();
+        match self.parser.go::<M>(inp) {
+            Ok(item) => {
+                if at_most == !0 {
+                    ensure_progress!(inp, before.cursor(), "Repeated", self.location);
+                }
+                *count += 1;
+                Ok(Some(item))
+            }
+            Err(()) => {
+                inp.rewind(before);
+                if *count >= at_least {
+                    Ok(None)
+                } else {
+                    Err(())
+                }
+            }
+        }
+    }
+}
+
+/// See [`Parser::repeated_till`].
+pub struct RepeatedTill<A, B, OA, OB, C, I, E> {
+    pub(crate) parser: A,
+    pub(crate) terminator: B,
+    pub(crate) consume_terminator: bool,
+    #[cfg(debug_assertions)]
+    pub(crate) location: Location<'static>,
+    #[allow(dead_code)]
+    pub(crate) phantom: EmptyPhantom<(OA, OB, C, E, I)>,
+}
+
+impl<A: Copy, B: Copy, OA, OB, C, I, E> Copy for RepeatedTill<A, B, OA, OB, C, I, E> {}
+impl<A: Clone, B: Clone, OA, OB, C, I, E> Clone for RepeatedTill<A, B, OA, OB, C, I, E> {
+    fn clone(&self) -> Self {
+        Self {
+            parser: self.parser.clone(),
+            terminator: self.terminator.clone(),
+            consume_terminator: self.consume_terminator,
+            #[cfg(debug_assertions)]
+            location: self.location,
+            phantom: EmptyPhantom::new(),
+        }
+    }
+}
+
+impl<'src, A, B, OA, OB, C, I, E> RepeatedTill<A, B, OA, OB, C, I, E>
+where
+    A: Parser<'src, I, OA, E>,
+    B: Parser<'src, I, OB, E>,
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+{
+    /// Collect the items into a different kind of [`Container`] (by default, a [`Vec`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let line = any::<_, extra::Err<Simple<char>>>()
+    ///     .repeated_till(just('\n'))
+    ///     .collect::<String>();
+    ///
+    /// assert_eq!(line.parse("So long\n").into_result(), Ok(("So long".to_string(), '\n')));
+    /// ```
+    pub fn collect<D: Container<OA>>(self) -> RepeatedTill<A, B, OA, OB, D, I, E> {
+        RepeatedTill {
+            parser: self.parser,
+            terminator: self.terminator,
+            consume_terminator: self.consume_terminator,
+            #[cfg(debug_assertions)]
+            location: self.location,
+            phantom: EmptyPhantom::new(),
+        }
+    }
+
+    /// Whether to consume the terminator (default: true). If not, the terminator is only peeked at and is left in the
+    /// input for the next parser, much like [`Parser::rewind`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let until_semicolon = any::<_, extra::Err<Simple<char>>>()
+    ///     .repeated_till(just(';'))
+    ///     .consume_terminator(false)
+    ///     .map(|(items, _)| items.len());
+    ///
+    /// assert_eq!(until_semicolon.then(just(';')).parse("abc;").into_result(), Ok((3, ';')));
+    /// ```
+    pub fn consume_terminator(self, consume_terminator: bool) -> Self {
+        Self {
+            consume_terminator,
+            ..self
+        }
+    }
+}
+
+impl<'src, A, B, OA, OB, C, I, E> Parser<'src, I, (C, OB), E>
+    for RepeatedTill<A, B, OA, OB, C, I, E>
+where
+    A: Parser<'src, I, OA, E>,
+    B: Parser<'src, I, OB, E>,
+    C: Container<OA>,
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+{
+    #[inline(always)]
+    fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, (C, OB)> {
+        let mut output = M::bind::<C, _>(|| C::default());
+        loop {
+            let start = inp.cursor();
+            let before = inp.save();
+            match self.terminator.go::<M>(inp) {
+                Ok(terminator) => {
+                    if !self.consume_terminator {
+                        inp.rewind(before);
+                    }
+                    break Ok(M::combine(output, terminator, |output, terminator| {
+                        (output, terminator)
+                    }));
+                }
+                Err(()) => inp.rewind(before),
+            }
+
+            // The terminator didn't match, so an item must. If it doesn't, the error produced by the terminator is
+            // merged with that of the item (both having failed at the same location).
+            let item = self.parser.go::<M>(inp)?;
+            M::combine_mut(&mut output, item, |output: &mut C, item| output.push(item));
+
+            ensure_progress!(inp, &start, "RepeatedTill", self.location);
+        }
+    }
+
+    go_extra!((C, OB));
+}
+
+/// See [`Parser::lazy_repeated`].
+pub struct LazyRepeated<A, B, OA, OB, I, E> {
+    pub(crate) parser: A,
+    pub(crate) follow: B,
+    pub(crate) at_least: usize,
+    #[cfg(debug_assertions)]
+    pub(crate) location: Location<'static>,
+    #[allow(dead_code)]
+    pub(crate) phantom: EmptyPhantom<(OA, OB, E, I)>,
+}
+
+impl<A: Copy, B: Copy, OA, OB, I, E> Copy for LazyRepeated<A, B, OA, OB, I, E> {}
+impl<A: Clone, B: Clone, OA, OB, I, E> Clone for LazyRepeated<A, B, OA, OB, I, E> {
+    fn clone(&self) -> Self {
+        Self {
+            parser: self.parser.clone(),
+            follow: self.follow.clone(),
+            at_least: self.at_least,
+            #[cfg(debug_assertions)]
+            location: self.location,
+            phantom: EmptyPhantom::new(),
+        }
+    }
+}
+
+impl<'src, A, B, OA, OB, I, E> LazyRepeated<A, B, OA, OB, I, E>
+where
+    A: Parser<'src, I, OA, E>,
+    B: Parser<'src, I, OB, E>,
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+{
+    /// Require that the pattern appear at least a minimum number of times before the following pattern is looked for,
+    /// like regex's `{n,}?`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let dashes = just::<_, _, extra::Err<Simple<char>>>('-')
+    ///     .lazy_repeated(just('-'))
+    ///     .at_least(2)
+    ///     .count();
+    ///
+    /// assert_eq!(dashes.then_ignore(just("---")).parse("-----").into_result(), Ok(2));
+    /// assert!(dashes.parse("-").has_errors());
+    /// ```
+    pub fn at_least(self, at_least: usize) -> Self {
+        Self { at_least, ..self }
+    }
+}
+
+impl<'src, I, E, A, B, OA, OB> Parser<'src, I, (), E> for LazyRepeated<A, B, OA, OB, I, E>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    A: Parser<'src, I, OA, E>,
+    B: Parser<'src, I, OB, E>,
+{
+    #[inline(always)]
     fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, ()> {
-        if self.at_most == !0 && self.at_least == 0 {
-            loop {
-                let before = inp.save();
-                match self.parser.go::<Check>(inp) {
-                    Ok(()) => {}
-                    Err(()) => {
-                        inp.rewind(before);
-                        break Ok(M::bind(|| ()));
-                    }
-                }
-                #[cfg(debug_assertions)]
-                debug_assert!(
-                    *before.cursor() != inp.cursor(),
-                    "found Repeated combinator making no progress at {}",
-                    self.location,
-                );
-            }
-        } else {
-            let mut state = self.make_iter::<Check>(inp)?;
-            loop {
-                #[cfg(debug_assertions)]
-                let before = inp.cursor();
-                match self.next::<Check>(inp, &mut state) {
-                    Ok(Some(())) => {}
-                    Ok(None) => break Ok(M::bind(|| ())),
-                    // TODO: Technically we should be rewinding here: as-is, this is invalid since errorring parsers
-                    // are permitted to leave input state unspecified. Really, unwinding should occur *here* and not in
-                    // `next`.
-                    Err(()) => break Err(()),
-                }
-                #[cfg(debug_assertions)]
-                debug_assert!(
-                    before != inp.cursor(),
-                    "found Repeated combinator making no progress at {}",
-                    self.location,
-                );
+        let mut state = self.make_iter::<Check>(inp)?;
+        loop {
+            match self.next::<Check>(inp, &mut state) {
+                Ok(Some(())) => {}
+                Ok(None) => break Ok(M::bind(|| ())),
+                Err(()) => break Err(()),
             }
         }
     }
@@ -1485,11 +2619,12 @@ where
     go_extra!(());
 }
 
-impl<'src, A, O, I, E> IterParser<'src, I, O, E> for Repeated<A, O, I, E>
+impl<'src, A, B, OA, OB, I, E> IterParser<'src, I, OA, E> for LazyRepeated<A, B, OA, OB, I, E>
 where
     I: Input<'src>,
     E: ParserExtra<'src, I>,
-    A: Parser<'src, I, O, E>,
+    A: Parser<'src, I, OA, E>,
+    B: Parser<'src, I, OB, E>,
 {
     type IterState<M: Mode> = usize;
 
@@ -1506,66 +2641,80 @@ where
         &self,
         inp: &mut InputRef<'src, '_, I, E>,
         count: &mut Self::IterState<M>,
-    ) -> IPResult<M, O> {
-        if *count as u64 >= self.at_most {
-            return Ok(None);
+    ) -> IPResult<M, OA> {
+        if *count >= self.at_least {
+            // The following pattern is only peeked at, never consumed
+            let before = inp.save();
+            let matched = self.follow.go::<Check>(inp).is_ok();
+            inp.rewind(before);
+            if matched {
+                return Ok(None);
+            }
         }
 
         let before = inp.save();
+
+        // The following pattern didn't match, so an item must. If it doesn't, the error produced by the following
+        // pattern is merged with that of the item (both having failed at the same location).
         match self.parser.go::<M>(inp) {
             Ok(item) => {
+                ensure_progress!(inp, before.cursor(), "LazyRepeated", self.location);
                 *count += 1;
                 Ok(Some(item))
             }
             Err(()) => {
                 inp.rewind(before);
-                if *count >= self.at_least {
-                    Ok(None)
-                } else {
-                    Err(())
-                }
+                Err(())
             }
         }
     }
 }
 
-impl<'src, A, O, I, E> ConfigIterParser<'src, I, O, E> for Repeated<A, O, I, E>
-where
-    I: Input<'src>,
-    E: ParserExtra<'src, I>,
-    A: Parser<'src, I, O, E>,
-{
-    type Config = RepeatedCfg;
+/// Configuration for [`Parser::separated_by`], used in [`ConfigIterParser::configure`].
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// let int = text::int::<_, extra::Err<Rich<char>>>(10)
+///     .from_str::<usize>()
+///     .unwrapped();
+///
+/// // A length-prefixed, comma-separated row of identifiers
+/// let row = int.then_ignore(just(':')).ignore_with_ctx(
+///     text::ascii::ident()
+///         .separated_by(just(','))
+///         .configure(|cfg, ctx| cfg.exactly(*ctx))
+///         .collect::<Vec<_>>(),
+/// );
+///
+/// assert_eq!(row.parse("2:a,b").into_result(), Ok(vec!["a", "b"]));
+/// assert!(row.parse("3:a,b").has_errors());
+/// ```
+#[derive(Default)]
+pub struct SeparatedByCfg {
+    at_least: Option<usize>,
+    at_most: Option<usize>,
+}
 
-    #[inline(always)]
-    fn next_cfg<M: Mode>(
-        &self,
-        inp: &mut InputRef<'src, '_, I, E>,
-        count: &mut Self::IterState<M>,
-        cfg: &Self::Config,
-    ) -> IPResult<M, O> {
-        let at_most = cfg.at_most.map(|x| x as u64).unwrap_or(self.at_most);
-        let at_least = cfg.at_least.unwrap_or(self.at_least);
+impl SeparatedByCfg {
+    /// Set the minimum number of items accepted
+    pub fn at_least(mut self, n: usize) -> Self {
+        self.at_least = Some(n);
+        self
+    }
 
-        if *count as u64 >= at_most {
-            return Ok(None);
-        }
+    /// Set the maximum number of items accepted
+    pub fn at_most(mut self, n: usize) -> Self {
+        self.at_most = Some(n);
+        self
+    }
 
-        let before = inp.save();
-        match self.parser.go::<M>(inp) {
-            Ok(item) => {
-                *count += 1;
-                Ok(Some(item))
-            }
-            Err(()) => {
-                inp.rewind(before);
-                if *count >= at_least {
-                    Ok(None)
-                } else {
-                    Err(())
-                }
-            }
-        }
+    /// Set an exact number of items to accept
+    pub fn exactly(mut self, n: usize) -> Self {
+        self.at_least = Some(n);
+        self.at_most = Some(n);
+        self
     }
 }
 
@@ -1736,8 +2885,282 @@ where
             ..self
         }
     }
+
+    /// Collect exactly `C::LEN` items into a container, along with the outputs of the `C::LEN - 1` separators between
+    /// them.
+    ///
+    /// This is useful for fixed-arity syntax, like coordinates or dates, where the separators are needed to validate or
+    /// reconstruct the input. Leading and trailing separators (if permitted) are not included in the output.
+    ///
+    /// Using containers whose lengths do not satisfy `CS::LEN + 1 == C::LEN` is a compile-time error.
+    ///
+    /// The output type of this parser is `(C, CS)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let date = text::int::<_, extra::Err<Rich<char>>>(10)
+    ///     .separated_by(one_of("-/"))
+    ///     .collect_exactly_with_separators::<[_; 3], [_; 2]>()
+    ///     .validate(|(parts, seps), e, emitter| {
+    ///         if seps[0] != seps[1] {
+    ///             emitter.emit(Rich::custom(e.span(), "inconsistent separators"));
+    ///         }
+    ///         parts
+    ///     });
+    ///
+    /// assert_eq!(date.parse("2024-7-31").into_result(), Ok(["2024", "7", "31"]));
+    /// assert_eq!(date.parse("2024/7/31").into_result(), Ok(["2024", "7", "31"]));
+    /// // Separators must be consistent
+    /// assert!(date.parse("2024-7/31").has_errors());
+    /// // Not enough parts
+    /// assert!(date.parse("2024-7").has_errors());
+    /// ```
+    pub fn collect_exactly_with_separators<C, CS>(
+        self,
+    ) -> SeparatedByExactly<A, B, OA, OB, C, CS, I, E>
+    where
+        C: ContainerExactly<OA>,
+        CS: ContainerExactly<OB>,
+    {
+        SeparatedByExactly {
+            parser: self.parser,
+            separator: self.separator,
+            allow_leading: self.allow_leading,
+            allow_trailing: self.allow_trailing,
+            phantom: EmptyPhantom::new(),
+        }
+    }
+}
+
+/// See [`SeparatedBy::collect_exactly_with_separators`].
+pub struct SeparatedByExactly<A, B, OA, OB, C, CS, I, E> {
+    pub(crate) parser: A,
+    pub(crate) separator: B,
+    pub(crate) allow_leading: bool,
+    pub(crate) allow_trailing: bool,
+    #[allow(dead_code)]
+    pub(crate) phantom: EmptyPhantom<(OA, OB, C, CS, E, I)>,
+}
+
+impl<A: Copy, B: Copy, OA, OB, C, CS, I, E> Copy for SeparatedByExactly<A, B, OA, OB, C, CS, I, E> {}
+impl<A: Clone, B: Clone, OA, OB, C, CS, I, E> Clone
+    for SeparatedByExactly<A, B, OA, OB, C, CS, I, E>
+{
+    fn clone(&self) -> Self {
+        Self {
+            parser: self.parser.clone(),
+            separator: self.separator.clone(),
+            allow_leading: self.allow_leading,
+            allow_trailing: self.allow_trailing,
+            phantom: EmptyPhantom::new(),
+        }
+    }
+}
+
+impl<A, B, OA, OB, C, CS, I, E> SeparatedByExactly<A, B, OA, OB, C, CS, I, E>
+where
+    C: ContainerExactly<OA>,
+    CS: ContainerExactly<OB>,
+{
+    // Evaluated when the parser is instantiated, making a mismatched number of separators a compile error
+    const SEPARATOR_COUNT: () = assert!(
+        CS::LEN + 1 == C::LEN,
+        "there must be exactly one fewer separator than there are items"
+    );
+}
+
+impl<'src, I, E, A, B, OA, OB, C, CS> Parser<'src, I, (C, CS), E>
+    for SeparatedByExactly<A, B, OA, OB, C, CS, I, E>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    A: Parser<'src, I, OA, E>,
+    B: Parser<'src, I, OB, E>,
+    C: ContainerExactly<OA>,
+    CS: ContainerExactly<OB>,
+{
+    #[inline]
+    fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, (C, CS)> {
+        let () = Self::SEPARATOR_COUNT;
+
+        if self.allow_leading {
+            let before = inp.save();
+            if self.separator.go::<Check>(inp).is_err() {
+                inp.rewind(before);
+            }
+        }
+
+        let mut items = M::bind(|| C::uninit());
+        let mut seps = M::bind(|| CS::uninit());
+        for idx in 0..C::LEN {
+            let sep = if idx > 0 {
+                self.separator.go::<M>(inp).map(Some)
+            } else {
+                Ok(None)
+            };
+            let res = sep.and_then(|sep| self.parser.go::<M>(inp).map(|item| (sep, item)));
+            match res {
+                Ok((sep, item)) => {
+                    if let Some(sep) = sep {
+                        M::combine_mut(&mut seps, sep, |c, sep| CS::write(c, idx - 1, sep));
+                    }
+                    M::combine_mut(&mut items, item, |c, item| C::write(c, idx, item));
+                }
+                Err(()) => {
+                    // SAFETY: We're guaranteed to have initialized up to `idx` items
+                    M::map(items, |mut items| unsafe {
+                        C::drop_before(&mut items, idx)
+                    });
+                    // SAFETY: We're guaranteed to have initialized up to `idx - 1` separators (a separator parsed
+                    // before a failing item is dropped by `and_then`)
+                    M::map(seps, |mut seps| unsafe {
+                        CS::drop_before(&mut seps, idx.saturating_sub(1))
+                    });
+                    return Err(());
+                }
+            }
+        }
+
+        if self.allow_trailing {
+            let before = inp.save();
+            if self.separator.go::<Check>(inp).is_err() {
+                inp.rewind(before);
+            }
+        }
+
+        // SAFETY: If we reach this point, we guarantee to have initialized all items and separators
+        Ok(M::combine(items, seps, |items, seps| unsafe {
+            (C::take(items), CS::take(seps))
+        }))
+    }
+
+    go_extra!((C, CS));
+}
+
+/// See [`Parser::interleave`].
+pub struct Interleave<A, B, OA, OB, I, E> {
+    pub(crate) parser: A,
+    pub(crate) separator: B,
+    pub(crate) allow_trailing: bool,
+    #[cfg(debug_assertions)]
+    pub(crate) location: Location<'static>,
+    #[allow(dead_code)]
+    pub(crate) phantom: EmptyPhantom<(OA, OB, E, I)>,
+}
+
+impl<A: Copy, B: Copy, OA, OB, I, E> Copy for Interleave<A, B, OA, OB, I, E> {}
+impl<A: Clone, B: Clone, OA, OB, I, E> Clone for Interleave<A, B, OA, OB, I, E> {
+    fn clone(&self) -> Self {
+        Self {
+            parser: self.parser.clone(),
+            separator: self.separator.clone(),
+            allow_trailing: self.allow_trailing,
+            #[cfg(debug_assertions)]
+            location: self.location,
+            phantom: EmptyPhantom::new(),
+        }
+    }
+}
+
+impl<A, B, OA, OB, I, E> Interleave<A, B, OA, OB, I, E> {
+    /// Allow a trailing separator to appear after the last item. If present, it is included in the separators.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let items = text::int::<_, extra::Err<Simple<char>>>(10)
+    ///     .interleave(just(','))
+    ///     .allow_trailing();
+    ///
+    /// let (nums, commas) = items.parse("1,2,").into_result().unwrap();
+    /// assert_eq!(nums.len(), 2);
+    /// assert_eq!(commas.len(), 2);
+    /// ```
+    pub fn allow_trailing(self) -> Self {
+        Self {
+            allow_trailing: true,
+            ..self
+        }
+    }
+}
+
+impl<'src, I, E, A, B, OA, OB> Parser<'src, I, InterleaveOutput<OA, OB, I::Span>, E>
+    for Interleave<A, B, OA, OB, I, E>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    A: Parser<'src, I, OA, E>,
+    B: Parser<'src, I, OB, E>,
+{
+    #[inline]
+    fn go<M: Mode>(
+        &self,
+        inp: &mut InputRef<'src, '_, I, E>,
+    ) -> PResult<M, InterleaveOutput<OA, OB, I::Span>> {
+        let mut items = M::bind(Vec::new);
+        let mut seps = M::bind(Vec::new);
+
+        let before = inp.save();
+        match self.parser.go::<M>(inp) {
+            Ok(item) => {
+                let span = inp.span_since(before.cursor());
+                M::combine_mut(&mut items, item, |items, item| items.push((item, span)));
+            }
+            Err(()) => {
+                inp.rewind(before);
+                return Ok(M::combine(items, seps, |items, seps| (items, seps)));
+            }
+        }
+
+        loop {
+            let before = inp.save();
+            let sep = match self.separator.go::<M>(inp) {
+                Ok(sep) => sep,
+                Err(()) => {
+                    inp.rewind(before);
+                    break;
+                }
+            };
+            let sep_span = inp.span_since(before.cursor());
+
+            let after_sep = inp.save();
+            match self.parser.go::<M>(inp) {
+                Ok(item) => {
+                    let span = inp.span_since(after_sep.cursor());
+                    M::combine_mut(&mut seps, sep, |seps, sep| seps.push((sep, sep_span)));
+                    M::combine_mut(&mut items, item, |items, item| items.push((item, span)));
+                }
+                Err(()) if self.allow_trailing => {
+                    inp.rewind(after_sep);
+                    M::combine_mut(&mut seps, sep, |seps, sep| seps.push((sep, sep_span)));
+                    break;
+                }
+                Err(()) => {
+                    inp.rewind(before);
+                    break;
+                }
+            }
+
+            #[cfg(debug_assertions)]
+            debug_assert!(
+                *before.cursor() != inp.cursor(),
+                "found Interleave combinator making no progress at {}",
+                self.location,
+            );
+        }
+
+        Ok(M::combine(items, seps, |items, seps| (items, seps)))
+    }
+
+    go_extra!(InterleaveOutput<OA, OB, I::Span>);
 }
 
+/// The output of [`Parser::interleave`]: the items and the separators between them, each paired with its span.
+pub type InterleaveOutput<OA, OB, S> = (Vec<(OA, S)>, Vec<(OB, S)>);
+
 impl<'src, I, E, A, B, OA, OB> IterParser<'src, I, OA, E> for SeparatedBy<A, B, OA, OB, I, E>
 where
     I: Input<'src>,
@@ -1764,10 +3187,56 @@ where
         inp: &mut InputRef<'src, '_, I, E>,
         state: &mut Self::IterState<M>,
     ) -> IPResult<M, OA> {
-        if *state as u64 >= self.at_most {
+        self.next_bounded::<M>(inp, state, self.at_least, self.at_most)
+    }
+}
+
+impl<'src, I, E, A, B, OA, OB> ConfigIterParser<'src, I, OA, E> for SeparatedBy<A, B, OA, OB, I, E>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    A: Parser<'src, I, OA, E>,
+    B: Parser<'src, I, OB, E>,
+{
+    type Config = SeparatedByCfg;
+
+    #[inline(always)]
+    fn next_cfg<M: Mode>(
+        &self,
+        inp: &mut InputRef<'src, '_, I, E>,
+        state: &mut Self::IterState<M>,
+        cfg: &Self::Config,
+    ) -> IPResult<M, OA> {
+        let at_least = cfg.at_least.unwrap_or(self.at_least);
+        let at_most = cfg.at_most.map(|x| x as u64).unwrap_or(self.at_most);
+        self.next_bounded::<M>(inp, state, at_least, at_most)
+    }
+}
+
+impl<'src, I, E, A, B, OA, OB> SeparatedBy<A, B, OA, OB, I, E>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    A: Parser<'src, I, OA, E>,
+    B: Parser<'src, I, OB, E>,
+{
+    #[inline(always)]
+    fn next_bounded<M: Mode>(
+        &self,
+        inp: &mut InputRef<'src, '_, I, E>,
+        state: &mut usize,
+        at_least: usize,
+        at_most: u64,
+    ) -> IPResult<M, OA> {
+        if *state as u64 >= at_most {
             return Ok(None);
         }
 
+        // Fast path: a missing separator that can be spotted by peeking needs no checkpoint to rewind to
+        if *state > 0 && self.separator.fails_on_peek(inp) {
+            return if *state < at_least { Err(()) } else { Ok(None) };
+        }
+
         let before_separator = inp.save();
         if *state == 0 && self.allow_leading {
             if self.separator.go::<Check>(inp).is_err() {
@@ -1778,7 +3247,7 @@ where
                 Ok(()) => {
                     // Do nothing
                 }
-                Err(()) if *state < self.at_least => {
+                Err(()) if *state < at_least => {
                     inp.rewind(before_separator);
                     return Err(());
                 }
@@ -1789,13 +3258,23 @@ where
             }
         }
 
-        let before_item = inp.save();
+        // The checkpoint after the separator is only ever used to keep a trailing separator, so avoid creating it in
+        // the (common) case where trailing separators are not permitted.
+        let before_item = if self.allow_trailing {
+            Some(inp.save())
+        } else {
+            None
+        };
         match self.parser.go::<M>(inp) {
             Ok(item) => {
+                // The first item may consume nothing, since the next iteration will need to parse a separator
+                if *state > 0 && at_most == !0 {
+                    ensure_progress!(inp, before_separator.cursor(), "SeparatedBy", self.location);
+                }
                 *state += 1;
                 Ok(Some(item))
             }
-            Err(()) if *state < self.at_least => {
+            Err(()) if *state < at_least => {
                 // We have errored before we have reached the count,
                 // and therefore should return this error, as we are
                 // still expecting items
@@ -1808,11 +3287,7 @@ where
 
                 // though if we don't allow trailing, we shouldn't have
                 // consumed the separator, so we need to rewind it.
-                if self.allow_trailing {
-                    inp.rewind(before_item);
-                } else {
-                    inp.rewind(before_separator);
-                }
+                inp.rewind(before_item.unwrap_or(before_separator));
                 Ok(None)
             }
         }
@@ -2025,6 +3500,43 @@ where
     go_extra!(C);
 }
 
+/// See [`Parser::or_hole`].
+#[derive(Copy, Clone)]
+pub struct OrHole<A> {
+    pub(crate) parser: A,
+}
+
+impl<'src, I, O, E, A> Parser<'src, I, Option<O>, E> for OrHole<A>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    A: Parser<'src, I, O, E>,
+{
+    #[inline(always)]
+    fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, Option<O>> {
+        let before = inp.save();
+        match self.parser.go::<M>(inp) {
+            Ok(out) => Ok(M::map(out, Some)),
+            Err(()) if inp.errors.holes => {
+                inp.rewind(before);
+                if let Some(alt) = inp.take_alt() {
+                    // Avoid a cascade of errors from several holes being filled in the same place
+                    let repeated = inp.errors.secondary.last().map_or(false, |last| {
+                        I::cursor_location(&last.pos) == I::cursor_location(&alt.pos)
+                    });
+                    if !repeated {
+                        inp.errors.secondary.push(alt);
+                    }
+                }
+                Ok(M::bind(|| None))
+            }
+            Err(()) => Err(()),
+        }
+    }
+
+    go_extra!(Option<O>);
+}
+
 /// See [`Parser::or_not`].
 #[derive(Copy, Clone)]
 pub struct OrNot<A> {
@@ -2052,6 +3564,71 @@ where
     go_extra!(Option<O>);
 }
 
+/// See [`Parser::or_default`].
+#[derive(Copy, Clone)]
+pub struct OrDefault<A> {
+    pub(crate) parser: A,
+}
+
+impl<'src, I, O, E, A> Parser<'src, I, O, E> for OrDefault<A>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    A: Parser<'src, I, O, E>,
+    O: Default,
+{
+    #[inline(always)]
+    fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, O> {
+        let before = inp.save();
+        Ok(match self.parser.go::<M>(inp) {
+            Ok(out) => out,
+            Err(()) => {
+                inp.rewind(before);
+                M::bind(O::default)
+            }
+        })
+    }
+
+    go_extra!(O);
+}
+
+/// See [`Parser::or_else_with`].
+#[derive(Copy, Clone)]
+pub struct OrElseWith<A, F> {
+    pub(crate) parser: A,
+    pub(crate) fallback: F,
+}
+
+impl<'src, I, O, E, A, F> Parser<'src, I, O, E> for OrElseWith<A, F>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    A: Parser<'src, I, O, E>,
+    F: Fn(&E::Error) -> O,
+{
+    #[inline(always)]
+    fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, O> {
+        let before = inp.save();
+        match self.parser.go::<M>(inp) {
+            Ok(out) => Ok(out),
+            Err(()) => {
+                inp.rewind(before);
+                Ok(M::bind(|| match &inp.errors.alt {
+                    Some(alt) => (self.fallback)(&alt.err),
+                    // Zero-sized errors are never recorded, so there's nothing to lose by creating a new one
+                    None => {
+                        let cursor = inp.cursor();
+                        let span = inp.span_since(&cursor);
+                        (self.fallback)(&E::Error::expected_found(None, None, span))
+                    }
+                }))
+            }
+        }
+    }
+
+    go_extra!(O);
+}
+
 impl<'src, A, O, I, E> IterParser<'src, I, O, E> for OrNot<A>
 where
     I: Input<'src>,
@@ -2245,7 +3822,7 @@ where
 {
     #[inline(always)]
     fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, OA> {
-        let before = inp.save().clone();
+        let before = inp.save();
         match self.parser_a.go::<M>(inp) {
             Ok(out) => {
                 // A succeeded -- go back to the beginning and try B
@@ -2275,6 +3852,84 @@ where
     go_extra!(OA);
 }
 
+/// See [`Parser::except`].
+pub struct Except<A, B, OB, M = &'static str> {
+    pub(crate) parser_a: A,
+    pub(crate) parser_b: B,
+    pub(crate) message: Option<M>,
+    #[allow(dead_code)]
+    pub(crate) phantom: EmptyPhantom<OB>,
+}
+
+impl<A: Copy, B: Copy, OB, M: Copy> Copy for Except<A, B, OB, M> {}
+impl<A: Clone, B: Clone, OB, M: Clone> Clone for Except<A, B, OB, M> {
+    fn clone(&self) -> Self {
+        Self {
+            parser_a: self.parser_a.clone(),
+            parser_b: self.parser_b.clone(),
+            message: self.message.clone(),
+            phantom: EmptyPhantom::new(),
+        }
+    }
+}
+
+impl<A, B, OB, M> Except<A, B, OB, M> {
+    /// Report excluded inputs with a custom message (via [`Error::custom`]), such as
+    /// `"reserved word used as identifier"`, rather than as an unexpected token.
+    pub fn with_message<N: fmt::Display>(self, message: N) -> Except<A, B, OB, N> {
+        Except {
+            parser_a: self.parser_a,
+            parser_b: self.parser_b,
+            message: Some(message),
+            phantom: EmptyPhantom::new(),
+        }
+    }
+}
+
+impl<'src, I, E, A, B, OA, OB, M> Parser<'src, I, OA, E> for Except<A, B, OB, M>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    A: Parser<'src, I, OA, E>,
+    B: Parser<'src, I, OB, E>,
+    M: fmt::Display,
+{
+    #[inline]
+    fn go<M2: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M2, OA> {
+        let before = inp.save();
+        let out = self.parser_a.go::<M2>(inp)?;
+        let after = inp.save();
+        inp.rewind(before.clone());
+
+        // Errors produced while checking for the excluded pattern are not relevant to the user
+        let alt = inp.errors.alt.take();
+        let excluded = self.parser_b.go::<Check>(inp).is_ok() && &inp.cursor() == after.cursor();
+
+        if excluded {
+            // As with `try_map`, the rejection replaces any alternatives that the first parser left behind
+            let span = inp.span(before.cursor()..after.cursor());
+            inp.rewind(before.clone());
+            match &self.message {
+                Some(message) => {
+                    let found = inp.peek_maybe();
+                    inp.add_alt_err(&before.cursor().inner, Error::custom(found, span, message))
+                }
+                None => {
+                    let found = inp.peek_maybe();
+                    inp.add_alt(None, found, span);
+                }
+            }
+            Err(())
+        } else {
+            inp.errors.alt = alt;
+            inp.rewind(after);
+            Ok(out)
+        }
+    }
+
+    go_extra!(OA);
+}
+
 /// See [`IterParser::foldr`].
 pub struct Foldr<F, A, B, OA, E> {
     pub(crate) parser_a: A,
@@ -2604,40 +4259,44 @@ where
     go_extra!(O);
 }
 
-// /// See [`Parser::map_err_with_span`].
-// #[derive(Copy, Clone)]
-// pub struct MapErrWithSpan<A, F> {
-//     pub(crate) parser: A,
-//     pub(crate) mapper: F,
-// }
+/// See [`Parser::map_err_with_span`].
+#[derive(Copy, Clone)]
+pub struct MapErrWithSpan<A, F> {
+    pub(crate) parser: A,
+    pub(crate) mapper: F,
+}
 
-// impl<'src, I, O, E, A, F> Parser<'src, I, O, E> for MapErrWithSpan<A, F>
-// where
-//     I: Input<'src>,
-//     E: ParserExtra<'src, I>,
-//     A: Parser<'src, I, O, E>,
-//     F: Fn(E::Error, I::Span) -> E::Error,
-// {
-//     #[inline(always)]
-//     fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, O>
-//     where
-//         Self: Sized,
-//     {
-//         let start = inp.cursor();
-//         let res = self.parser.go::<M>(inp);
-
-//         if res.is_err() {
-//             let mut e = inp.take_alt();
-//             let span = inp.span_since(start);
-//             e.err = (self.mapper)(e.err, span);
-//             inp.errors.alt = Some(e);
-//         }
+impl<'src, I, O, E, A, F> Parser<'src, I, O, E> for MapErrWithSpan<A, F>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    A: Parser<'src, I, O, E>,
+    F: Fn(E::Error, I::Span, usize) -> E::Error,
+{
+    #[inline(always)]
+    fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, O>
+    where
+        Self: Sized,
+    {
+        let old_alt = inp.take_alt();
+        let res = self.parser.go::<M>(inp);
 
-//         res
-//     }
+        if res.is_err() {
+            // Can't fail!
+            let mut new_alt = inp.take_alt().unwrap();
+            let span = inp.span_of_token_at(&new_alt.pos);
+            let pos = I::cursor_location(&new_alt.pos);
+            new_alt.err = (self.mapper)(new_alt.err, span, pos);
 
-//     go_extra!(O);
-// }
+            inp.errors.alt = old_alt;
+            inp.add_alt_err(&new_alt.pos, new_alt.err);
+        }
+
+        res
+    }
+
+    go_extra!(O);
+}
 
 // TODO: Remove combinator, replace with map_err_with
 /// See [`Parser::map_err_with_state`].
@@ -2679,6 +4338,72 @@ where
     go_extra!(O);
 }
 
+/// See [`Parser::inspect`].
+pub struct Inspect<A, OA, F> {
+    pub(crate) parser: A,
+    pub(crate) inspector: F,
+    #[allow(dead_code)]
+    pub(crate) phantom: EmptyPhantom<OA>,
+}
+
+impl<A: Copy, OA, F: Copy> Copy for Inspect<A, OA, F> {}
+impl<A: Clone, OA, F: Clone> Clone for Inspect<A, OA, F> {
+    fn clone(&self) -> Self {
+        Self {
+            parser: self.parser.clone(),
+            inspector: self.inspector.clone(),
+            phantom: EmptyPhantom::new(),
+        }
+    }
+}
+
+impl<'src, I, O, E, A, F> Parser<'src, I, O, E> for Inspect<A, O, F>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    A: Parser<'src, I, O, E>,
+    F: Fn(&O),
+{
+    #[inline(always)]
+    fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, O> {
+        let out = self.parser.go::<M>(inp)?;
+        Ok(M::map(out, |out| {
+            (self.inspector)(&out);
+            out
+        }))
+    }
+
+    go_extra!(O);
+}
+
+/// See [`Parser::inspect_err`].
+#[derive(Copy, Clone)]
+pub struct InspectErr<A, F> {
+    pub(crate) parser: A,
+    pub(crate) inspector: F,
+}
+
+impl<'src, I, O, E, A, F> Parser<'src, I, O, E> for InspectErr<A, F>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    A: Parser<'src, I, O, E>,
+    F: Fn(&E::Error),
+{
+    #[inline(always)]
+    fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, O> {
+        let res = self.parser.go::<M>(inp);
+        if res.is_err() {
+            if let Some(alt) = &inp.errors.alt {
+                (self.inspector)(&alt.err);
+            }
+        }
+        res
+    }
+
+    go_extra!(O);
+}
+
 /// See [`Parser::validate`]
 pub struct Validate<A, OA, F> {
     pub(crate) parser: A,
@@ -2715,8 +4440,8 @@ where
 
         let mut emitter = Emitter::new();
         let out = (self.validator)(out, &mut MapExtra::new(&before, inp), &mut emitter);
-        for err in emitter.errors() {
-            inp.emit(before.clone(), err);
+        for (severity, err) in emitter.errors() {
+            inp.emit_with_severity(before.clone(), err, severity);
         }
         Ok(M::bind(|| out))
     }
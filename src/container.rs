@@ -1,7 +1,7 @@
 //! TODO
 
 use super::*;
-use alloc::collections::LinkedList;
+use alloc::{borrow::Cow, collections::LinkedList};
 use hashbrown::HashSet;
 
 /// A utility trait for types that can be constructed from a series of items.
@@ -92,6 +92,36 @@ impl Container<char> for String {
     }
 }
 
+/// Characters are always pushed to an owned string.
+impl Container<char> for Cow<'_, str> {
+    fn push(&mut self, item: char) {
+        self.to_mut().push(item)
+    }
+}
+
+/// Slices only cause an allocation if they need to be appended to a non-empty string: a single non-empty slice,
+/// perhaps with empty slices around it, is borrowed.
+impl<'a> Container<&'a str> for Cow<'a, str> {
+    fn push(&mut self, item: &'a str) {
+        if self.is_empty() {
+            *self = Cow::Borrowed(item);
+        } else if !item.is_empty() {
+            self.to_mut().push_str(item);
+        }
+    }
+}
+
+/// Like the implementation for `&str`, a single borrowed string remains borrowed.
+impl<'a> Container<Cow<'a, str>> for Cow<'a, str> {
+    fn push(&mut self, item: Cow<'a, str>) {
+        if self.is_empty() {
+            *self = item;
+        } else if !item.is_empty() {
+            self.to_mut().push_str(&item);
+        }
+    }
+}
+
 impl<K: Eq + Hash, V> Container<(K, V)> for HashMap<K, V> {
     fn with_capacity(n: usize) -> Self {
         Self::with_capacity(n)
@@ -882,24 +912,368 @@ impl<'p> Seq<'p, &'p Grapheme> for &'p Graphemes {
     }
 }
 
+/// A set of bytes, stored as a 256-entry bit table so that membership can be checked in constant time.
+///
+/// This is most often created with the [`one_of!`](crate::one_of) and [`none_of!`](crate::none_of) macros, but
+/// can also be built manually and given to [`one_of`](crate::primitive::one_of) or
+/// [`none_of`](crate::primitive::none_of). Since it implements [`Seq`], errors produced by these parsers list every
+/// byte in the set.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ByteSet {
+    bits: [u64; 4],
+}
+
+impl ByteSet {
+    /// Create an empty set.
+    pub const fn new() -> Self {
+        Self { bits: [0; 4] }
+    }
+
+    /// Create a set from a list of inclusive ranges of bytes.
+    pub const fn from_ranges(ranges: &[(u8, u8)]) -> Self {
+        let mut set = Self::new();
+        let mut i = 0;
+        while i < ranges.len() {
+            set = set.with_range(ranges[i].0, ranges[i].1);
+            i += 1;
+        }
+        set
+    }
+
+    /// Add a byte to this set.
+    pub const fn with(mut self, byte: u8) -> Self {
+        self.bits[byte as usize / 64] |= 1 << (byte % 64);
+        self
+    }
+
+    /// Add an inclusive range of bytes to this set.
+    pub const fn with_range(mut self, start: u8, end: u8) -> Self {
+        let mut byte = start as usize;
+        while byte <= end as usize {
+            self = self.with(byte as u8);
+            byte += 1;
+        }
+        self
+    }
+
+    /// Determine whether this set contains the given byte.
+    pub const fn contains(&self, byte: u8) -> bool {
+        self.bits[byte as usize / 64] & (1 << (byte % 64)) != 0
+    }
+}
+
+impl FromIterator<u8> for ByteSet {
+    fn from_iter<It: IntoIterator<Item = u8>>(iter: It) -> Self {
+        iter.into_iter().fold(Self::new(), Self::with)
+    }
+}
+
+/// An iterator over the bytes in a [`ByteSet`], in ascending order.
+#[derive(Clone, Debug)]
+pub struct ByteSetIter<'a> {
+    set: &'a ByteSet,
+    next: u16,
+}
+
+impl Iterator for ByteSetIter<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        while self.next < 256 {
+            let byte = self.next as u8;
+            self.next += 1;
+            if self.set.contains(byte) {
+                return Some(byte);
+            }
+        }
+        None
+    }
+}
+
+impl<'p> Seq<'p, u8> for ByteSet {
+    type Item<'a>
+        = u8
+    where
+        Self: 'a;
+
+    type Iter<'a>
+        = ByteSetIter<'a>
+    where
+        Self: 'a;
+
+    #[inline(always)]
+    fn seq_iter(&self) -> Self::Iter<'_> {
+        ByteSetIter { set: self, next: 0 }
+    }
+
+    #[inline(always)]
+    fn contains(&self, val: &u8) -> bool {
+        ByteSet::contains(self, *val)
+    }
+
+    #[inline]
+    fn to_maybe_ref<'b>(item: Self::Item<'b>) -> MaybeRef<'p, u8>
+    where
+        'p: 'b,
+    {
+        MaybeRef::Val(item)
+    }
+}
+
+/// A set of characters, with a bit table for ASCII characters and a sorted list of ranges for everything else.
+///
+/// This is most often created with the [`one_of!`](crate::one_of) and [`none_of!`](crate::none_of) macros, but
+/// can also be built manually and given to [`one_of`](crate::primitive::one_of) or
+/// [`none_of`](crate::primitive::none_of). Since it implements [`Seq`], errors produced by these parsers list every
+/// character in the set.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CharSet {
+    ascii: [u64; 2],
+    // Sorted, non-overlapping and non-adjacent
+    ranges: Vec<RangeInclusive<char>>,
+}
+
+impl CharSet {
+    /// Create an empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a set from a list of inclusive ranges of characters.
+    pub fn from_ranges(ranges: &[(char, char)]) -> Self {
+        ranges
+            .iter()
+            .fold(Self::new(), |set, &(start, end)| set.with_range(start, end))
+    }
+
+    /// Add a character to this set.
+    pub fn with(self, c: char) -> Self {
+        self.with_range(c, c)
+    }
+
+    /// Add an inclusive range of characters to this set.
+    pub fn with_range(mut self, start: char, end: char) -> Self {
+        if start > end {
+            return self;
+        }
+        let mut start = start as u32;
+        while start <= end as u32 && start < 0x80 {
+            self.ascii[start as usize / 64] |= 1 << (start % 64);
+            start += 1;
+        }
+        if let Some(start) = char::from_u32(start).filter(|start| *start <= end) {
+            self.ranges.push(start..=end);
+            self.ranges.sort_by_key(|r| *r.start());
+            let mut merged: Vec<RangeInclusive<char>> = Vec::with_capacity(self.ranges.len());
+            for r in self.ranges.drain(..) {
+                match merged.last_mut() {
+                    Some(last) if *r.start() as u32 <= *last.end() as u32 + 1 => {
+                        if r.end() > last.end() {
+                            *last = *last.start()..=*r.end();
+                        }
+                    }
+                    _ => merged.push(r),
+                }
+            }
+            self.ranges = merged;
+        }
+        self
+    }
+
+    /// Determine whether this set contains the given character.
+    pub fn contains(&self, c: char) -> bool {
+        if c.is_ascii() {
+            self.ascii[c as usize / 64] & (1 << (c as u32 % 64)) != 0
+        } else {
+            self.ranges
+                .binary_search_by(|r| {
+                    if *r.end() < c {
+                        Ordering::Less
+                    } else if *r.start() > c {
+                        Ordering::Greater
+                    } else {
+                        Ordering::Equal
+                    }
+                })
+                .is_ok()
+        }
+    }
+}
+
+impl FromIterator<char> for CharSet {
+    fn from_iter<It: IntoIterator<Item = char>>(iter: It) -> Self {
+        iter.into_iter().fold(Self::new(), Self::with)
+    }
+}
+
+/// An iterator over the characters in a [`CharSet`], in ascending order.
+#[derive(Clone, Debug)]
+pub struct CharSetIter<'a> {
+    set: &'a CharSet,
+    next_ascii: u8,
+    ranges: core::slice::Iter<'a, RangeInclusive<char>>,
+    current: Option<RangeInclusive<char>>,
+}
+
+impl Iterator for CharSetIter<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        while self.next_ascii < 0x80 {
+            let c = self.next_ascii as char;
+            self.next_ascii += 1;
+            if self.set.contains(c) {
+                return Some(c);
+            }
+        }
+        loop {
+            if let Some(c) = self.current.as_mut().and_then(Iterator::next) {
+                return Some(c);
+            }
+            self.current = Some(self.ranges.next()?.clone());
+        }
+    }
+}
+
+impl<'p> Seq<'p, char> for CharSet {
+    type Item<'a>
+        = char
+    where
+        Self: 'a;
+
+    type Iter<'a>
+        = CharSetIter<'a>
+    where
+        Self: 'a;
+
+    #[inline(always)]
+    fn seq_iter(&self) -> Self::Iter<'_> {
+        CharSetIter {
+            set: self,
+            next_ascii: 0,
+            ranges: self.ranges.iter(),
+            current: None,
+        }
+    }
+
+    #[inline(always)]
+    fn contains(&self, val: &char) -> bool {
+        CharSet::contains(self, *val)
+    }
+
+    #[inline]
+    fn to_maybe_ref<'b>(item: Self::Item<'b>) -> MaybeRef<'p, char>
+    where
+        'p: 'b,
+    {
+        MaybeRef::Val(item)
+    }
+}
+
+/// A token type that has a dedicated set type, used by the [`one_of!`](crate::one_of) and
+/// [`none_of!`](crate::none_of) macros.
+#[doc(hidden)]
+pub trait SetToken: Sized {
+    /// The set type for this token type.
+    type Set;
+
+    /// Create a set from a list of inclusive ranges.
+    fn set_from_ranges(ranges: &[(Self, Self)]) -> Self::Set;
+}
+
+impl SetToken for u8 {
+    type Set = ByteSet;
+
+    fn set_from_ranges(ranges: &[(Self, Self)]) -> Self::Set {
+        ByteSet::from_ranges(ranges)
+    }
+}
+
+impl SetToken for char {
+    type Set = CharSet;
+
+    fn set_from_ranges(ranges: &[(Self, Self)]) -> Self::Set {
+        CharSet::from_ranges(ranges)
+    }
+}
+
 /// A utility trait to abstract over *linear* container-like things.
 ///
 /// This trait is likely to change in future versions of the crate, so avoid implementing it yourself.
-pub trait OrderedSeq<'p, T>: Seq<'p, T> {}
-
-impl<T: Clone> OrderedSeq<'_, T> for T {}
-impl<'p, T> OrderedSeq<'p, T> for &'p T {}
-impl<'p, T> OrderedSeq<'p, T> for &'p [T] {}
-impl<T: Clone, const N: usize> OrderedSeq<'_, T> for [T; N] {}
-impl<'p, T, const N: usize> OrderedSeq<'p, T> for &'p [T; N] {}
-impl<T: Clone> OrderedSeq<'_, T> for Vec<T> {}
+pub trait OrderedSeq<'p, T>: Seq<'p, T> {
+    /// View this sequence as a contiguous slice of items, if possible.
+    #[doc(hidden)]
+    #[inline(always)]
+    fn seq_as_slice(&self) -> Option<&[T]> {
+        None
+    }
+
+    /// View this sequence as a string, if possible.
+    #[doc(hidden)]
+    #[inline(always)]
+    fn seq_as_str(&self) -> Option<&str> {
+        None
+    }
+}
+
+impl<T: Clone> OrderedSeq<'_, T> for T {
+    #[inline(always)]
+    fn seq_as_slice(&self) -> Option<&[T]> {
+        Some(core::slice::from_ref(self))
+    }
+}
+impl<'p, T> OrderedSeq<'p, T> for &'p T {
+    #[inline(always)]
+    fn seq_as_slice(&self) -> Option<&[T]> {
+        Some(core::slice::from_ref(*self))
+    }
+}
+impl<'p, T> OrderedSeq<'p, T> for &'p [T] {
+    #[inline(always)]
+    fn seq_as_slice(&self) -> Option<&[T]> {
+        Some(self)
+    }
+}
+impl<T: Clone, const N: usize> OrderedSeq<'_, T> for [T; N] {
+    #[inline(always)]
+    fn seq_as_slice(&self) -> Option<&[T]> {
+        Some(self)
+    }
+}
+impl<'p, T, const N: usize> OrderedSeq<'p, T> for &'p [T; N] {
+    #[inline(always)]
+    fn seq_as_slice(&self) -> Option<&[T]> {
+        Some(*self)
+    }
+}
+impl<T: Clone> OrderedSeq<'_, T> for Vec<T> {
+    #[inline(always)]
+    fn seq_as_slice(&self) -> Option<&[T]> {
+        Some(self)
+    }
+}
 impl<'p, T> OrderedSeq<'p, T> for Range<T> where Self: Seq<'p, T> {}
 impl<'p, T> OrderedSeq<'p, T> for core::ops::RangeInclusive<T> where Self: Seq<'p, T> {}
 impl<'p, T> OrderedSeq<'p, T> for RangeFrom<T> where Self: Seq<'p, T> {}
 
-impl OrderedSeq<'_, char> for str {}
-impl OrderedSeq<'_, char> for String {}
-impl<'p> OrderedSeq<'p, char> for &'p str {}
+impl OrderedSeq<'_, char> for str {
+    #[inline(always)]
+    fn seq_as_str(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+impl OrderedSeq<'_, char> for String {
+    #[inline(always)]
+    fn seq_as_str(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+impl<'p> OrderedSeq<'p, char> for &'p str {
+    #[inline(always)]
+    fn seq_as_str(&self) -> Option<&str> {
+        Some(self)
+    }
+}
 impl<'p> OrderedSeq<'p, &'p Grapheme> for &'p str {}
 impl<'p> OrderedSeq<'p, &'p Grapheme> for &'p Graphemes {}
 
@@ -925,6 +1299,28 @@ mod test {
         unsafe { C::drop_before(&mut uninit, C::LEN / 2) };
     }
 
+    #[test]
+    fn cow_str_stays_borrowed() {
+        fn collect<'a, T>(items: impl IntoIterator<Item = T>) -> Cow<'a, str>
+        where
+            Cow<'a, str>: Container<T>,
+        {
+            let mut c = Cow::default();
+            items.into_iter().for_each(|item| c.push(item));
+            c
+        }
+
+        // The default `Cow<str>` is an empty `String`, which doesn't allocate
+        assert!(matches!(collect::<&str>([]), Cow::Owned(s) if s.capacity() == 0));
+        assert!(matches!(collect(["", "towel", ""]), Cow::Borrowed("towel")));
+        assert!(matches!(collect(["to", "wel"]), Cow::Owned(s) if s == "towel"));
+        assert!(matches!(collect(['4', '2']), Cow::Owned(s) if s == "42"));
+        assert!(matches!(
+            collect([Cow::Borrowed("a"), Cow::Owned("b".to_string())]),
+            Cow::Owned(s) if s == "ab"
+        ));
+    }
+
     #[test]
     fn exact_array() {
         let c = init_container::<[usize; 4]>();
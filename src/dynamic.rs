@@ -0,0 +1,505 @@
+//! Parsers built from grammars that are only known at runtime.
+//!
+//! *“The ships hung in the sky in much the same way that bricks don't.”*
+//!
+//! Most grammars are written in Rust and known at compile time. Some applications, however, need to accept grammars
+//! that are provided by their users (syntax highlighting rules, say) without recompiling. The types in this module
+//! allow a grammar to be described as a value, a [`Grammar`] made of named [`Rule`]s, and then turned into a parser.
+//!
+//! The output of such a parser is a tree of [`Node`]s: one for each named rule that matched, with the span of the
+//! input that it matched.
+//!
+//! These parsers are built out of [`Boxed`] and [`Recursive`] parsers and so are slower than their statically-written
+//! equivalents.
+
+use super::*;
+
+/// A pattern that makes up part of a runtime [`Grammar`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Rule<T> {
+    /// Accepts the given sequence of tokens, like [`just`].
+    Just(Vec<T>),
+    /// Accepts any one of the given tokens, like [`one_of`].
+    OneOf(Vec<T>),
+    /// Accepts any token that is not one of the given tokens, like [`none_of`].
+    NoneOf(Vec<T>),
+    /// Accepts any single token, like [`any`].
+    Any,
+    /// Accepts the end of the input, like [`end`].
+    End,
+    /// Accepts each of the given rules, one after another.
+    Seq(Vec<Rule<T>>),
+    /// Accepts the first of the given rules that matches, like [`choice`].
+    Choice(Vec<Rule<T>>),
+    /// Accepts the given rule repeatedly, like [`Parser::repeated`].
+    Repeated {
+        /// The rule to repeat.
+        rule: Box<Rule<T>>,
+        /// The minimum number of repetitions.
+        at_least: usize,
+        /// The maximum number of repetitions, if any.
+        at_most: Option<usize>,
+    },
+    /// Accepts the given rule, if it is present, like [`Parser::or_not`].
+    Optional(Box<Rule<T>>),
+    /// Accepts the named rule of the grammar, producing a [`Node`] for it.
+    Named(String),
+}
+
+impl<T> Rule<T> {
+    /// Accept this rule any number of times.
+    pub fn repeated(self) -> Self {
+        Self::Repeated {
+            rule: Box::new(self),
+            at_least: 0,
+            at_most: None,
+        }
+    }
+
+    /// Accept this rule at least once.
+    pub fn at_least_once(self) -> Self {
+        Self::Repeated {
+            rule: Box::new(self),
+            at_least: 1,
+            at_most: None,
+        }
+    }
+
+    /// Accept this rule, if it is present.
+    pub fn or_not(self) -> Self {
+        Self::Optional(Box::new(self))
+    }
+
+    /// Refer to the rule of the grammar with the given name.
+    pub fn named(name: impl Into<String>) -> Self {
+        Self::Named(name.into())
+    }
+}
+
+impl Rule<char> {
+    /// Accept the characters of the given string.
+    pub fn text(s: &str) -> Self {
+        Self::Just(s.chars().collect())
+    }
+}
+
+/// A node in the output of a parser built from a [`Grammar`], corresponding to a named rule that matched.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Node<S> {
+    /// The name of the rule that matched.
+    pub name: Rc<str>,
+    /// The span of the input that the rule matched.
+    pub span: S,
+    /// The nodes of the named rules that matched within this rule, in order.
+    pub children: Vec<Node<S>>,
+}
+
+/// An error produced when building a parser from a [`Grammar`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GrammarError {
+    /// The grammar refers to a rule that it does not define.
+    UndefinedRule(String),
+    /// The grammar contains left recursion: a rule that can invoke itself without consuming any input, which would
+    /// recurse forever. The names of the rules that make up the cycle are given in order, starting and ending with the
+    /// same rule.
+    LeftRecursion(Vec<String>),
+}
+
+impl fmt::Display for GrammarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UndefinedRule(name) => write!(f, "grammar refers to undefined rule '{name}'"),
+            Self::LeftRecursion(cycle) => {
+                write!(f, "grammar is left-recursive: {}", cycle.join(" -> "))
+            }
+        }
+    }
+}
+
+/// A grammar, made up of named [`Rule`]s, that can be turned into a parser at runtime.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, dynamic::{Grammar, GrammarError, Rule}};
+/// // list  = '[' (item (',' item)*)? ']'
+/// // item  = digit+ | list
+/// let grammar = Grammar::new()
+///     .rule("list", Rule::Seq(vec![
+///         Rule::text("["),
+///         Rule::Seq(vec![
+///             Rule::named("item"),
+///             Rule::Seq(vec![Rule::text(","), Rule::named("item")]).repeated(),
+///         ]).or_not(),
+///         Rule::text("]"),
+///     ]))
+///     .rule("item", Rule::Choice(vec![
+///         Rule::OneOf("0123456789".chars().collect()).at_least_once(),
+///         Rule::named("list"),
+///     ]));
+///
+/// let parser = grammar.parser::<&str, extra::Err<Simple<char>>>("list").unwrap();
+///
+/// let tree = parser.parse("[1,[23],[]]").into_result().unwrap();
+/// assert_eq!(&*tree.name, "list");
+/// assert_eq!(tree.children.len(), 3);
+/// assert_eq!(&*tree.children[1].children[0].name, "list");
+/// assert_eq!(tree.children[1].children[0].span, (3..7).into());
+///
+/// assert!(parser.parse("[1,]").has_errors());
+/// assert_eq!(
+///     grammar.parser::<&str, extra::Err<Simple<char>>>("value").err(),
+///     Some(GrammarError::UndefinedRule("value".to_string())),
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grammar<T> {
+    rules: Vec<(String, Rule<T>)>,
+}
+
+impl<T> Default for Grammar<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Grammar<T> {
+    /// Create a new grammar with no rules.
+    pub fn new() -> Self {
+        Self { rules: Vec::new() }
+    }
+
+    /// Add a named rule to this grammar, replacing any existing rule with the same name.
+    pub fn rule(mut self, name: impl Into<String>, rule: Rule<T>) -> Self {
+        let name = name.into();
+        self.rules.retain(|(n, _)| *n != name);
+        self.rules.push((name, rule));
+        self
+    }
+
+    /// Get the rule with the given name, if it exists.
+    pub fn get(&self, name: &str) -> Option<&Rule<T>> {
+        self.rules.iter().find(|(n, _)| n == name).map(|(_, r)| r)
+    }
+
+    /// Build a parser that accepts the rule of this grammar with the given name.
+    ///
+    /// Fails if the grammar refers to a rule that it does not define, or if any of its rules are left-recursive (since
+    /// the parser would otherwise recurse forever).
+    pub fn parser<'src, I, E>(&self, start: &str) -> Result<GrammarParser<'src, I, E>, GrammarError>
+    where
+        I: ValueInput<'src, Token = T> + 'src,
+        I::Span: Clone + 'src,
+        T: PartialEq + Clone + 'src,
+        E: ParserExtra<'src, I> + 'src,
+    {
+        let rules = self
+            .rules
+            .iter()
+            .map(|(name, _)| {
+                (
+                    name.as_str(),
+                    (Rc::<str>::from(name.as_str()), Recursive::declare()),
+                )
+            })
+            .collect::<HashMap<_, _>>();
+
+        for (name, rule) in &self.rules {
+            rules[name.as_str()].1.clone().define(build(rule, &rules)?);
+        }
+        self.check_left_recursion()?;
+
+        let start = build(&Rule::Named(start.into()), &rules)?
+            .map(|mut nodes| nodes.pop().expect("named rules always produce a node"))
+            .boxed();
+
+        Ok(GrammarParser {
+            start,
+            rules: rules.into_values().map(|(_, rule)| rule).collect(),
+        })
+    }
+}
+
+impl<T> Grammar<T> {
+    /// Find which rules can match without consuming any input.
+    fn nullable(&self) -> HashMap<&str, bool> {
+        let mut nullable = self
+            .rules
+            .iter()
+            .map(|(name, _)| (name.as_str(), false))
+            .collect::<HashMap<_, _>>();
+        // Iterate until nothing changes, since rules may refer to one another
+        loop {
+            let mut changed = false;
+            for (name, rule) in &self.rules {
+                if !nullable[name.as_str()] && rule.is_nullable(&nullable) {
+                    nullable.insert(name.as_str(), true);
+                    changed = true;
+                }
+            }
+            if !changed {
+                break nullable;
+            }
+        }
+    }
+
+    /// Check that no rule can invoke itself, directly or through other rules, without consuming any input.
+    fn check_left_recursion(&self) -> Result<(), GrammarError> {
+        let nullable = self.nullable();
+        let edges = self
+            .rules
+            .iter()
+            .map(|(name, rule)| {
+                let mut refs = Vec::new();
+                rule.left_refs(&nullable, &mut refs);
+                (name.as_str(), refs)
+            })
+            .collect::<HashMap<_, _>>();
+
+        // Depth-first search for a cycle, keeping the path taken so far so that the cycle can be reported
+        fn visit<'a>(
+            name: &'a str,
+            edges: &HashMap<&'a str, Vec<&'a str>>,
+            done: &mut HashMap<&'a str, bool>,
+            path: &mut Vec<&'a str>,
+        ) -> Result<(), GrammarError> {
+            if let Some(start) = path.iter().position(|n| *n == name) {
+                let mut cycle = path[start..]
+                    .iter()
+                    .map(|n| n.to_string())
+                    .collect::<Vec<_>>();
+                cycle.push(name.to_string());
+                return Err(GrammarError::LeftRecursion(cycle));
+            }
+            if done.get(name).copied().unwrap_or(false) {
+                return Ok(());
+            }
+            path.push(name);
+            for next in &edges[name] {
+                visit(next, edges, done, path)?;
+            }
+            path.pop();
+            done.insert(name, true);
+            Ok(())
+        }
+
+        let mut done = HashMap::default();
+        for (name, _) in &self.rules {
+            visit(name, &edges, &mut done, &mut Vec::new())?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Rule<T> {
+    /// Whether this rule can match without consuming any input, given which named rules can.
+    fn is_nullable(&self, nullable: &HashMap<&str, bool>) -> bool {
+        match self {
+            Self::Just(seq) => seq.is_empty(),
+            Self::OneOf(_) | Self::NoneOf(_) | Self::Any => false,
+            Self::End | Self::Optional(_) => true,
+            Self::Seq(seq) => seq.iter().all(|rule| rule.is_nullable(nullable)),
+            Self::Choice(alts) => alts.iter().any(|rule| rule.is_nullable(nullable)),
+            Self::Repeated { rule, at_least, .. } => *at_least == 0 || rule.is_nullable(nullable),
+            Self::Named(name) => nullable.get(name.as_str()).copied().unwrap_or(false),
+        }
+    }
+
+    /// Collect the named rules that this rule may invoke before consuming any input.
+    fn left_refs<'a>(&'a self, nullable: &HashMap<&str, bool>, refs: &mut Vec<&'a str>) {
+        match self {
+            Self::Just(_) | Self::OneOf(_) | Self::NoneOf(_) | Self::Any | Self::End => {}
+            Self::Seq(seq) => {
+                for rule in seq {
+                    rule.left_refs(nullable, refs);
+                    if !rule.is_nullable(nullable) {
+                        break;
+                    }
+                }
+            }
+            Self::Choice(alts) => alts.iter().for_each(|rule| rule.left_refs(nullable, refs)),
+            Self::Repeated { rule, .. } | Self::Optional(rule) => rule.left_refs(nullable, refs),
+            Self::Named(name) => refs.push(name),
+        }
+    }
+}
+
+type RuleParser<'src, I, E> =
+    Recursive<recursive::Indirect<'src, 'src, I, Vec<Node<<I as Input<'src>>::Span>>, E>>;
+
+fn build<'src, I, E>(
+    rule: &Rule<I::Token>,
+    rules: &HashMap<&str, (Rc<str>, RuleParser<'src, I, E>)>,
+) -> Result<Boxed<'src, 'src, I, Vec<Node<I::Span>>, E>, GrammarError>
+where
+    I: ValueInput<'src> + 'src,
+    I::Token: PartialEq + Clone + 'src,
+    I::Span: Clone + 'src,
+    E: ParserExtra<'src, I> + 'src,
+{
+    Ok(match rule {
+        Rule::Just(seq) => just(seq.clone()).map(|_| Vec::new()).boxed(),
+        Rule::OneOf(seq) => one_of(seq.clone()).map(|_| Vec::new()).boxed(),
+        Rule::NoneOf(seq) => none_of(seq.clone()).map(|_| Vec::new()).boxed(),
+        Rule::Any => any().map(|_| Vec::new()).boxed(),
+        Rule::End => end().map(|_| Vec::new()).boxed(),
+        Rule::Seq(seq) => {
+            let mut parser = empty().map(|_| Vec::new()).boxed();
+            for rule in seq {
+                parser = parser
+                    .then(build(rule, rules)?)
+                    .map(|(mut a, b)| {
+                        a.extend(b);
+                        a
+                    })
+                    .boxed();
+            }
+            parser
+        }
+        Rule::Choice(alts) => choice(
+            alts.iter()
+                .map(|rule| build(rule, rules))
+                .collect::<Result<Vec<_>, _>>()?,
+        )
+        .boxed(),
+        Rule::Repeated {
+            rule,
+            at_least,
+            at_most,
+        } => {
+            let repeated = build(rule, rules)?.repeated().at_least(*at_least);
+            let repeated = match at_most {
+                Some(at_most) => repeated.at_most(*at_most),
+                None => repeated,
+            };
+            repeated
+                .collect::<Vec<_>>()
+                .map(|nodes| nodes.into_iter().flatten().collect())
+                .boxed()
+        }
+        Rule::Optional(rule) => build(rule, rules)?
+            .or_not()
+            .map(Option::unwrap_or_default)
+            .boxed(),
+        Rule::Named(name) => {
+            let (name, parser) = rules
+                .get(name.as_str())
+                .ok_or_else(|| GrammarError::UndefinedRule(name.clone()))?;
+            let name = name.clone();
+            // Refer to the rule weakly: the strong references are owned by the `GrammarParser`, avoiding cycles
+            parser
+                .downgrade()
+                .map_with(move |children, e| {
+                    vec![Node {
+                        name: name.clone(),
+                        span: e.span(),
+                        children,
+                    }]
+                })
+                .boxed()
+        }
+    })
+}
+
+/// A parser built from a [`Grammar`]. See [`Grammar::parser`].
+///
+/// The output type of this parser is [`Node<I::Span>`], the node of the rule that the parser was built for.
+pub struct GrammarParser<'src, I: Input<'src>, E: ParserExtra<'src, I>> {
+    start: Boxed<'src, 'src, I, Node<I::Span>, E>,
+    // Keeps the rules alive, since rules only refer to one another weakly
+    #[allow(dead_code)]
+    rules: Vec<RuleParser<'src, I, E>>,
+}
+
+impl<'src, I: Input<'src>, E: ParserExtra<'src, I>> Clone for GrammarParser<'src, I, E> {
+    fn clone(&self) -> Self {
+        Self {
+            start: self.start.clone(),
+            rules: self.rules.clone(),
+        }
+    }
+}
+
+impl<'src, I, E> Parser<'src, I, Node<I::Span>, E> for GrammarParser<'src, I, E>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+{
+    #[inline]
+    fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, Node<I::Span>> {
+        self.start.go::<M>(inp)
+    }
+
+    go_extra!(Node<I::Span>);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Extra = extra::Err<Simple<'static, char>>;
+
+    #[test]
+    fn empty_choice_never_matches() {
+        let grammar = Grammar::new().rule("nothing", Rule::Choice(Vec::new()));
+        let parser = grammar.parser::<&str, Extra>("nothing").unwrap();
+        assert!(parser.parse("").has_errors());
+        assert!(parser.parse("a").has_errors());
+    }
+
+    #[test]
+    fn left_recursion_is_rejected() {
+        // expr = expr '+' digit | digit
+        let grammar = Grammar::new().rule(
+            "expr",
+            Rule::Choice(vec![
+                Rule::Seq(vec![
+                    Rule::named("expr"),
+                    Rule::text("+"),
+                    Rule::OneOf("0123456789".chars().collect()),
+                ]),
+                Rule::OneOf("0123456789".chars().collect()),
+            ]),
+        );
+        assert_eq!(
+            grammar.parser::<&str, Extra>("expr").err(),
+            Some(GrammarError::LeftRecursion(vec![
+                "expr".to_string(),
+                "expr".to_string()
+            ])),
+        );
+
+        // a = 'x'? b
+        // b = a | 'y'
+        let grammar = Grammar::new()
+            .rule(
+                "a",
+                Rule::Seq(vec![Rule::text("x").or_not(), Rule::named("b")]),
+            )
+            .rule("b", Rule::Choice(vec![Rule::named("a"), Rule::text("y")]));
+        assert_eq!(
+            grammar.parser::<&str, Extra>("b").err(),
+            Some(GrammarError::LeftRecursion(vec![
+                "a".to_string(),
+                "b".to_string(),
+                "a".to_string()
+            ])),
+        );
+    }
+
+    #[test]
+    fn right_recursion_is_accepted() {
+        // parens = '(' parens? ')'
+        let grammar = Grammar::new().rule(
+            "parens",
+            Rule::Seq(vec![
+                Rule::text("("),
+                Rule::named("parens").or_not(),
+                Rule::text(")"),
+            ]),
+        );
+        let parser = grammar.parser::<&str, Extra>("parens").unwrap();
+        assert!(!parser.parse("((()))").has_errors());
+        assert!(parser.parse("(()").has_errors());
+    }
+}
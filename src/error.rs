@@ -8,7 +8,7 @@
 
 use super::*;
 #[cfg(not(feature = "std"))]
-use alloc::string::ToString;
+use alloc::{format, string::ToString};
 
 /// A trait that describes parser error types.
 ///
@@ -66,7 +66,7 @@ use alloc::string::ToString;
 /// assert_eq!(numeral.parse("7").into_result(), Ok(7));
 /// assert_eq!(numeral.parse("f").into_errors(), vec![MyError::NotADigit((0..1).into(), 'f')]);
 /// ```
-// TODO: Add support for more specialised kinds of error: unclosed delimiters, and more
+// TODO: Add support for more specialised kinds of error
 pub trait Error<'a, I: Input<'a>>: Sized {
     /// Create a new error describing a conflict between expected inputs and that which was actually found.
     ///
@@ -97,6 +97,22 @@ pub trait Error<'a, I: Input<'a>>: Sized {
         self.merge(Self::expected_found(expected, found, span))
     }
 
+    /// Create a new error describing a conflict between an expected (inclusive) range of inputs and that which was
+    /// actually found.
+    ///
+    /// By default, this produces an error with no expected inputs. Error types that are able to describe ranges
+    /// should override this.
+    #[inline(always)]
+    fn expected_range_found(
+        start: MaybeRef<'a, I::Token>,
+        end: MaybeRef<'a, I::Token>,
+        found: Option<MaybeRef<'a, I::Token>>,
+        span: I::Span,
+    ) -> Self {
+        #![allow(unused_variables)]
+        Self::expected_found(None, found, span)
+    }
+
     /// Fast path for `a = Error::expected_found(...)` that may incur less overhead by, for example, reusing allocations.
     #[inline(always)]
     fn replace_expected_found<E: IntoIterator<Item = Option<MaybeRef<'a, I::Token>>>>(
@@ -107,6 +123,76 @@ pub trait Error<'a, I: Input<'a>>: Sized {
     ) -> Self {
         Self::expected_found(expected, found, span)
     }
+
+    /// Create a new error with a custom message, for problems that can't be described in terms of the inputs that were
+    /// expected, such as an integer literal with leading zeros.
+    ///
+    /// `found` is the token at the start of the problem, if any. By default, this produces an error with no expected
+    /// inputs. Error types that are able to describe custom messages, like [`Rich`], should override this.
+    #[inline(always)]
+    fn custom<M: fmt::Display>(
+        found: Option<MaybeRef<'a, I::Token>>,
+        span: I::Span,
+        msg: M,
+    ) -> Self {
+        #![allow(unused_variables)]
+        Self::expected_found(None, found, span)
+    }
+
+    /// Create a new error describing an identifier that was found where a specific keyword was expected.
+    ///
+    /// This is produced by [`text::ascii::keyword`](crate::text::ascii::keyword) and
+    /// [`text::unicode::keyword`](crate::text::unicode::keyword). `span` covers the whole identifier that was found, and
+    /// `found` is its first token.
+    ///
+    /// By default, this produces an error with no expected inputs. Error types that are able to describe keywords
+    /// should override this.
+    #[inline(always)]
+    fn expected_keyword_found(
+        keyword: &str,
+        found: Option<MaybeRef<'a, I::Token>>,
+        span: I::Span,
+    ) -> Self {
+        #![allow(unused_variables)]
+        Self::expected_found(None, found, span)
+    }
+
+    /// Attach the span of a delimiter that was opened, but never closed, to this error.
+    ///
+    /// This is called by [`DelimitedBy::report_unclosed`](crate::combinator::DelimitedBy::report_unclosed) when the
+    /// closing delimiter of a pattern is missing. By default, the span is ignored.
+    #[inline(always)]
+    fn with_unclosed_delimiter(self, opened_at: I::Span) -> Self {
+        #![allow(unused_variables)]
+        self
+    }
+}
+
+/// The severity of an error emitted during parsing.
+///
+/// Only errors with [`Severity::Error`] cause a parse to be considered failed. Warnings and hints (such as those
+/// emitted by [`Emitter::warn`](crate::input::Emitter::warn) within [`Parser::validate`](crate::Parser::validate))
+/// are returned via [`ParseResult::warnings`](crate::ParseResult::warnings) instead.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// A hint, such as a suggestion for a more idiomatic syntax.
+    Hint,
+    /// A warning, such as the use of deprecated syntax.
+    Warning,
+    /// A fatal error.
+    #[default]
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Hint => write!(f, "hint"),
+            Self::Warning => write!(f, "warning"),
+            Self::Error => write!(f, "error"),
+        }
+    }
 }
 
 /// A ZST error type that tracks only whether a parse error occurred at all. This type is for when
@@ -184,6 +270,9 @@ where
 pub struct Simple<'a, T, S = SimpleSpan<usize>> {
     span: S,
     found: Option<MaybeRef<'a, T>>,
+    // Whether the error was caused by reaching the end of the input. Custom errors may have no found token without
+    // this being the case.
+    incomplete: bool,
 }
 
 impl<T, S> Simple<'_, T, S> {
@@ -196,6 +285,12 @@ impl<T, S> Simple<'_, T, S> {
     pub fn found(&self) -> Option<&T> {
         self.found.as_deref()
     }
+
+    /// Returns `true` if this error occurred because the end of input was reached, meaning that the input might
+    /// become valid if more of it were provided. See [`Rich::is_incomplete`].
+    pub fn is_incomplete(&self) -> bool {
+        self.incomplete
+    }
 }
 
 impl<'a, T, S> Simple<'a, T, S> {
@@ -210,6 +305,7 @@ impl<'a, T, S> Simple<'a, T, S> {
         Simple {
             span: self.span,
             found: self.found.map(|found| f(found.into_inner()).into()),
+            incomplete: self.incomplete,
         }
     }
 }
@@ -221,7 +317,24 @@ impl<'a, I: Input<'a>> Error<'a, I> for Simple<'a, I::Token, I::Span> {
         found: Option<MaybeRef<'a, I::Token>>,
         span: I::Span,
     ) -> Self {
-        Self { span, found }
+        Self {
+            span,
+            incomplete: found.is_none(),
+            found,
+        }
+    }
+
+    #[inline]
+    fn custom<M: fmt::Display>(
+        found: Option<MaybeRef<'a, I::Token>>,
+        span: I::Span,
+        _msg: M,
+    ) -> Self {
+        Self {
+            span,
+            found,
+            incomplete: false,
+        }
     }
 }
 
@@ -254,8 +367,17 @@ where
 pub enum RichPattern<'a, T, L = &'static str> {
     /// A specific token was expected.
     Token(MaybeRef<'a, T>),
+    /// A token within an inclusive range of tokens was expected.
+    Range {
+        /// The first token in the range.
+        start: MaybeRef<'a, T>,
+        /// The last token in the range.
+        end: MaybeRef<'a, T>,
+    },
     /// A labelled pattern was expected.
     Label(L),
+    /// A specific keyword was expected, such as by [`text::ascii::keyword`](crate::text::ascii::keyword).
+    Keyword(String),
     /// The end of input was expected.
     EndOfInput,
 }
@@ -271,7 +393,12 @@ impl<'a, T, L> RichPattern<'a, T, L> {
     {
         match self {
             Self::Token(t) => RichPattern::Token(f(t.into_inner()).into()),
+            Self::Range { start, end } => RichPattern::Range {
+                start: f(start.into_inner()).into(),
+                end: f(end.into_inner()).into(),
+            },
             Self::Label(s) => RichPattern::Label(s),
+            Self::Keyword(keyword) => RichPattern::Keyword(keyword),
             Self::EndOfInput => RichPattern::EndOfInput,
         }
     }
@@ -283,7 +410,12 @@ impl<'a, T, L> RichPattern<'a, T, L> {
     {
         match self {
             Self::Token(tok) => RichPattern::Token(tok.into_owned()),
+            Self::Range { start, end } => RichPattern::Range {
+                start: start.into_owned(),
+                end: end.into_owned(),
+            },
             Self::Label(label) => RichPattern::Label(label),
+            Self::Keyword(keyword) => RichPattern::Keyword(keyword),
             Self::EndOfInput => RichPattern::EndOfInput,
         }
     }
@@ -300,7 +432,15 @@ impl<'a, T, L> RichPattern<'a, T, L> {
                 fmt_token(tok, f)?;
                 write!(f, "'")
             }
+            Self::Range { start, end } => {
+                write!(f, "'")?;
+                fmt_token(start, f)?;
+                write!(f, "'..='")?;
+                fmt_token(end, f)?;
+                write!(f, "'")
+            }
             Self::Label(label) => fmt_label(label, f),
+            Self::Keyword(keyword) => write!(f, "keyword `{keyword}`"),
             Self::EndOfInput => write!(f, "end of input"),
         }
     }
@@ -314,7 +454,9 @@ where
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Self::Token(t) => write!(f, "{t:?}"),
+            Self::Range { start, end } => write!(f, "{:?}..={:?}", &**start, &**end),
             Self::Label(label) => write!(f, "{label:?}"),
+            Self::Keyword(keyword) => write!(f, "keyword {keyword:?}"),
             Self::EndOfInput => write!(f, "end of input"),
         }
     }
@@ -328,7 +470,9 @@ where
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Token(t) => write!(f, "'{}'", &**t),
+            Self::Range { start, end } => write!(f, "'{}'..='{}'", &**start, &**end),
             Self::Label(s) => write!(f, "{s}"),
+            Self::Keyword(keyword) => write!(f, "keyword `{keyword}`"),
             Self::EndOfInput => write!(f, "end of input"),
         }
     }
@@ -348,6 +492,8 @@ pub enum RichReason<'a, T, L = &'static str> {
     },
     /// An error with a custom message
     Custom(String),
+    /// Several distinct reasons that occurred at the same location and were merged together
+    Many(Vec<Self>),
 }
 
 impl<'a, T, L> RichReason<'a, T, L> {
@@ -356,6 +502,17 @@ impl<'a, T, L> RichReason<'a, T, L> {
         match self {
             Self::ExpectedFound { found, .. } => found.as_deref(),
             Self::Custom(_) => None,
+            Self::Many(reasons) => reasons.iter().find_map(Self::found),
+        }
+    }
+
+    /// Returns `true` if this reason describes the end of input being reached while more input was expected. See
+    /// [`Rich::is_incomplete`].
+    pub fn is_incomplete(&self) -> bool {
+        match self {
+            Self::ExpectedFound { found, .. } => found.is_none(),
+            Self::Custom(_) => false,
+            Self::Many(reasons) => reasons.iter().any(Self::is_incomplete),
         }
     }
 
@@ -370,6 +527,9 @@ impl<'a, T, L> RichReason<'a, T, L> {
                 found: found.map(MaybeRef::into_owned),
             },
             Self::Custom(msg) => RichReason::Custom(msg),
+            Self::Many(reasons) => {
+                RichReason::Many(reasons.into_iter().map(Self::into_owned).collect())
+            }
         }
     }
 
@@ -378,6 +538,7 @@ impl<'a, T, L> RichReason<'a, T, L> {
         match self {
             RichReason::ExpectedFound { found, .. } => found.take(),
             RichReason::Custom(_) => None,
+            RichReason::Many(reasons) => reasons.iter_mut().find_map(Self::take_found),
         }
     }
 
@@ -386,6 +547,14 @@ impl<'a, T, L> RichReason<'a, T, L> {
     /// This is useful when you wish to combine errors from multiple compilation passes (lexing and parsing, say) where
     /// the token type for each pass is different (`char` vs `MyToken`, say).
     pub fn map_token<U, F: FnMut(T) -> U>(self, mut f: F) -> RichReason<'a, U, L>
+    where
+        T: Clone,
+    {
+        self.map_token_dyn(&mut f)
+    }
+
+    // Takes a trait object to avoid unbounded recursive instantiation when mapping `RichReason::Many`
+    fn map_token_dyn<U>(self, f: &mut dyn FnMut(T) -> U) -> RichReason<'a, U, L>
     where
         T: Clone,
     {
@@ -393,11 +562,17 @@ impl<'a, T, L> RichReason<'a, T, L> {
             RichReason::ExpectedFound { expected, found } => RichReason::ExpectedFound {
                 expected: expected
                     .into_iter()
-                    .map(|pat| pat.map_token(&mut f))
+                    .map(|pat| pat.map_token(&mut *f))
                     .collect(),
                 found: found.map(|found| f(found.into_inner()).into()),
             },
             RichReason::Custom(msg) => RichReason::Custom(msg),
+            RichReason::Many(reasons) => RichReason::Many(
+                reasons
+                    .into_iter()
+                    .map(|reason| reason.map_token_dyn(&mut *f))
+                    .collect(),
+            ),
         }
     }
 
@@ -442,6 +617,25 @@ impl<'a, T, L> RichReason<'a, T, L> {
                     fmt_span(span, f)?;
                 }
             }
+            RichReason::Many(reasons) => {
+                for (i, reason) in reasons.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, "; ")?;
+                    }
+                    // Erase the formatter types to avoid unbounded recursive instantiation
+                    reason.inner_fmt(
+                        f,
+                        &mut fmt_token
+                            as &mut dyn FnMut(&T, &mut fmt::Formatter<'_>) -> fmt::Result,
+                        &mut fmt_span as &mut dyn FnMut(&S, &mut fmt::Formatter<'_>) -> fmt::Result,
+                        &mut fmt_label
+                            as &mut dyn FnMut(&L, &mut fmt::Formatter<'_>) -> fmt::Result,
+                        span,
+                        #[cfg(feature = "label")]
+                        &[],
+                    )?;
+                }
+            }
         }
         #[cfg(feature = "label")]
         for (l, s) in context {
@@ -462,6 +656,29 @@ where
     #[inline]
     fn flat_merge(self, other: Self) -> Self {
         match (self, other) {
+            (RichReason::Many(mut these), RichReason::Many(others)) => {
+                for other in others {
+                    if !these.contains(&other) {
+                        these.push(other);
+                    }
+                }
+                RichReason::Many(these)
+            }
+            // Custom reasons take priority over expected inputs
+            (RichReason::Many(these), RichReason::ExpectedFound { .. })
+            | (RichReason::ExpectedFound { .. }, RichReason::Many(these)) => {
+                RichReason::Many(these)
+            }
+            (RichReason::Many(mut these), other) | (other, RichReason::Many(mut these)) => {
+                if !these.contains(&other) {
+                    these.push(other);
+                }
+                RichReason::Many(these)
+            }
+            // Keep distinct custom reasons, rather than arbitrarily discarding one of them
+            (RichReason::Custom(a), RichReason::Custom(b)) if a != b => {
+                RichReason::Many(vec![RichReason::Custom(a), RichReason::Custom(b)])
+            }
             // Prefer first error, if ambiguous
             (a @ RichReason::Custom(_), _) => a,
             (_, b @ RichReason::Custom(_)) => b,
@@ -515,12 +732,15 @@ where
 ///
 /// Please note that it uses a [`Vec`] to remember expected symbols. If you find this to be too slow, you can
 /// implement [`Error`] for your own error type or use [`Simple`] instead.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Rich<'a, T, S = SimpleSpan<usize>, L = &'static str> {
     span: S,
     reason: Box<RichReason<'a, T, L>>,
     #[cfg(feature = "label")]
     context: Vec<(L, S)>,
+    notes: Vec<String>,
+    unclosed: Option<S>,
 }
 
 impl<T, S, L> Rich<'_, T, S, L> {
@@ -528,19 +748,28 @@ impl<T, S, L> Rich<'_, T, S, L> {
         &self,
         f: &mut fmt::Formatter<'_>,
         fmt_token: impl FnMut(&T, &mut fmt::Formatter<'_>) -> fmt::Result,
-        fmt_span: impl FnMut(&S, &mut fmt::Formatter<'_>) -> fmt::Result,
+        mut fmt_span: impl FnMut(&S, &mut fmt::Formatter<'_>) -> fmt::Result,
         fmt_label: impl FnMut(&L, &mut fmt::Formatter<'_>) -> fmt::Result,
         with_spans: bool,
     ) -> fmt::Result {
         self.reason.inner_fmt(
             f,
             fmt_token,
-            fmt_span,
+            &mut fmt_span,
             fmt_label,
             if with_spans { Some(&self.span) } else { None },
             #[cfg(feature = "label")]
             &self.context,
-        )
+        )?;
+        for note in &self.notes {
+            write!(f, " (note: {note})")?;
+        }
+        if let Some(unclosed) = &self.unclosed {
+            write!(f, " (unclosed delimiter opened at ")?;
+            fmt_span(unclosed, f)?;
+            write!(f, ")")?;
+        }
+        Ok(())
     }
 }
 
@@ -553,14 +782,52 @@ impl<'a, T, S, L> Rich<'a, T, S, L> {
             reason: Box::new(RichReason::Custom(msg.to_string())),
             #[cfg(feature = "label")]
             context: Vec::new(),
+            notes: Vec::new(),
+            unclosed: None,
         }
     }
 
+    /// Attach a note to this error, such as a hint about how to fix the problem (`"did you mean 'foo'?"`).
+    ///
+    /// Notes are kept when errors are merged, and are included when the error is displayed. They are commonly
+    /// attached within [`Parser::map_err`], [`Parser::try_map`], or [`Parser::validate`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let semi = just::<_, _, extra::Err<Rich<char>>>(';')
+    ///     .map_err(|e: Rich<char>| e.with_note("statements must end with a semicolon"));
+    ///
+    /// let errs = semi.parse(",").into_errors();
+    /// assert_eq!(errs[0].notes().collect::<Vec<_>>(), vec!["statements must end with a semicolon"]);
+    /// assert_eq!(
+    ///     errs[0].to_string(),
+    ///     "found , expected ';' (note: statements must end with a semicolon)",
+    /// );
+    /// ```
+    pub fn with_note<M: ToString>(mut self, note: M) -> Self {
+        self.notes.push(note.to_string());
+        self
+    }
+
+    /// Get an iterator over the notes attached to this error. See [`Rich::with_note`].
+    pub fn notes(&self) -> impl ExactSizeIterator<Item = &str> {
+        self.notes.iter().map(String::as_str)
+    }
+
     /// Get the span associated with this error.
     pub fn span(&self) -> &S {
         &self.span
     }
 
+    /// Get the span of the delimiter that was left unclosed, if this error was caused by a missing closing delimiter.
+    ///
+    /// See [`DelimitedBy::report_unclosed`](crate::combinator::DelimitedBy::report_unclosed).
+    pub fn unclosed_delimiter(&self) -> Option<&S> {
+        self.unclosed.as_ref()
+    }
+
     /// Get the reason for this error.
     pub fn reason(&self) -> &RichReason<'a, T, L> {
         &self.reason
@@ -576,6 +843,29 @@ impl<'a, T, S, L> Rich<'a, T, S, L> {
         self.reason.found()
     }
 
+    /// Returns `true` if this error occurred because the end of input was reached while more input was expected,
+    /// rather than because of an unexpected token.
+    ///
+    /// Incomplete input might become valid if more of it were provided, so this is useful for REPLs and editors that
+    /// want to ask for a continuation line instead of reporting a syntax error. Errors with custom messages are never
+    /// considered incomplete.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let call = text::ascii::ident::<_, extra::Err<Rich<char>>>()
+    ///     .then(text::int(10).separated_by(just(',')).delimited_by(just('('), just(')')));
+    ///
+    /// // The user hasn't finished typing yet
+    /// assert!(call.parse("foo(1,").into_errors()[0].is_incomplete());
+    /// // This can never become valid, no matter what comes next
+    /// assert!(!call.parse("foo(1,)").into_errors()[0].is_incomplete());
+    /// ```
+    pub fn is_incomplete(&self) -> bool {
+        self.reason.is_incomplete()
+    }
+
     /// Return an iterator over the labelled contexts of this error, from least general to most.
     ///
     /// 'Context' here means parser patterns that the parser was in the process of parsing when the error occurred. To
@@ -600,10 +890,43 @@ impl<'a, T, S, L> Rich<'a, T, S, L> {
     pub fn expected(&self) -> impl ExactSizeIterator<Item = &RichPattern<'a, T, L>> {
         match &*self.reason {
             RichReason::ExpectedFound { expected, .. } => expected.iter(),
-            RichReason::Custom(_) => [].iter(),
+            RichReason::Custom(_) | RichReason::Many(_) => [].iter(),
         }
     }
 
+    /// Render this error in a stable, line-based format intended for snapshot tests.
+    ///
+    /// Unlike [`Display`](fmt::Display), which is intended for humans and may change between versions, the rendered
+    /// format is fixed: one `key: value` line per piece of information, with the expected patterns sorted so that
+    /// their order does not depend on the order in which the parser tried them. Tokens are written with
+    /// [`Debug`](fmt::Debug) so that whitespace and other invisible characters are unambiguous.
+    ///
+    /// The lines are, in order: `at` (the span), `found` and `expected` (or `message` for custom errors, with one
+    /// block per reason if several were merged), then any `context`, `note`, and `unclosed` lines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let list = just::<_, _, extra::Err<Rich<char>>>('a')
+    ///     .separated_by(just(','))
+    ///     .collect::<Vec<_>>()
+    ///     .delimited_by(just('['), just(']'))
+    ///     .report_unclosed();
+    ///
+    /// let errs = list.parse("[a,a\n").into_errors();
+    /// assert_eq!(
+    ///     errs[0].render().to_string(),
+    ///     "at: 4..5\n\
+    ///      found: '\\n'\n\
+    ///      expected: ',', ']'\n\
+    ///      unclosed: 0..1\n",
+    /// );
+    /// ```
+    pub fn render(&self) -> Rendered<'_, 'a, T, S, L> {
+        Rendered(self)
+    }
+
     /// Transform this error's tokens using the given function.
     ///
     /// This is useful when you wish to combine errors from multiple compilation passes (lexing and parsing, say) where
@@ -617,6 +940,8 @@ impl<'a, T, S, L> Rich<'a, T, S, L> {
             reason: Box::new(self.reason.map_token(f)),
             #[cfg(feature = "label")]
             context: self.context,
+            notes: self.notes,
+            unclosed: self.unclosed,
         }
     }
 }
@@ -646,17 +971,74 @@ where
             }),
             #[cfg(feature = "label")]
             context: Vec::new(),
+            notes: Vec::new(),
+            unclosed: None,
         }
     }
 
     #[inline]
-    fn merge(self, other: Self) -> Self {
+    fn expected_range_found(
+        start: MaybeRef<'a, I::Token>,
+        end: MaybeRef<'a, I::Token>,
+        found: Option<MaybeRef<'a, I::Token>>,
+        span: I::Span,
+    ) -> Self {
+        Self {
+            span,
+            reason: Box::new(RichReason::ExpectedFound {
+                expected: vec![RichPattern::Range { start, end }],
+                found,
+            }),
+            #[cfg(feature = "label")]
+            context: Vec::new(),
+            notes: Vec::new(),
+            unclosed: None,
+        }
+    }
+
+    #[inline]
+    fn custom<M: fmt::Display>(
+        _found: Option<MaybeRef<'a, I::Token>>,
+        span: I::Span,
+        msg: M,
+    ) -> Self {
+        Rich::custom(span, msg)
+    }
+
+    #[inline]
+    fn expected_keyword_found(
+        keyword: &str,
+        found: Option<MaybeRef<'a, I::Token>>,
+        span: I::Span,
+    ) -> Self {
+        Self {
+            span,
+            reason: Box::new(RichReason::ExpectedFound {
+                expected: vec![RichPattern::Keyword(keyword.into())],
+                found,
+            }),
+            #[cfg(feature = "label")]
+            context: Vec::new(),
+            notes: Vec::new(),
+            unclosed: None,
+        }
+    }
+
+    #[inline]
+    fn merge(mut self, other: Self) -> Self {
         let new_reason = self.reason.flat_merge(*other.reason);
+        for note in other.notes {
+            if !self.notes.contains(&note) {
+                self.notes.push(note);
+            }
+        }
         Self {
             span: self.span,
             reason: Box::new(new_reason),
             #[cfg(feature = "label")]
             context: self.context, // TOOD: Merge contexts
+            notes: self.notes,
+            unclosed: self.unclosed.or(other.unclosed),
         }
     }
 
@@ -679,7 +1061,7 @@ where
                 }
                 *found = found.take().or(new_found); //land
             }
-            RichReason::Custom(_) => {}
+            RichReason::Custom(_) | RichReason::Many(_) => {}
         }
         // TOOD: Merge contexts
         self
@@ -717,6 +1099,14 @@ where
         }
         #[cfg(feature = "label")]
         self.context.clear();
+        self.notes.clear();
+        self.unclosed = None;
+        self
+    }
+
+    #[inline]
+    fn with_unclosed_delimiter(mut self, opened_at: I::Span) -> Self {
+        self.unclosed = Some(opened_at);
         self
     }
 }
@@ -736,10 +1126,10 @@ where
                 expected.push(RichPattern::Label(label));
             }
             _ => {
-                self.reason = Box::new(RichReason::ExpectedFound {
+                *self.reason = RichReason::ExpectedFound {
                     expected: vec![RichPattern::Label(label)],
                     found: self.reason.take_found(),
-                });
+                };
             }
         }
     }
@@ -774,6 +1164,67 @@ where
     }
 }
 
+/// A stable rendering of a [`Rich`] error, for use in snapshot tests. See [`Rich::render`].
+pub struct Rendered<'b, 'a, T, S, L>(&'b Rich<'a, T, S, L>);
+
+impl<T, S, L> fmt::Display for Rendered<'_, '_, T, S, L>
+where
+    T: fmt::Debug,
+    S: fmt::Display,
+    L: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let err = self.0;
+        writeln!(f, "at: {}", err.span)?;
+        err.reason.render(f)?;
+        #[cfg(feature = "label")]
+        for (label, span) in &err.context {
+            writeln!(f, "context: {label} at {span}")?;
+        }
+        for note in &err.notes {
+            writeln!(f, "note: {note}")?;
+        }
+        if let Some(unclosed) = &err.unclosed {
+            writeln!(f, "unclosed: {unclosed}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: fmt::Debug, L: fmt::Display> RichReason<'_, T, L> {
+    fn render(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RichReason::ExpectedFound { expected, found } => {
+                match found {
+                    Some(found) => writeln!(f, "found: {:?}", &**found)?,
+                    None => writeln!(f, "found: end of input")?,
+                }
+                let mut expected = expected
+                    .iter()
+                    .map(|pat| match pat {
+                        RichPattern::Token(tok) => format!("{:?}", &**tok),
+                        RichPattern::Range { start, end } => {
+                            format!("{:?}..={:?}", &**start, &**end)
+                        }
+                        RichPattern::Label(label) => label.to_string(),
+                        RichPattern::Keyword(keyword) => format!("keyword `{keyword}`"),
+                        RichPattern::EndOfInput => "end of input".to_string(),
+                    })
+                    .collect::<Vec<_>>();
+                expected.sort();
+                expected.dedup();
+                if expected.is_empty() {
+                    writeln!(f, "expected: something else")
+                } else {
+                    writeln!(f, "expected: {}", expected.join(", "))
+                }
+            }
+            RichReason::Custom(msg) => writeln!(f, "message: {msg}"),
+            RichReason::Many(reasons) => reasons.iter().try_for_each(|reason| reason.render(f)),
+        }
+    }
+}
+
 fn write_token<T>(
     f: &mut fmt::Formatter,
     mut fmt_token: impl FnMut(&T, &mut fmt::Formatter<'_>) -> fmt::Result,
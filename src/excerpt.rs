@@ -0,0 +1,141 @@
+//! A simple renderer that prints the line of source code that an error points at, with the error's span underlined.
+//!
+//! *“Forty-two,” said Deep Thought, with infinite majesty and calm.*
+//!
+//! This is intended for command-line tools that want readable errors without depending on a full diagnostic
+//! reporting crate like [`ariadne`](https://crates.io/crates/ariadne). Only the first line of the span is shown.
+//!
+//! Widths are calculated per grapheme cluster, so combining characters and wide characters (such as CJK ideographs
+//! and most emoji) are underlined correctly in a terminal, and tabs are expanded to the next tab stop.
+
+use super::*;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// See [`excerpt`].
+#[derive(Copy, Clone, Debug)]
+pub struct Excerpt<'src, M> {
+    src: &'src str,
+    span: SimpleSpan,
+    message: M,
+    tab_width: usize,
+}
+
+impl<M> Excerpt<'_, M> {
+    /// Set the number of columns between tab stops, used when expanding tabs. The default is 4.
+    pub fn with_tab_width(self, tab_width: usize) -> Self {
+        Self {
+            tab_width: tab_width.max(1),
+            ..self
+        }
+    }
+}
+
+/// Render `message`, followed by the line of `src` that `span` (given in bytes, as by `&str` inputs) starts on, with
+/// the span underlined by carets.
+///
+/// The location is reported as a 1-based line number and a 1-based column, counted in characters.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, excerpt::excerpt};
+/// let src = "let x = [1,\t\t2;";
+/// let list = text::int::<_, extra::Err<Rich<char>>>(10)
+///     .separated_by(just(',').padded())
+///     .collect::<Vec<_>>()
+///     .delimited_by(just('['), just(']'));
+/// let parser = text::ascii::keyword("let")
+///     .ignore_then(text::ascii::ident().padded())
+///     .then_ignore(just('=').padded())
+///     .then(list);
+///
+/// let errs = parser.parse(src).into_errors();
+/// // The tab is expanded to the next tab stop
+/// assert_eq!(
+///     excerpt(src, *errs[0].span(), &errs[0]).to_string(),
+///     "found ; expected ',', or ']'\n\
+///      \x20--> 1:15\n\
+///      \x20 |\n\
+///      1 | let x = [1,     2;\n\
+///      \x20 |                  ^\n",
+/// );
+/// ```
+pub fn excerpt<M: fmt::Display>(src: &str, span: SimpleSpan, message: M) -> Excerpt<'_, M> {
+    Excerpt {
+        src,
+        span,
+        message,
+        tab_width: 4,
+    }
+}
+
+impl<M: fmt::Display> fmt::Display for Excerpt<'_, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let src = self.src;
+        let start = floor_char_boundary(src, self.span.start);
+        let end = floor_char_boundary(src, self.span.end).max(start);
+
+        let line_start = src[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = src[start..].find('\n').map_or(src.len(), |i| start + i);
+        let line_no = src[..line_start].matches('\n').count() + 1;
+        let col = src[line_start..start].chars().count() + 1;
+
+        // Expand the line for display, finding the columns that the span starts and ends at on the way
+        let mut line = String::new();
+        let mut width = 0;
+        let mut caret_start = None;
+        let mut caret_end = None;
+        for (offset, grapheme) in src[line_start..line_end].grapheme_indices(true) {
+            let pos = line_start + offset;
+            if pos >= start {
+                caret_start.get_or_insert(width);
+            }
+            if pos >= end {
+                caret_end.get_or_insert(width);
+            }
+            match grapheme {
+                "\t" => {
+                    let spaces = self.tab_width - width % self.tab_width;
+                    line.push_str(&" ".repeat(spaces));
+                    width += spaces;
+                }
+                "\r" => {}
+                grapheme => {
+                    line.push_str(grapheme);
+                    width += grapheme.width();
+                }
+            }
+        }
+        let caret_start = caret_start.unwrap_or(width);
+        let caret_end = caret_end.unwrap_or(width).max(caret_start + 1);
+
+        // The width of the line number, in digits
+        let mut gutter = 1;
+        let mut n = line_no / 10;
+        while n > 0 {
+            gutter += 1;
+            n /= 10;
+        }
+        writeln!(f, "{}", self.message)?;
+        writeln!(f, "{:gutter$}--> {line_no}:{col}", "")?;
+        writeln!(f, "{:gutter$} |", "")?;
+        writeln!(f, "{line_no} | {line}")?;
+        writeln!(
+            f,
+            "{:gutter$} | {:caret_start$}{}",
+            "",
+            "",
+            "^".repeat(caret_end - caret_start)
+        )
+    }
+}
+
+// Clamp `offset` to the length of `src`, and move it backwards onto a character boundary
+fn floor_char_boundary(src: &str, offset: usize) -> usize {
+    let mut offset = offset.min(src.len());
+    while !src.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    offset
+}
@@ -1,5 +1,10 @@
 //! Generic error, state and context types for parsers
 //! Useful for custom allocation, error handling, context-specific parsers, and more.
+//!
+//! State is *mutable* and shared by the whole parse, which makes it suitable for things like string interners and
+//! arena allocators (see [`Parser::parse_with_state`]). Context is *read-only* configuration, like a language edition
+//! or a set of enabled extensions, that can be overridden for part of a parser (see [`Parser::parse_with_ctx`] and
+//! [`Parser::with_ctx`]).
 
 use inspector::Inspector;
 pub use inspector::SimpleState;
@@ -28,6 +33,9 @@ where
     /// such as interned identifiers or position-dependent name resolution, however *cannot* influence
     /// the actual progress of the parser - for that, use [`Self::Context`].
     ///
+    /// The state may borrow from data owned by the caller (for example, `SimpleState<&mut SymbolTable>`). State that
+    /// borrows from the input itself can instead be owned by the caller and inspected once parsing is done.
+    ///
     /// For examples of using this type, see [`Parser::map_with`] or [`Parser::foldl_with`].
     type State: Inspector<'a, I> + 'a;
     /// Context used for parser configuration. This is used to provide context-sensitive parsing of *input*.
@@ -45,6 +53,28 @@ pub type Default = Full<DefaultErr, DefaultState, DefaultCtx>;
 /// Use specified error type, but default other types. See [`ParserExtra`] for more details.
 pub type Err<E> = Full<E, DefaultState, DefaultCtx>;
 
+/// Use [`Rich`](crate::error::Rich) errors, but default other types. Shorthand for `Err<Rich<'a, T, S, L>>`.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// fn digit<'src>() -> impl Parser<'src, &'src str, char, extra::RichErr<'src, char>> {
+///     any().filter(char::is_ascii_digit)
+/// }
+///
+/// let errs = digit().parse("x").into_errors();
+/// assert_eq!(errs[0].span(), &SimpleSpan::new(0, 1));
+/// ```
+pub type RichErr<'a, T, S = SimpleSpan<usize>, L = &'static str> =
+    Err<crate::error::Rich<'a, T, S, L>>;
+
+/// Use [`Simple`](crate::error::Simple) errors, but default other types. Shorthand for `Err<Simple<'a, T, S>>`.
+pub type SimpleErr<'a, T, S = SimpleSpan<usize>> = Err<crate::error::Simple<'a, T, S>>;
+
+/// Use [`Cheap`](crate::error::Cheap) errors, but default other types. Shorthand for `Err<Cheap<S>>`.
+pub type CheapErr<S = SimpleSpan<usize>> = Err<crate::error::Cheap<S>>;
+
 /// Use specified state type, but default other types. See [`ParserExtra`] for more details.
 ///
 /// Use `State<S>` or `Full<E, S, C>` as the `Extra` type parameter of a parser to use a custom state type.
@@ -0,0 +1,19 @@
+//! Ready-made parsers for common data formats.
+//!
+//! *“Time is an illusion. Lunchtime doubly so.”*
+//!
+//! The parsers in this module are built from the same combinators that are available to you, so as well as being
+//! useful in their own right, they serve as examples of how larger grammars can be put together.
+
+mod address;
+mod version;
+
+pub use address::{email, hostname, Email, Hostname};
+pub use version::{semver, semver_spanned, Prerelease, Version, VersionSpans};
+
+pub mod csv;
+#[cfg(feature = "datetime")]
+pub mod datetime;
+pub mod json;
+pub mod keyvalue;
+pub mod uri;
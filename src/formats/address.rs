@@ -0,0 +1,211 @@
+use crate::{extra::ParserExtra, prelude::*};
+use alloc::vec::Vec;
+
+/// A hostname, split into its labels.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Hostname<'src> {
+    /// The whole hostname.
+    pub name: &'src str,
+    /// The dot-separated labels of the hostname, in order, with their spans.
+    pub labels: Vec<(&'src str, SimpleSpan)>,
+}
+
+/// An email address, split into its parts.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Email<'src> {
+    /// The part before the `@`, with its span. If the local part is quoted, the quotes are included.
+    pub local_part: (&'src str, SimpleSpan),
+    /// The part after the `@`, with its span: either a hostname or an IPv4 address in brackets.
+    pub domain: (&'src str, SimpleSpan),
+}
+
+/// Fail with an error pointing at the span returned by `invalid`, if it returns one.
+fn check<'src, O, E>(
+    parser: impl Parser<'src, &'src str, O, E> + Clone,
+    invalid: impl Fn(&O, SimpleSpan) -> Option<SimpleSpan> + Clone,
+) -> impl Parser<'src, &'src str, O, E> + Clone
+where
+    E: ParserExtra<'src, &'src str>,
+{
+    parser.try_map(move |out, span| match invalid(&out, span) {
+        None => Ok(out),
+        Some(span) => Err(E::Error::expected_found(None, None, span)),
+    })
+}
+
+/// A parser that accepts a hostname, following [RFC 1123](https://www.rfc-editor.org/rfc/rfc1123).
+///
+/// A hostname is made up of labels separated by dots. Each label is 1 to 63 ASCII letters, digits and hyphens, and
+/// may not start or end with a hyphen. The whole hostname may be at most 253 characters long.
+///
+/// Errors point at the part of the hostname that is malformed: the offending character or, for labels (or hostnames)
+/// that are too long, the characters beyond the limit.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, formats};
+/// let hostname = formats::hostname::<extra::Err<Rich<char>>>();
+///
+/// let host = hostname.parse("deep-thought.magrathea.example").into_result().unwrap();
+/// assert_eq!(host.labels[1], ("magrathea", (13..22).into()));
+///
+/// // Labels may not end with a hyphen, so the `.` after one is unexpected
+/// let errs = hostname.parse("deep-.example").into_result().unwrap_err();
+/// assert_eq!(errs[0].span(), &(5..6).into());
+///
+/// let long_label = format!("{}.example", "a".repeat(70));
+/// let errs = formats::hostname::<extra::Err<Rich<char>>>()
+///     .parse(&long_label)
+///     .into_result()
+///     .unwrap_err();
+/// assert_eq!(errs[0].span(), &(63..70).into());
+/// ```
+pub fn hostname<'src, E>() -> impl Parser<'src, &'src str, Hostname<'src>, E> + Clone
+where
+    E: ParserExtra<'src, &'src str>,
+{
+    let alphanumeric = any().filter(char::is_ascii_alphanumeric);
+    let label = alphanumeric
+        .then(just('-').repeated().then(alphanumeric).repeated())
+        .to_slice()
+        .map_with(|label: &str, e| (label, e.span()));
+    let label = check(label, |(label, span), _| {
+        (label.len() > 63).then(|| SimpleSpan::from(span.start + 63..span.end))
+    });
+
+    let labels = label
+        .separated_by(just('.'))
+        .at_least(1)
+        .collect::<Vec<_>>();
+    let hostname = labels.map_with(|labels, e| Hostname {
+        name: e.slice(),
+        labels,
+    });
+    check(hostname, |hostname, span| {
+        (hostname.name.len() > 253).then(|| SimpleSpan::from(span.start + 253..span.end))
+    })
+}
+
+/// A parser that accepts an email address, following a pragmatic subset of
+/// [RFC 5322](https://www.rfc-editor.org/rfc/rfc5322).
+///
+/// The local part may be a sequence of dot-separated atoms (like `ford.prefect+hitchhiker`) or a quoted string (like
+/// `"ford prefect"`), and may be at most 64 characters long. The domain may be a [`hostname`] or an IPv4 address in
+/// brackets (like `[192.0.2.1]`). Comments, folding whitespace and obsolete syntax are not accepted.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, formats};
+/// let email = formats::email::<extra::Err<Rich<char>>>();
+///
+/// let address = email.parse("ford.prefect@betelgeuse.example").into_result().unwrap();
+/// assert_eq!(address.local_part, ("ford.prefect", (0..12).into()));
+/// assert_eq!(address.domain, ("betelgeuse.example", (13..31).into()));
+///
+/// assert!(email.parse("\"zaphod beeblebrox\"@[192.0.2.42]").into_result().is_ok());
+///
+/// // Errors point at the malformed part of the address
+/// let errs = email.parse("ford..prefect@betelgeuse.example").into_result().unwrap_err();
+/// assert_eq!(errs[0].span(), &(5..6).into());
+/// let errs = email.parse("ford@-betelgeuse.example").into_result().unwrap_err();
+/// assert_eq!(errs[0].span(), &(5..6).into());
+/// ```
+pub fn email<'src, E>() -> impl Parser<'src, &'src str, Email<'src>, E> + Clone
+where
+    E: ParserExtra<'src, &'src str>,
+{
+    let atext =
+        any().filter(|c: &char| c.is_ascii_alphanumeric() || "!#$%&'*+-/=?^_`{|}~".contains(*c));
+    let dot_atom = atext
+        .repeated()
+        .at_least(1)
+        .separated_by(just('.'))
+        .at_least(1);
+    let printable = any().filter(|c: &char| c.is_ascii_graphic() || *c == ' ');
+    let quoted = just('\\')
+        .then(printable)
+        .ignored()
+        .or(printable.filter(|c| *c != '"' && *c != '\\').ignored())
+        .repeated()
+        .delimited_by(just('"'), just('"'));
+    let local_part = dot_atom
+        .or(quoted)
+        .to_slice()
+        .map_with(|local: &str, e| (local, e.span()));
+    let local_part = check(local_part, |(local, span), _| {
+        (local.len() > 64).then(|| SimpleSpan::from(span.start + 64..span.end))
+    });
+
+    let octet = check(
+        text::digits(10).at_most(3).to_slice(),
+        |octet: &&str, span| (octet.parse::<u8>().is_err()).then_some(span),
+    );
+    let address_literal = octet
+        .separated_by(just('.'))
+        .exactly(4)
+        .delimited_by(just('['), just(']'));
+    let domain = address_literal
+        .or(hostname().ignored())
+        .to_slice()
+        .map_with(|domain: &str, e| (domain, e.span()));
+
+    local_part
+        .then_ignore(just('@'))
+        .then(domain)
+        .map(|(local_part, domain)| Email { local_part, domain })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hostname_rules() {
+        let max_label = "a".repeat(63);
+        let long_name = [max_label.as_str(); 4].join(".");
+        let hostname = hostname::<extra::Err<Simple<char>>>();
+        for (src, valid) in [
+            ("localhost", true),
+            ("a-b--c.example", true),
+            ("123.example", true),
+            ("", false),
+            ("-a.example", false),
+            ("a-.example", false),
+            ("a..example", false),
+            ("a.example.", false),
+            ("under_score.example", false),
+        ] {
+            assert_eq!(hostname.parse(src).into_result().is_ok(), valid, "{src}");
+        }
+
+        assert!(hostname.parse(&max_label).into_result().is_ok());
+        let errs = hostname.parse(&long_name).into_result().unwrap_err();
+        assert_eq!(errs[0].span(), &(253..255).into());
+    }
+
+    #[test]
+    fn email_rules() {
+        let long_local = format!("{}@example.com", "a".repeat(65));
+        let email = email::<extra::Err<Simple<char>>>();
+        for (src, valid) in [
+            ("a@b", true),
+            ("first.last+tag@example.com", true),
+            ("\"quoted \\\" string\"@example.com", true),
+            ("user@[255.255.255.255]", true),
+            ("user@[256.0.0.1]", false),
+            ("user@[1.2.3]", false),
+            (".user@example.com", false),
+            ("user.@example.com", false),
+            ("user@", false),
+            ("@example.com", false),
+            ("two@@example.com", false),
+        ] {
+            assert_eq!(email.parse(src).into_result().is_ok(), valid, "{src}");
+        }
+
+        let errs = email.parse(&long_local).into_result().unwrap_err();
+        assert_eq!(errs[0].span(), &(64..65).into());
+    }
+}
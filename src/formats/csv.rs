@@ -0,0 +1,354 @@
+//! Parsers for CSV, TSV and other delimiter-separated formats.
+//!
+//! The punctuation of the format is described by a [`Dialect`]. By default, this is the format described by
+//! [RFC 4180](https://www.rfc-editor.org/rfc/rfc4180): fields are separated by commas, fields may be quoted with `"`,
+//! and quotes within quoted fields are written twice.
+//!
+//! Fields that contain no escape sequences are borrowed from the input, so parsing does not allocate for them.
+//!
+//! # Examples
+//!
+//! ```
+//! # use chumsky::{prelude::*, formats::csv::{self, Dialect}};
+//! let records = csv::records::<extra::Err<Rich<char>>>(Dialect::CSV);
+//!
+//! let table = records
+//!     .parse("name,quote\nArthur,\"Ford, you're turning into a penguin.\"\n")
+//!     .into_result()
+//!     .unwrap();
+//!
+//! assert_eq!(table.len(), 2);
+//! assert_eq!(table[1].fields, ["Arthur", "Ford, you're turning into a penguin."]);
+//! ```
+
+use crate::{extra::ParserExtra, prelude::*};
+use alloc::{borrow::Cow, vec::Vec};
+use core::marker::PhantomData;
+
+/// The punctuation of a delimiter-separated format.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Dialect {
+    delimiter: char,
+    quote: char,
+    escape: Option<char>,
+}
+
+impl Dialect {
+    /// Comma-separated values: fields are separated by `,` and quoted with `"`, and quotes are escaped by writing them
+    /// twice.
+    pub const CSV: Self = Self {
+        delimiter: ',',
+        quote: '"',
+        escape: None,
+    };
+
+    /// Tab-separated values: like [`Dialect::CSV`], but fields are separated by tabs.
+    pub const TSV: Self = Self::CSV.with_delimiter('\t');
+
+    /// Separate fields with the given character.
+    pub const fn with_delimiter(self, delimiter: char) -> Self {
+        Self { delimiter, ..self }
+    }
+
+    /// Quote fields with the given character.
+    pub const fn with_quote(self, quote: char) -> Self {
+        Self { quote, ..self }
+    }
+
+    /// Escape characters within quoted fields by prefixing them with the given character, instead of writing quotes
+    /// twice.
+    pub const fn with_escape(self, escape: char) -> Self {
+        Self {
+            escape: Some(escape),
+            ..self
+        }
+    }
+}
+
+impl Default for Dialect {
+    fn default() -> Self {
+        Self::CSV
+    }
+}
+
+/// A record (a line, usually) of a delimiter-separated format.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Record<'src> {
+    /// The fields of the record, with any quoting removed.
+    pub fields: Vec<Cow<'src, str>>,
+    /// The span of the input that the record was parsed from, not including the line ending.
+    pub span: SimpleSpan,
+}
+
+/// A parser that accepts a single field, quoted or not.
+///
+/// The output type of this parser is the text of the field, which is borrowed from the input unless quotes needed
+/// to be removed from within it.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, formats::csv::{self, Dialect}};
+/// # use std::borrow::Cow;
+/// let field = csv::field::<extra::Err<Simple<char>>>(Dialect::CSV);
+///
+/// assert_eq!(field.parse("plain").into_result(), Ok(Cow::Borrowed("plain")));
+/// assert_eq!(field.parse("\"a, b\"").into_result(), Ok(Cow::Borrowed("a, b")));
+/// assert_eq!(field.parse("\"say \"\"hi\"\"\"").into_result(), Ok(Cow::Owned("say \"hi\"".to_string())));
+/// assert!(field.parse("\"unterminated").has_errors());
+///
+/// let escaped = csv::field::<extra::Err<Simple<char>>>(Dialect::CSV.with_escape('\\'));
+/// assert_eq!(escaped.parse(r#""say \"hi\"""#).into_result(), Ok(Cow::Owned("say \"hi\"".to_string())));
+/// ```
+pub fn field<'src, E>(dialect: Dialect) -> impl Parser<'src, &'src str, Cow<'src, str>, E> + Clone
+where
+    E: ParserExtra<'src, &'src str>,
+{
+    let Dialect {
+        delimiter,
+        quote,
+        escape,
+    } = dialect;
+    // Without an escape character, quotes are escaped by writing them twice
+    let escape_char = escape.unwrap_or(quote);
+
+    let quoted = text::escaped(
+        none_of([quote, escape_char]),
+        escape_char,
+        any().filter(move |c| escape.is_some() || *c == quote),
+    )
+    .delimited_by(just(quote), just(quote));
+
+    let unquoted = none_of([delimiter, quote, '\r', '\n'])
+        .repeated()
+        .to_slice()
+        .map(Cow::Borrowed);
+
+    quoted.or(unquoted)
+}
+
+/// A parser that accepts a single record: one or more fields separated by the delimiter of the dialect.
+///
+/// The record's line ending is not included.
+pub fn record<'src, E>(dialect: Dialect) -> impl Parser<'src, &'src str, Record<'src>, E> + Clone
+where
+    E: ParserExtra<'src, &'src str>,
+{
+    field(dialect)
+        .separated_by(just(dialect.delimiter))
+        .at_least(1)
+        .collect()
+        .map_with(|fields, e| Record {
+            fields,
+            span: e.span(),
+        })
+}
+
+/// A record followed by its line ending, recovering from errors in the record by skipping the rest of the line.
+///
+/// Records that could not be parsed produce `None`.
+fn record_line<'src, E>(
+    dialect: Dialect,
+) -> impl Parser<'src, &'src str, Option<Record<'src>>, E> + Clone
+where
+    E: ParserExtra<'src, &'src str>,
+{
+    let line_end = text::newline().or(end());
+    record(dialect)
+        .then_ignore(line_end)
+        .map(Some)
+        .recover_with(via_parser(
+            none_of("\r\n").repeated().then(line_end).to(None),
+        ))
+}
+
+/// A parser that accepts a whole table of records, separated by line endings.
+///
+/// A line ending after the last record is permitted. Records that fail to parse are reported as errors and skipped,
+/// allowing the rest of the table to be parsed.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, formats::csv::{self, Dialect}};
+/// let records = csv::records::<extra::Err<Simple<char>>>(Dialect::TSV);
+///
+/// let (table, errors) = records.parse("a\tb\n\"c\"d\te\nf\tg").into_output_errors();
+/// let table = table.unwrap();
+///
+/// // The malformed second record is skipped
+/// assert_eq!(table.len(), 2);
+/// assert_eq!(table[1].fields, ["f", "g"]);
+/// assert_eq!(table[1].span, (11..14).into());
+/// assert_eq!(errors.len(), 1);
+/// ```
+pub fn records<'src, E>(
+    dialect: Dialect,
+) -> impl Parser<'src, &'src str, Vec<Record<'src>>, E> + Clone
+where
+    E: ParserExtra<'src, &'src str>,
+{
+    end()
+        .not()
+        .ignore_then(record_line(dialect))
+        .repeated()
+        .collect::<Vec<_>>()
+        .map(|records| records.into_iter().flatten().collect())
+}
+
+/// An iterator over the records of a table, parsing each record only when it is requested.
+///
+/// Each item is either a record or, if the record failed to parse, the errors that were produced while parsing it.
+/// Parsing continues with the next line after a record fails.
+///
+/// Each record is parsed from the rest of the input on its own, so the spans of errors are relative to the start of the
+/// record that failed (given by [`Records::offset`] before the record is parsed). The spans of records are adjusted to
+/// be relative to the start of the whole input.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, formats::csv::{Dialect, Records}};
+/// let mut records = Records::<extra::Err<Simple<char>>>::new("1,2\n3,\"4\n5,6", Dialect::CSV);
+///
+/// assert_eq!(records.next().unwrap().unwrap().fields, ["1", "2"]);
+/// assert!(records.next().unwrap().is_err());
+/// assert_eq!(records.next().unwrap().unwrap().span, (9..12).into());
+/// assert!(records.next().is_none());
+/// ```
+pub struct Records<'src, E: ParserExtra<'src, &'src str>> {
+    input: &'src str,
+    offset: usize,
+    dialect: Dialect,
+    phantom: PhantomData<E>,
+}
+
+impl<'src, E: ParserExtra<'src, &'src str>> Records<'src, E> {
+    /// Create an iterator over the records of the given input.
+    pub fn new(input: &'src str, dialect: Dialect) -> Self {
+        Self {
+            input,
+            offset: 0,
+            dialect,
+            phantom: PhantomData,
+        }
+    }
+
+    /// The part of the input that has not yet been parsed.
+    pub fn remaining(&self) -> &'src str {
+        &self.input[self.offset..]
+    }
+
+    /// The byte offset of the part of the input that has not yet been parsed.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl<'src, E> Iterator for Records<'src, E>
+where
+    E: ParserExtra<'src, &'src str>,
+    E::State: Default,
+    E::Context: Default,
+{
+    type Item = Result<Record<'src>, Vec<E::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.input.len() {
+            return None;
+        }
+
+        let offset = self.offset;
+        let (out, errs) = record_line::<E>(self.dialect)
+            .map_with(|record, e| (record, e.span()))
+            .lazy()
+            .parse(self.remaining())
+            .into_output_errors();
+        // Recovery always succeeds, so there's always an output
+        let (record, span) = out?;
+        self.offset += span.end;
+
+        Some(match record {
+            Some(mut record) if errs.is_empty() => {
+                record.span = (record.span.start + offset..record.span.end + offset).into();
+                Ok(record)
+            }
+            _ => Err(errs),
+        })
+    }
+}
+
+impl<'src, E: ParserExtra<'src, &'src str>> Clone for Records<'src, E> {
+    fn clone(&self) -> Self {
+        Self {
+            input: self.input,
+            offset: self.offset,
+            dialect: self.dialect,
+            phantom: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quoted_fields_across_lines() {
+        let records = records::<extra::Err<Rich<char>>>(Dialect::CSV.with_delimiter(';'));
+        let table = records
+            .parse("\"a\nb\";c\r\n;\n\"\"\"\"")
+            .into_result()
+            .unwrap();
+
+        assert_eq!(table.len(), 3);
+        assert_eq!(table[0].fields, ["a\nb", "c"]);
+        assert_eq!(table[1].fields, ["", ""]);
+        assert_eq!(table[2].fields, ["\""]);
+        assert!(matches!(table[0].fields[0], Cow::Borrowed(_)));
+        assert!(matches!(table[2].fields[0], Cow::Owned(_)));
+    }
+
+    #[test]
+    fn empty_input() {
+        let records = records::<extra::Err<Simple<char>>>(Dialect::CSV);
+        assert_eq!(records.parse("").into_result(), Ok(Vec::new()));
+        assert_eq!(
+            Records::<extra::Err<Simple<char>>>::new("", Dialect::CSV).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn iterator_matches_parser() {
+        let input = "a,b\n\"c\"x,d\ne,\"f\"\n\n";
+        let (table, errs) = records::<extra::Err<Rich<char>>>(Dialect::CSV)
+            .parse(input)
+            .into_output_errors();
+
+        let mut iter_table = Vec::new();
+        let mut iter_errs = Vec::new();
+        let mut records = Records::<extra::Err<Rich<char>>>::new(input, Dialect::CSV);
+        loop {
+            let offset = records.offset();
+            match records.next() {
+                Some(Ok(record)) => iter_table.push(record),
+                Some(Err(errs)) => iter_errs.extend(errs.iter().map(|e| {
+                    (
+                        e.span().start + offset..e.span().end + offset,
+                        e.to_string(),
+                    )
+                })),
+                None => break,
+            }
+        }
+
+        assert_eq!(table, Some(iter_table));
+        assert_eq!(
+            errs.iter()
+                .map(|e| (e.span().into_range(), e.to_string()))
+                .collect::<Vec<_>>(),
+            iter_errs
+        );
+    }
+}
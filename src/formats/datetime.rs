@@ -0,0 +1,456 @@
+//! Parsers for dates, times and durations, as described by [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339) and
+//! ISO 8601.
+//!
+//! This module is only available with the `datetime` feature.
+//!
+//! The parsers produce simple plain-data types ([`Date`], [`Time`], [`DateTime`] and [`Duration`]) rather than the
+//! types of a particular date and time library, so that they can be converted into whichever one an application
+//! already uses. Components are validated as they are parsed (including the number of days in the month, accounting
+//! for leap years), and invalid components are reported with the span of just that component, which makes for
+//! precise errors when timestamps appear in configuration files.
+//!
+//! # Examples
+//!
+//! ```
+//! # use chumsky::{prelude::*, formats::datetime::{self, Date}};
+//! let timestamp = datetime::datetime::<extra::Err<Rich<char>>>();
+//!
+//! let launch = timestamp.parse("1978-03-08T20:00:00+00:00").into_result().unwrap();
+//! assert_eq!(launch.date, Date { year: 1978, month: 3, day: 8 });
+//!
+//! // February 1979 had no 29th day, and the error points at exactly that component
+//! let errs = timestamp.parse("1979-02-29T12:00:00Z").into_result().unwrap_err();
+//! assert_eq!(errs[0].span(), &(8..10).into());
+//! ```
+
+use crate::{extra::ParserExtra, prelude::*};
+
+/// A calendar date.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    /// The year, from 0 to 9999.
+    pub year: u16,
+    /// The month, from 1 to 12.
+    pub month: u8,
+    /// The day of the month, from 1 to 31.
+    pub day: u8,
+}
+
+/// A time of day, without an offset.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Time {
+    /// The hour, from 0 to 23.
+    pub hour: u8,
+    /// The minute, from 0 to 59.
+    pub minute: u8,
+    /// The second, from 0 to 60 (to allow for leap seconds).
+    pub second: u8,
+    /// The fraction of the second, in nanoseconds. Digits beyond the ninth are ignored.
+    pub nanosecond: u32,
+}
+
+/// A date and time, with an offset from UTC.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DateTime {
+    /// The date.
+    pub date: Date,
+    /// The time of day.
+    pub time: Time,
+    /// The offset from UTC, in minutes east. `Z` and `-00:00` both produce an offset of zero.
+    pub offset_minutes: i16,
+}
+
+/// The spans of the components of a [`DateTime`]. See [`datetime_spanned`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DateTimeSpans {
+    /// The span of the year.
+    pub year: SimpleSpan,
+    /// The span of the month.
+    pub month: SimpleSpan,
+    /// The span of the day.
+    pub day: SimpleSpan,
+    /// The span of the hour.
+    pub hour: SimpleSpan,
+    /// The span of the minute.
+    pub minute: SimpleSpan,
+    /// The span of the second.
+    pub second: SimpleSpan,
+    /// The span of the fractional digits of the second (not including the `.`), if any.
+    pub fraction: Option<SimpleSpan>,
+    /// The span of the offset, including its sign, or of the `Z`.
+    pub offset: SimpleSpan,
+}
+
+/// A duration, as its components. Components that were not present in the input are zero.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Duration {
+    /// The number of years.
+    pub years: u32,
+    /// The number of months.
+    pub months: u32,
+    /// The number of weeks.
+    pub weeks: u32,
+    /// The number of days.
+    pub days: u32,
+    /// The number of hours.
+    pub hours: u32,
+    /// The number of minutes.
+    pub minutes: u32,
+    /// The number of seconds.
+    pub seconds: u32,
+    /// The fraction of a second, in nanoseconds. Digits beyond the ninth are ignored.
+    pub nanoseconds: u32,
+}
+
+fn is_leap_year(year: u16) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Exactly `n` ASCII digits, with their value and span.
+fn digits<'src, E>(n: usize) -> impl Parser<'src, &'src str, (u32, SimpleSpan), E> + Copy
+where
+    E: ParserExtra<'src, &'src str>,
+{
+    any()
+        .filter(char::is_ascii_digit)
+        .repeated()
+        .exactly(n)
+        .to_slice()
+        .map_with(|digits: &str, e| (digits.parse().unwrap(), e.span()))
+}
+
+/// Exactly `n` ASCII digits, producing an error with their span if their value is not within `min..=max`.
+fn component<'src, E>(
+    n: usize,
+    min: u32,
+    max: u32,
+) -> impl Parser<'src, &'src str, (u32, SimpleSpan), E> + Copy
+where
+    E: ParserExtra<'src, &'src str>,
+{
+    digits(n).try_map(move |(value, span), _| {
+        if (min..=max).contains(&value) {
+            Ok((value, span))
+        } else {
+            Err(E::Error::expected_found(None, None, span))
+        }
+    })
+}
+
+/// A fraction of a second in nanoseconds, from one or more digits (not including the `.`).
+fn nanoseconds<'src, E>() -> impl Parser<'src, &'src str, (u32, SimpleSpan), E> + Copy
+where
+    E: ParserExtra<'src, &'src str>,
+{
+    any()
+        .filter(char::is_ascii_digit)
+        .repeated()
+        .at_least(1)
+        .to_slice()
+        .map_with(|digits: &str, e| {
+            let nanos = digits
+                .bytes()
+                .chain(core::iter::repeat(b'0'))
+                .take(9)
+                .fold(0, |nanos, digit| nanos * 10 + u32::from(digit - b'0'));
+            (nanos, e.span())
+        })
+}
+
+fn date_spanned<'src, E>() -> impl Parser<'src, &'src str, (Date, [SimpleSpan; 3]), E> + Copy
+where
+    E: ParserExtra<'src, &'src str>,
+{
+    digits(4)
+        .then_ignore(just('-'))
+        .then(component(2, 1, 12))
+        .then_ignore(just('-'))
+        .then(component(2, 1, 31))
+        .try_map(
+            |(((year, year_span), (month, month_span)), (day, day_span)), _| {
+                let date = Date {
+                    year: year as u16,
+                    month: month as u8,
+                    day: day as u8,
+                };
+                if date.day <= days_in_month(date.year, date.month) {
+                    Ok((date, [year_span, month_span, day_span]))
+                } else {
+                    Err(E::Error::expected_found(None, None, day_span))
+                }
+            },
+        )
+}
+
+fn time_spanned<'src, E>(
+) -> impl Parser<'src, &'src str, (Time, [SimpleSpan; 3], Option<SimpleSpan>), E> + Copy
+where
+    E: ParserExtra<'src, &'src str>,
+{
+    component(2, 0, 23)
+        .then_ignore(just(':'))
+        .then(component(2, 0, 59))
+        .then_ignore(just(':'))
+        .then(component(2, 0, 60))
+        .then(just('.').ignore_then(nanoseconds()).or_not())
+        .map(|(((hour, minute), second), fraction)| {
+            let time = Time {
+                hour: hour.0 as u8,
+                minute: minute.0 as u8,
+                second: second.0 as u8,
+                nanosecond: fraction.map_or(0, |(nanos, _)| nanos),
+            };
+            (
+                time,
+                [hour.1, minute.1, second.1],
+                fraction.map(|(_, span)| span),
+            )
+        })
+}
+
+/// A parser that accepts a date in the form `YYYY-MM-DD` (RFC 3339's `full-date`).
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, formats::datetime::{self, Date}};
+/// let date = datetime::date::<extra::Err<Simple<char>>>();
+///
+/// assert_eq!(date.parse("2000-02-29").into_result(), Ok(Date { year: 2000, month: 2, day: 29 }));
+/// assert!(date.parse("1900-02-29").has_errors());
+/// assert!(date.parse("2001-13-01").has_errors());
+/// assert!(date.parse("2001-1-01").has_errors());
+/// ```
+pub fn date<'src, E>() -> impl Parser<'src, &'src str, Date, E> + Copy
+where
+    E: ParserExtra<'src, &'src str>,
+{
+    date_spanned().map(|(date, _)| date)
+}
+
+/// A parser that accepts a time of day in the form `hh:mm:ss`, optionally followed by a fraction of a second
+/// (RFC 3339's `partial-time`).
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, formats::datetime::{self, Time}};
+/// let time = datetime::time::<extra::Err<Simple<char>>>();
+///
+/// assert_eq!(
+///     time.parse("23:59:60.25").into_result(),
+///     Ok(Time { hour: 23, minute: 59, second: 60, nanosecond: 250_000_000 }),
+/// );
+/// assert!(time.parse("24:00:00").has_errors());
+/// assert!(time.parse("12:00").has_errors());
+/// ```
+pub fn time<'src, E>() -> impl Parser<'src, &'src str, Time, E> + Copy
+where
+    E: ParserExtra<'src, &'src str>,
+{
+    time_spanned().map(|(time, _, _)| time)
+}
+
+/// A parser that accepts an RFC 3339 timestamp, producing the spans of each of its components along with it.
+///
+/// The date and time may be separated by `T`, `t` or a space, and the offset may be `Z`, `z` or `±hh:mm`.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, formats::datetime};
+/// let timestamp = datetime::datetime_spanned::<extra::Err<Simple<char>>>();
+///
+/// let (datetime, spans) = timestamp.parse("2024-05-25 11:42:00.5-05:30").into_result().unwrap();
+/// assert_eq!(datetime.offset_minutes, -330);
+/// assert_eq!(spans.minute, (14..16).into());
+/// assert_eq!(spans.fraction, Some((20..21).into()));
+/// assert_eq!(spans.offset, (21..27).into());
+/// ```
+pub fn datetime_spanned<'src, E>(
+) -> impl Parser<'src, &'src str, (DateTime, DateTimeSpans), E> + Copy
+where
+    E: ParserExtra<'src, &'src str>,
+{
+    let numeric_offset = one_of("+-")
+        .then(component(2, 0, 23))
+        .then_ignore(just(':'))
+        .then(component(2, 0, 59))
+        .map(|((sign, (hours, _)), (minutes, _))| {
+            let minutes = (hours * 60 + minutes) as i16;
+            if sign == '-' {
+                -minutes
+            } else {
+                minutes
+            }
+        });
+    let offset = one_of("Zz")
+        .to(0)
+        .or(numeric_offset)
+        .map_with(|offset, e| (offset, e.span()));
+
+    date_spanned()
+        .then_ignore(one_of("Tt "))
+        .then(time_spanned())
+        .then(offset)
+        .map(
+            |(
+                ((date, [year, month, day]), (time, [hour, minute, second], fraction)),
+                (offset_minutes, offset),
+            )| {
+                (
+                    DateTime {
+                        date,
+                        time,
+                        offset_minutes,
+                    },
+                    DateTimeSpans {
+                        year,
+                        month,
+                        day,
+                        hour,
+                        minute,
+                        second,
+                        fraction,
+                        offset,
+                    },
+                )
+            },
+        )
+}
+
+/// A parser that accepts an RFC 3339 timestamp, like `1985-04-12T23:20:50.52Z`.
+///
+/// See [`datetime_spanned`] for details of the accepted syntax.
+pub fn datetime<'src, E>() -> impl Parser<'src, &'src str, DateTime, E> + Copy
+where
+    E: ParserExtra<'src, &'src str>,
+{
+    datetime_spanned().map(|(datetime, _)| datetime)
+}
+
+/// A parser that accepts an ISO 8601 duration, like `P1Y2M3DT4H5M6.5S` or `P2W`.
+///
+/// Every component is optional, but at least one must be present, and `T` must be followed by at least one of the
+/// time components. Only seconds may have a fractional part.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, formats::datetime::{self, Duration}};
+/// let duration = datetime::duration::<extra::Err<Simple<char>>>();
+///
+/// assert_eq!(
+///     duration.parse("P1DT12H0.25S").into_result(),
+///     Ok(Duration { days: 1, hours: 12, nanoseconds: 250_000_000, ..Duration::default() }),
+/// );
+/// assert_eq!(duration.parse("P2W").into_result(), Ok(Duration { weeks: 2, ..Duration::default() }));
+/// assert!(duration.parse("P").has_errors());
+/// assert!(duration.parse("P1D T").has_errors());
+/// assert!(duration.parse("PT1D").has_errors());
+/// ```
+pub fn duration<'src, E>() -> impl Parser<'src, &'src str, Duration, E> + Clone
+where
+    E: ParserExtra<'src, &'src str>,
+{
+    let number = text::int(10).from_str::<u32>().unwrapped();
+    let unit = |designator| number.then_ignore(just(designator)).or_not();
+
+    let date_part = unit('Y').then(unit('M')).then(unit('W')).then(unit('D'));
+
+    let seconds = number
+        .then(one_of(".,").ignore_then(nanoseconds()).or_not())
+        .then_ignore(just('S'))
+        .or_not();
+    let time_part = just('T')
+        .ignore_then(unit('H').then(unit('M')).then(seconds))
+        .filter(|((hours, minutes), seconds)| {
+            hours.is_some() || minutes.is_some() || seconds.is_some()
+        })
+        .or_not();
+
+    just('P').ignore_then(date_part.then(time_part)).try_map(
+        |((((years, months), weeks), days), time), span| {
+            let ((hours, minutes), seconds) = time.unwrap_or_default();
+            if [years, months, weeks, days, hours, minutes]
+                .iter()
+                .all(Option::is_none)
+                && seconds.is_none()
+            {
+                return Err(E::Error::expected_found(None, None, span));
+            }
+            let (seconds, fraction) = seconds.unwrap_or_default();
+            Ok(Duration {
+                years: years.unwrap_or(0),
+                months: months.unwrap_or(0),
+                weeks: weeks.unwrap_or(0),
+                days: days.unwrap_or(0),
+                hours: hours.unwrap_or(0),
+                minutes: minutes.unwrap_or(0),
+                seconds,
+                nanoseconds: fraction.map_or(0, |(nanos, _)| nanos),
+            })
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_in_month() {
+        let date = date::<extra::Err<Simple<char>>>();
+        for (src, valid) in [
+            ("2024-02-29", true),
+            ("2023-02-29", false),
+            ("2000-02-29", true),
+            ("2100-02-29", false),
+            ("2024-04-30", true),
+            ("2024-04-31", false),
+            ("2024-12-31", true),
+            ("2024-01-00", false),
+        ] {
+            assert_eq!(
+                date.parse(src).into_result().is_ok(),
+                valid,
+                "{src} was handled incorrectly"
+            );
+        }
+    }
+
+    #[test]
+    fn component_error_spans() {
+        let datetime = datetime::<extra::Err<Rich<char>>>();
+        for (src, span) in [
+            ("2024-13-01T00:00:00Z", 5..7),
+            ("2024-01-01T24:00:00Z", 11..13),
+            ("2024-01-01T00:60:00Z", 14..16),
+            ("2024-01-01T00:00:61Z", 17..19),
+            ("2024-01-01T00:00:00+01:60", 23..25),
+        ] {
+            let errs = datetime.parse(src).into_result().unwrap_err();
+            assert_eq!(errs[0].span(), &span.into(), "{src}");
+        }
+    }
+
+    #[test]
+    fn fractions() {
+        let time = time::<extra::Err<Simple<char>>>();
+        let nanos = |src| time.parse(src).into_result().map(|time| time.nanosecond);
+        assert_eq!(nanos("00:00:00"), Ok(0));
+        assert_eq!(nanos("00:00:00.1"), Ok(100_000_000));
+        assert_eq!(nanos("00:00:00.000000001"), Ok(1));
+        assert_eq!(nanos("00:00:00.1234567891"), Ok(123_456_789));
+        assert!(nanos("00:00:00.").is_err());
+    }
+}
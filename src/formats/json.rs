@@ -0,0 +1,315 @@
+//! A parser for JSON, with optional support for comments and trailing commas.
+//!
+//! By default, the parser accepts exactly the grammar of [RFC 8259](https://www.rfc-editor.org/rfc/rfc8259). Some
+//! relaxations that are common in configuration files (as found in JSON5 and JSONC) may be enabled with [`Options`].
+//!
+//! Every value in the output carries the span of the input that it was parsed from, so that later stages (such as
+//! deserialization into a configuration struct) can report errors that point to the right place. Strings are borrowed
+//! from the input unless they contain escape sequences.
+//!
+//! The parser recovers from errors within arrays and objects, producing [`JsonValue::Invalid`] in place of values that
+//! could not be parsed.
+//!
+//! # Examples
+//!
+//! ```
+//! # use chumsky::{prelude::*, formats::json::{self, JsonValue, Options}};
+//! let json = json::value::<extra::Err<Rich<char>>>(Options::STRICT.comments(true));
+//!
+//! let value = json
+//!     .parse(r#"{ "answer": 42, /* the question is unknown */ "towel": true }"#)
+//!     .into_result()
+//!     .unwrap();
+//!
+//! let JsonValue::Object(members) = value else { panic!() };
+//! assert_eq!(members[0].0, ("answer".into(), (2..10).into()));
+//! assert_eq!(members[0].1, (JsonValue::Number(42.0), (12..14).into()));
+//! ```
+
+use crate::{extra::ParserExtra, prelude::*};
+use alloc::{borrow::Cow, vec::Vec};
+
+/// A value paired with the span of the input that it was parsed from.
+pub type Spanned<T> = (T, SimpleSpan);
+
+/// A JSON value.
+#[derive(Clone, Debug, PartialEq)]
+pub enum JsonValue<'src> {
+    /// A value that could not be parsed. Only produced when the parser recovers from an error.
+    Invalid,
+    /// `null`.
+    Null,
+    /// `true` or `false`.
+    Bool(bool),
+    /// A number.
+    Number(f64),
+    /// A string, with escape sequences decoded.
+    String(Cow<'src, str>),
+    /// An array of values.
+    Array(Vec<Spanned<JsonValue<'src>>>),
+    /// An object, as its members in the order they appear in the input. Duplicate keys are preserved.
+    Object(Vec<(Spanned<Cow<'src, str>>, Spanned<JsonValue<'src>>)>),
+}
+
+/// The relaxations of the JSON grammar that the parser accepts.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Options {
+    comments: bool,
+    trailing_commas: bool,
+}
+
+impl Options {
+    /// Accept only standard JSON.
+    pub const STRICT: Self = Self {
+        comments: false,
+        trailing_commas: false,
+    };
+
+    /// Accept both `// line` and `/* block */` comments wherever whitespace is permitted (default: false).
+    pub const fn comments(self, comments: bool) -> Self {
+        Self { comments, ..self }
+    }
+
+    /// Accept a comma after the last element of an array or the last member of an object (default: false).
+    pub const fn trailing_commas(self, trailing_commas: bool) -> Self {
+        Self {
+            trailing_commas,
+            ..self
+        }
+    }
+}
+
+/// Whitespace, and comments if they are enabled.
+fn whitespace<'src, E>(options: Options) -> impl Parser<'src, &'src str, (), E> + Clone
+where
+    E: ParserExtra<'src, &'src str>,
+{
+    let line_comment = just("//").then(none_of("\r\n").repeated()).ignored();
+    let block_comment = just("/*")
+        .then(any().and_is(just("*/").not()).repeated())
+        .then(just("*/"))
+        .ignored();
+    let comment = empty()
+        .filter(move |_| options.comments)
+        .ignore_then(line_comment.or(block_comment));
+
+    one_of(" \t\r\n").ignored().or(comment).repeated()
+}
+
+/// A string literal, including its quotes.
+fn string<'src, E>() -> impl Parser<'src, &'src str, Cow<'src, str>, E> + Clone
+where
+    E: ParserExtra<'src, &'src str>,
+{
+    let hex4 = any()
+        .filter(char::is_ascii_hexdigit)
+        .repeated()
+        .exactly(4)
+        .to_slice()
+        .map(|digits| u32::from_str_radix(digits, 16).unwrap());
+    let surrogate_pair = hex4
+        .filter(|hi| (0xD800..0xDC00).contains(hi))
+        .then_ignore(just("\\u"))
+        .then(hex4.filter(|lo| (0xDC00..0xE000).contains(lo)))
+        .map(|(hi, lo)| 0x10000 + ((hi - 0xD800) << 10) + (lo - 0xDC00));
+    // Lone surrogates are not valid characters, so `from_u32` rejects them
+    let unicode = surrogate_pair.or(hex4).filter_map(char::from_u32);
+
+    let escape = choice((
+        just('"'),
+        just('\\'),
+        just('/'),
+        just('b').to('\x08'),
+        just('f').to('\x0C'),
+        just('n').to('\n'),
+        just('r').to('\r'),
+        just('t').to('\t'),
+        just('u').ignore_then(unicode),
+    ));
+    let normal = any().filter(|c: &char| *c != '"' && *c != '\\' && !c.is_control());
+
+    text::escaped(normal, '\\', escape).delimited_by(just('"'), just('"'))
+}
+
+/// A number, following the JSON grammar (no leading zeros, no leading `+`, digits on both sides of a `.`).
+fn number<'src, E>() -> impl Parser<'src, &'src str, f64, E> + Clone
+where
+    E: ParserExtra<'src, &'src str>,
+{
+    let digits = text::digits(10);
+    let frac = just('.').then(digits);
+    let exp = one_of("eE").then(one_of("+-").or_not()).then(digits);
+
+    just('-')
+        .or_not()
+        .then(text::int(10))
+        .then(frac.or_not())
+        .then(exp.or_not())
+        .to_slice()
+        .map(|number: &str| number.parse().unwrap())
+}
+
+/// A parser that accepts a JSON value, surrounded by optional whitespace.
+///
+/// See the [module-level documentation](self) for more information.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, formats::json::{self, JsonValue, Options}};
+/// let strict = json::value::<extra::Err<Simple<char>>>(Options::STRICT);
+/// let relaxed = json::value::<extra::Err<Simple<char>>>(Options::STRICT.trailing_commas(true));
+///
+/// assert_eq!(
+///     strict.parse(r#"["\u00e9\ud83d\ude00", -1.5e3]"#).into_result(),
+///     Ok(JsonValue::Array(vec![
+///         (JsonValue::String("é😀".into()), (1..21).into()),
+///         (JsonValue::Number(-1500.0), (23..29).into()),
+///     ])),
+/// );
+///
+/// assert!(strict.parse("[1, 2,]").has_errors());
+/// assert!(relaxed.parse("[1, 2,]").into_result().is_ok());
+///
+/// // Errors within arrays and objects are recovered from
+/// let (value, errors) = strict.parse("[1, 01, 3]").into_output_errors();
+/// assert_eq!(errors.len(), 1);
+/// let Some(JsonValue::Array(items)) = value else { panic!() };
+/// assert_eq!(items.len(), 3);
+/// ```
+pub fn value<'src, E>(options: Options) -> impl Parser<'src, &'src str, JsonValue<'src>, E> + Clone
+where
+    E: ParserExtra<'src, &'src str>,
+{
+    let ws = whitespace(options);
+
+    recursive({
+        let ws = ws.clone();
+        move |value| {
+            let spanned_value = value
+                .map_with(|value, e| (value, e.span()))
+                .padded_by(ws.clone());
+            let comma = just(',');
+            let trailing_comma = comma.filter(move |_| options.trailing_commas).or_not();
+
+            let array = spanned_value
+                .clone()
+                .separated_by(comma.recover_with(skip_then_retry_until(
+                    any().ignored(),
+                    one_of(",]").ignored(),
+                )))
+                .collect()
+                .then_ignore(trailing_comma)
+                .padded_by(ws.clone())
+                .delimited_by(just('['), just(']'))
+                .map(JsonValue::Array)
+                .boxed();
+
+            let member = string()
+                .map_with(|key, e| (key, e.span()))
+                .padded_by(ws.clone())
+                .then_ignore(just(':'))
+                .then(spanned_value);
+            let object = member
+                .separated_by(comma.recover_with(skip_then_retry_until(
+                    any().ignored(),
+                    one_of(",}").ignored(),
+                )))
+                .collect()
+                .then_ignore(trailing_comma)
+                .padded_by(ws.clone())
+                .delimited_by(just('{'), just('}'))
+                .map(JsonValue::Object)
+                .boxed();
+
+            choice((
+                just("null").to(JsonValue::Null),
+                just("true").to(JsonValue::Bool(true)),
+                just("false").to(JsonValue::Bool(false)),
+                number().map(JsonValue::Number),
+                string().map(JsonValue::String),
+                array,
+                object,
+            ))
+            .recover_with(via_parser(nested_delimiters(
+                '[',
+                ']',
+                [('{', '}')],
+                |_| JsonValue::Invalid,
+            )))
+            .recover_with(via_parser(nested_delimiters(
+                '{',
+                '}',
+                [('[', ']')],
+                |_| JsonValue::Invalid,
+            )))
+            .recover_with(skip_then_retry_until(
+                any().ignored(),
+                one_of(",]}").ignored(),
+            ))
+        }
+    })
+    .padded_by(ws)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(options: Options, src: &str) -> Result<JsonValue<'_>, Vec<Simple<'_, char>>> {
+        value::<extra::Err<Simple<char>>>(options)
+            .parse(src)
+            .into_result()
+    }
+
+    #[test]
+    fn rejects_non_standard_json() {
+        for src in [
+            "",
+            "01",
+            "+1",
+            "1.",
+            ".5",
+            "tru",
+            "\"\\x\"",
+            "\"\\ud800\"",
+            "\"\n\"",
+            "[1,]",
+            "{\"a\":1,}",
+            "// comment\n1",
+            "{'a': 1}",
+        ] {
+            assert!(parse(Options::STRICT, src).is_err(), "{src:?} was accepted");
+        }
+    }
+
+    #[test]
+    fn relaxations() {
+        let options = Options::STRICT.comments(true).trailing_commas(true);
+        assert_eq!(
+            parse(options, "// leading\n[1, /* two */ 2, ] // trailing"),
+            Ok(JsonValue::Array(vec![
+                (JsonValue::Number(1.0), (12..13).into()),
+                (JsonValue::Number(2.0), (25..26).into()),
+            ])),
+        );
+        assert!(parse(options, "[1,,]").is_err());
+        assert!(parse(options, "/* unterminated").is_err());
+    }
+
+    #[test]
+    fn strings_borrow_when_possible() {
+        let Ok(JsonValue::Object(members)) =
+            parse(Options::STRICT, r#"{"plain": "text", "esc\"aped": "\t"}"#)
+        else {
+            panic!()
+        };
+        assert!(matches!(members[0].0 .0, Cow::Borrowed("plain")));
+        assert!(matches!(
+            &members[0].1 .0,
+            JsonValue::String(Cow::Borrowed("text"))
+        ));
+        assert!(matches!(&members[1].0 .0, Cow::Owned(key) if key == "esc\"aped"));
+        assert_eq!(members[1].1 .1, (31..35).into());
+    }
+}
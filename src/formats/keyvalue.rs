@@ -0,0 +1,391 @@
+//! Parsers for INI-style configuration files, made up of `key = value` entries grouped into `[sections]`.
+//!
+//! The format accepted is the common ground between INI files and the simpler parts of TOML:
+//!
+//! - Each line is blank, a comment, a section header like `[server]`, or an entry like `port = 8080`.
+//! - Comments start with `#` or `;` and run to the end of the line. They may follow headers and entries.
+//! - Keys are made up of ASCII letters, digits, `_`, `-` and `.`. Keys and values may be separated by `=` or `:`.
+//! - Values may be double-quoted (with `\` escapes), single-quoted (taken literally), or unquoted, in which case they
+//!   run until the end of the line or the start of a comment, with surrounding whitespace removed.
+//! - Entries that come before the first section header belong to an unnamed section.
+//!
+//! Entries are collected into any [`Container`], so a section can be read directly into a [`BTreeMap`] or a
+//! [`HashMap`]. What happens when a key appears more than once in a section is controlled by [`DuplicateKeys`].
+//!
+//! Lines that fail to parse are reported as errors and skipped, allowing the rest of the file to be parsed.
+//!
+//! [`BTreeMap`]: alloc::collections::BTreeMap
+//! [`HashMap`]: hashbrown::HashMap
+//!
+//! # Examples
+//!
+//! ```
+//! # use chumsky::{prelude::*, formats::keyvalue::{self, DuplicateKeys}};
+//! # use std::collections::BTreeMap;
+//! let config = keyvalue::document::<BTreeMap<_, _>, extra::Err<Rich<char>>>(DuplicateKeys::Error);
+//!
+//! let sections = config
+//!     .parse("# Deep Thought\n[answer]\nvalue = 42\nquestion = \"unknown\" ; for now\n")
+//!     .into_result()
+//!     .unwrap();
+//!
+//! assert_eq!(sections[0].name, Some(("answer", (16..22).into())));
+//! assert_eq!(sections[0].entries["value"], "42");
+//! assert_eq!(sections[0].entries["question"], "unknown");
+//! ```
+
+use crate::{container::Container, extra::ParserExtra, prelude::*};
+use alloc::{borrow::Cow, vec::Vec};
+
+/// A value paired with the span of the input that it was parsed from.
+pub type Spanned<T> = (T, SimpleSpan);
+
+/// What to do when a key appears more than once in the same section.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DuplicateKeys {
+    /// Pass every entry on to the container, in order. A `Vec` will keep all of them, while maps will keep the last.
+    #[default]
+    Keep,
+    /// Keep only the first entry with each key.
+    First,
+    /// Keep only the last entry with each key.
+    Last,
+    /// Produce an error for every entry after the first with each key, keeping only the first.
+    Error,
+}
+
+/// A section of a configuration file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Section<'src, C> {
+    /// The name of the section and its span, or `None` for the entries before the first section header.
+    pub name: Option<Spanned<&'src str>>,
+    /// The entries of the section, as `(key, value)` pairs.
+    pub entries: C,
+}
+
+#[derive(Clone)]
+enum Line<'src> {
+    Blank,
+    Header(Spanned<&'src str>),
+    Entry(Spanned<&'src str>, Cow<'src, str>),
+}
+
+/// Spaces and tabs.
+fn inline_ws<'src, E>() -> impl Parser<'src, &'src str, (), E> + Copy
+where
+    E: ParserExtra<'src, &'src str>,
+{
+    one_of(" \t").ignored().repeated()
+}
+
+/// The end of a line, including any trailing whitespace and comment.
+fn line_end<'src, E>() -> impl Parser<'src, &'src str, (), E> + Clone
+where
+    E: ParserExtra<'src, &'src str>,
+{
+    let comment = one_of("#;").then(none_of("\r\n").repeated());
+    inline_ws()
+        .then(comment.or_not())
+        .then(text::newline().or(end()))
+        .ignored()
+}
+
+/// A key, made up of ASCII letters, digits, `_`, `-` and `.`.
+fn key<'src, E>() -> impl Parser<'src, &'src str, &'src str, E> + Copy
+where
+    E: ParserExtra<'src, &'src str>,
+{
+    any()
+        .filter(|c: &char| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+        .repeated()
+        .at_least(1)
+        .to_slice()
+}
+
+/// A value: quoted with `"` or `'`, or unquoted.
+///
+/// The output type of this parser is the text of the value, which is borrowed from the input unless it contained
+/// escape sequences.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, formats::keyvalue};
+/// # use std::borrow::Cow;
+/// let value = keyvalue::value::<extra::Err<Simple<char>>>();
+///
+/// assert_eq!(value.parse("plain text").into_result(), Ok(Cow::Borrowed("plain text")));
+/// assert_eq!(value.parse(r"'C:\Users'").into_result(), Ok(Cow::Borrowed(r"C:\Users")));
+/// assert_eq!(value.parse(r#""tab\tseparated""#).into_result(), Ok(Cow::Owned("tab\tseparated".to_string())));
+/// assert!(value.parse(r#""unterminated"#).has_errors());
+/// ```
+pub fn value<'src, E>() -> impl Parser<'src, &'src str, Cow<'src, str>, E> + Clone
+where
+    E: ParserExtra<'src, &'src str>,
+{
+    let escape = choice((
+        one_of("\"\\"),
+        just('n').to('\n'),
+        just('r').to('\r'),
+        just('t').to('\t'),
+    ));
+    let double_quoted =
+        text::escaped(none_of("\"\\\r\n"), '\\', escape).delimited_by(just('"'), just('"'));
+
+    let single_quoted = none_of("'\r\n")
+        .repeated()
+        .to_slice()
+        .map(Cow::Borrowed)
+        .delimited_by(just('\''), just('\''));
+
+    let unquoted = none_of("\"'#;\r\n")
+        .then(none_of("#;\r\n").repeated())
+        .to_slice()
+        .or_not()
+        .map(|text: Option<&str>| Cow::Borrowed(text.unwrap_or("").trim_end()));
+
+    double_quoted.or(single_quoted).or(unquoted)
+}
+
+/// A parser that accepts a single `key = value` entry, with no line ending.
+///
+/// This is useful on its own for command-line tools that accept settings like `--set key=value`.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, formats::keyvalue};
+/// let entry = keyvalue::entry::<extra::Err<Simple<char>>>();
+///
+/// assert_eq!(entry.parse("log.level=debug").into_result(), Ok(("log.level", "debug".into())));
+/// assert_eq!(entry.parse("name: \"Zaphod\"").into_result(), Ok(("name", "Zaphod".into())));
+/// assert!(entry.parse("=oops").has_errors());
+/// ```
+pub fn entry<'src, E>() -> impl Parser<'src, &'src str, (&'src str, Cow<'src, str>), E> + Clone
+where
+    E: ParserExtra<'src, &'src str>,
+{
+    key()
+        .then_ignore(one_of("=:").padded_by(inline_ws()))
+        .then(value())
+}
+
+/// A single line, recovering from errors by skipping to the end of the line.
+///
+/// Lines that could not be parsed produce `None`.
+fn line<'src, E>() -> impl Parser<'src, &'src str, Option<Line<'src>>, E> + Clone
+where
+    E: ParserExtra<'src, &'src str>,
+{
+    let header = none_of("]\r\n")
+        .repeated()
+        .to_slice()
+        .map_with(|name: &str, e| {
+            // Exclude surrounding whitespace from both the name and its span
+            let span: SimpleSpan = e.span();
+            let start = span.start + (name.len() - name.trim_start().len());
+            (
+                name.trim(),
+                SimpleSpan::from(start..start + name.trim().len()),
+            )
+        })
+        .delimited_by(just('['), just(']'))
+        .map(Line::Header);
+    let entry = key()
+        .map_with(|key, e| (key, e.span()))
+        .then_ignore(one_of("=:").padded_by(inline_ws()))
+        .then(value())
+        .map(|(key, value)| Line::Entry(key, value));
+
+    inline_ws()
+        .ignore_then(header.or(entry).or_not())
+        .then_ignore(line_end())
+        .map(|line| Some(line.unwrap_or(Line::Blank)))
+        .recover_with(via_parser(
+            none_of("\r\n")
+                .repeated()
+                .then(text::newline().or(end()))
+                .to(None),
+        ))
+}
+
+/// Push the entries of a section into a container, applying the given policy for duplicate keys.
+fn apply_policy<'src, C>(
+    entries: Vec<(Spanned<&'src str>, Cow<'src, str>)>,
+    duplicates: DuplicateKeys,
+    mut emit: impl FnMut(SimpleSpan),
+) -> C
+where
+    C: Container<(&'src str, Cow<'src, str>)>,
+{
+    let mut container = C::with_capacity(entries.len());
+    for (i, ((key, span), value)) in entries.iter().enumerate() {
+        let seen_before = entries[..i].iter().any(|((other, _), _)| other == key);
+        let keep = match duplicates {
+            DuplicateKeys::Keep => true,
+            DuplicateKeys::First => !seen_before,
+            DuplicateKeys::Last => !entries[i + 1..].iter().any(|((other, _), _)| other == key),
+            DuplicateKeys::Error => {
+                if seen_before {
+                    emit(*span);
+                }
+                !seen_before
+            }
+        };
+        if keep {
+            container.push((*key, value.clone()));
+        }
+    }
+    container
+}
+
+/// A parser that accepts a whole configuration file, producing its sections in order.
+///
+/// The entries of each section are collected into `C`, after applying the given policy for duplicate keys. Sections
+/// with the same name are not merged. The unnamed section, containing the entries before the first header, is only
+/// produced if it has at least one entry.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, formats::keyvalue::{self, DuplicateKeys}};
+/// let config = keyvalue::document::<Vec<_>, extra::Err<Simple<char>>>(DuplicateKeys::Last);
+///
+/// let (sections, errors) = config
+///     .parse("mode = fast\nmode = slow\n[paths]\n!bad line\nhome = '/home/arthur'")
+///     .into_output_errors();
+/// let sections = sections.unwrap();
+///
+/// assert_eq!(sections[0].name, None);
+/// assert_eq!(sections[0].entries, [("mode", "slow".into())]);
+/// assert_eq!(sections[1].entries, [("home", "/home/arthur".into())]);
+/// // The malformed line is skipped
+/// assert_eq!(errors.len(), 1);
+/// ```
+pub fn document<'src, C, E>(
+    duplicates: DuplicateKeys,
+) -> impl Parser<'src, &'src str, Vec<Section<'src, C>>, E> + Clone
+where
+    C: Container<(&'src str, Cow<'src, str>)>,
+    E: ParserExtra<'src, &'src str>,
+{
+    end()
+        .not()
+        .ignore_then(line())
+        .repeated()
+        .collect::<Vec<_>>()
+        .validate(move |lines, _, emitter| {
+            let mut groups = Vec::new();
+            let mut name = None;
+            let mut entries = Vec::new();
+            for line in lines.into_iter().flatten() {
+                match line {
+                    Line::Blank => {}
+                    Line::Header(header) => {
+                        if name.is_some() || !entries.is_empty() {
+                            groups.push((name, core::mem::take(&mut entries)));
+                        }
+                        name = Some(header);
+                    }
+                    Line::Entry(key, value) => entries.push((key, value)),
+                }
+            }
+            if name.is_some() || !entries.is_empty() {
+                groups.push((name, entries));
+            }
+
+            groups
+                .into_iter()
+                .map(|(name, entries)| Section {
+                    name,
+                    entries: apply_policy(entries, duplicates, |span| {
+                        emitter.emit(E::Error::expected_found(None, None, span))
+                    }),
+                })
+                .collect()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::collections::BTreeMap;
+
+    fn parse<C: Container<(&'static str, Cow<'static, str>)>>(
+        duplicates: DuplicateKeys,
+        src: &'static str,
+    ) -> (Option<Vec<Section<'static, C>>>, Vec<Simple<'static, char>>) {
+        document::<C, extra::Err<Simple<char>>>(duplicates)
+            .parse(src)
+            .into_output_errors()
+    }
+
+    #[test]
+    fn duplicate_key_policies() {
+        let src = "a = 1\nb = 2\na = 3\n[s]\na = 4";
+        let entries = |duplicates| {
+            let (sections, errors) = parse::<Vec<_>>(duplicates, src);
+            let sections = sections.unwrap();
+            assert_eq!(sections[1].entries, [("a", "4".into())]);
+            (sections[0].entries.clone(), errors.len())
+        };
+
+        assert_eq!(
+            entries(DuplicateKeys::Keep),
+            (
+                vec![("a", "1".into()), ("b", "2".into()), ("a", "3".into())],
+                0
+            ),
+        );
+        assert_eq!(
+            entries(DuplicateKeys::First),
+            (vec![("a", "1".into()), ("b", "2".into())], 0),
+        );
+        assert_eq!(
+            entries(DuplicateKeys::Last),
+            (vec![("b", "2".into()), ("a", "3".into())], 0),
+        );
+        assert_eq!(
+            entries(DuplicateKeys::Error),
+            (vec![("a", "1".into()), ("b", "2".into())], 1),
+        );
+
+        let (_, errors) = parse::<Vec<_>>(DuplicateKeys::Error, src);
+        assert_eq!(errors[0].span(), &(12..13).into());
+    }
+
+    #[test]
+    fn comments_and_whitespace() {
+        let (sections, errors) = parse::<BTreeMap<_, _>>(
+            DuplicateKeys::Keep,
+            "; leading\r\n\n  [ main ]  # header\r\n\tempty =\nquoted = ' x ; y '  ; trailing\n",
+        );
+        assert!(errors.is_empty(), "{errors:?}");
+        let sections = sections.unwrap();
+
+        assert_eq!(sections.len(), 1);
+        assert_eq!(sections[0].name, Some(("main", (16..20).into())));
+        assert_eq!(sections[0].entries["empty"], "");
+        assert_eq!(sections[0].entries["quoted"], " x ; y ");
+    }
+
+    #[test]
+    fn recovery() {
+        let (sections, errors) = parse::<Vec<_>>(
+            DuplicateKeys::Keep,
+            "a = \"open\n[broken\nb = 'ok'\n= nothing\nc = 3",
+        );
+        let sections = sections.unwrap();
+
+        assert_eq!(errors.len(), 3);
+        assert_eq!(sections.len(), 1);
+        assert_eq!(sections[0].entries, [("b", "ok".into()), ("c", "3".into())]);
+    }
+
+    #[test]
+    fn empty_input() {
+        let (sections, errors) = parse::<Vec<_>>(DuplicateKeys::Keep, "");
+        assert_eq!(sections, Some(Vec::new()));
+        assert!(errors.is_empty());
+    }
+}
@@ -0,0 +1,196 @@
+use crate::{extra::ParserExtra, prelude::*};
+use alloc::vec::Vec;
+
+/// A version number, following [Semantic Versioning 2.0.0](https://semver.org).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Version<'src> {
+    /// The major version.
+    pub major: u64,
+    /// The minor version.
+    pub minor: u64,
+    /// The patch version.
+    pub patch: u64,
+    /// The dot-separated identifiers of the pre-release version, like `alpha.7`, if any.
+    pub pre: Vec<Prerelease<'src>>,
+    /// The dot-separated identifiers of the build metadata, like `20130313144700`, if any.
+    pub build: Vec<&'src str>,
+}
+
+/// An identifier of a pre-release version.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Prerelease<'src> {
+    /// An identifier made up only of digits, like `7`.
+    Numeric(u64),
+    /// An identifier containing letters or hyphens, like `alpha`.
+    Alphanumeric(&'src str),
+}
+
+/// The spans of the components of a [`Version`]. See [`semver_spanned`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VersionSpans {
+    /// The span of the major version.
+    pub major: SimpleSpan,
+    /// The span of the minor version.
+    pub minor: SimpleSpan,
+    /// The span of the patch version.
+    pub patch: SimpleSpan,
+    /// The span of the pre-release version (not including the `-`), if any.
+    pub pre: Option<SimpleSpan>,
+    /// The span of the build metadata (not including the `+`), if any.
+    pub build: Option<SimpleSpan>,
+}
+
+/// A numeric component: `0` or digits without leading zeros, which must fit in a [`u64`].
+fn number<'src, E>() -> impl Parser<'src, &'src str, (u64, SimpleSpan), E> + Copy
+where
+    E: ParserExtra<'src, &'src str>,
+{
+    text::int(10).try_map(|digits: &str, span| match digits.parse() {
+        Ok(n) => Ok((n, span)),
+        Err(_) => Err(E::Error::expected_found(None, None, span)),
+    })
+}
+
+/// A parser that accepts a semantic version like `1.0.0-alpha.7+build.5`, producing the spans of each of its
+/// components along with it.
+///
+/// Numeric components may not have leading zeros (with the exception of build metadata, which is not interpreted),
+/// and must fit in a [`u64`]. Errors point at the offending component.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, formats};
+/// let semver = formats::semver_spanned::<extra::Err<Rich<char>>>();
+///
+/// let (version, spans) = semver.parse("42.0.1-rc.1+exp.sha.5114f85").into_result().unwrap();
+/// assert_eq!((version.major, version.minor, version.patch), (42, 0, 1));
+/// assert_eq!(spans.minor, (3..4).into());
+/// assert_eq!(spans.pre, Some((7..11).into()));
+/// assert_eq!(spans.build, Some((12..27).into()));
+///
+/// // Leading zeros are not permitted
+/// let errs = semver.parse("1.02.0").into_result().unwrap_err();
+/// assert_eq!(errs[0].span(), &(2..4).into());
+/// ```
+pub fn semver_spanned<'src, E>(
+) -> impl Parser<'src, &'src str, (Version<'src>, VersionSpans), E> + Clone
+where
+    E: ParserExtra<'src, &'src str>,
+{
+    let ident_char = any().filter(|c: &char| c.is_ascii_alphanumeric() || *c == '-');
+
+    // A pre-release identifier is numeric if it's entirely digits, in which case leading zeros are not permitted
+    let alphanumeric = any()
+        .filter(char::is_ascii_digit)
+        .repeated()
+        .then(any().filter(|c: &char| c.is_ascii_alphabetic() || *c == '-'))
+        .then(ident_char.repeated())
+        .to_slice()
+        .map(Prerelease::Alphanumeric);
+    let numeric = number().map(|(n, _)| Prerelease::Numeric(n));
+    let pre = alphanumeric
+        .or(numeric)
+        .separated_by(just('.'))
+        .at_least(1)
+        .collect::<Vec<_>>()
+        .map_with(|pre, e| (pre, e.span()));
+
+    let build = ident_char
+        .repeated()
+        .at_least(1)
+        .to_slice()
+        .separated_by(just('.'))
+        .at_least(1)
+        .collect::<Vec<_>>()
+        .map_with(|build, e| (build, e.span()));
+
+    number()
+        .then_ignore(just('.'))
+        .then(number())
+        .then_ignore(just('.'))
+        .then(number())
+        .then(just('-').ignore_then(pre).or_not())
+        .then(just('+').ignore_then(build).or_not())
+        .map(|((((major, minor), patch), pre), build)| {
+            let (pre, pre_span) = pre.map_or((Vec::new(), None), |(pre, span)| (pre, Some(span)));
+            let (build, build_span) =
+                build.map_or((Vec::new(), None), |(build, span)| (build, Some(span)));
+            (
+                Version {
+                    major: major.0,
+                    minor: minor.0,
+                    patch: patch.0,
+                    pre,
+                    build,
+                },
+                VersionSpans {
+                    major: major.1,
+                    minor: minor.1,
+                    patch: patch.1,
+                    pre: pre_span,
+                    build: build_span,
+                },
+            )
+        })
+}
+
+/// A parser that accepts a semantic version like `1.0.0-alpha.7+build.5`.
+///
+/// See [`semver_spanned`] for details of the accepted syntax.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, formats::{self, Prerelease}};
+/// let semver = formats::semver::<extra::Err<Simple<char>>>();
+///
+/// let version = semver.parse("1.0.0-alpha.7").into_result().unwrap();
+/// assert_eq!(version.pre, [Prerelease::Alphanumeric("alpha"), Prerelease::Numeric(7)]);
+/// assert!(version.build.is_empty());
+///
+/// assert!(semver.parse("1.0").has_errors());
+/// assert!(semver.parse("1.0.0-").has_errors());
+/// ```
+pub fn semver<'src, E>() -> impl Parser<'src, &'src str, Version<'src>, E> + Clone
+where
+    E: ParserExtra<'src, &'src str>,
+{
+    semver_spanned().map(|(version, _)| version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn semver_rules() {
+        let semver = semver::<extra::Err<Simple<char>>>();
+        for (src, valid) in [
+            ("0.0.0", true),
+            ("1.2.3-0", true),
+            ("1.2.3-0a.--.00a", true),
+            ("1.2.3+001.-", true),
+            ("1.2.3-alpha+build", true),
+            ("01.2.3", false),
+            ("1.2.3-01", false),
+            ("1.2.3-a..b", false),
+            ("1.2.3+", false),
+            ("1.2.3+a_b", false),
+            ("v1.2.3", false),
+            ("1.2.3.4", false),
+            ("18446744073709551616.0.0", false),
+        ] {
+            assert_eq!(semver.parse(src).into_result().is_ok(), valid, "{src}");
+        }
+
+        assert_eq!(
+            semver.parse("1.2.3-0a.-.9").into_result().map(|v| v.pre),
+            Ok(vec![
+                Prerelease::Alphanumeric("0a"),
+                Prerelease::Alphanumeric("-"),
+                Prerelease::Numeric(9),
+            ])
+        );
+    }
+}
@@ -10,6 +10,12 @@ use inspector::Inspector;
 pub use crate::stream::{BoxedExactSizeStream, BoxedStream, IterInput, Stream};
 
 use super::*;
+use crate::span::{LineCol, LineColSpan};
+#[cfg(feature = "memoization")]
+use core::{
+    any::{Any, TypeId},
+    hash::Hasher,
+};
 #[cfg(feature = "std")]
 use std::io::{BufReader, Read, Seek};
 
@@ -23,8 +29,11 @@ use std::io::{BufReader, Read, Seek};
 ///
 /// Some common input types, and which traits they implement are:
 /// - `&str`: [`SliceInput`], [`StrInput`], [`ValueInput`], [`ExactSizeInput`]
-/// - `&[T]`: [`SliceInput`], [`ValueInput`], [`BorrowInput`], [`ExactSizeInput`]
+/// - `&[T]`, `&[T; N]` and `&Vec<T>`: [`SliceInput`], [`ValueInput`] if `T: Clone`, [`BorrowInput`], [`ExactSizeInput`]
 /// - `Stream<I>`: [`ValueInput`], [`ExactSizeInput`] if `I: ExactSizeIterator`
+/// - `&MmapInput`: [`SliceInput`], [`StrInput`], [`ValueInput`], [`BorrowInput`], [`ExactSizeInput`] (requires the `mmap` feature)
+/// - `BitInput`: [`ValueInput`], [`ExactSizeInput`]
+/// - `&ReadInput<R>`: [`ValueInput`] (requires the `std` feature)
 pub trait Input<'src>: 'src {
     /// The type of a span on this input.
     ///
@@ -73,34 +82,90 @@ pub trait Input<'src>: 'src {
     /// must not be shared between multiple inputs.
     unsafe fn span(cache: &mut Self::Cache, range: Range<&Self::Cursor>) -> Self::Span;
 
-    // /// Split an input that produces tokens of type `(T, S)` into one that produces tokens of type `T` and spans of
-    // /// type `S`.
-    // ///
-    // /// This is commonly required for lexers that generate token-span tuples. For example, `logos`'
-    // /// [`SpannedIter`](https://docs.rs/logos/0.12.0/logos/struct.Lexer.html#method.spanned) lexer generates such
-    // /// pairs.
-    // ///
-    // /// Also required is an 'End Of Input' (EoI) span. This span is arbitrary, but is used by the input to produce
-    // /// sensible spans that extend to the end of the input or are zero-width. Most implementations simply use some
-    // /// equivalent of `len..len` (i.e: a span where both the start and end cursors are set to the end of the input).
-    // /// However, what you choose for this span is up to you: but consider that the context, start, and end of the span
-    // /// will be recombined to create new spans as required by the parser.
-    // ///
-    // /// Although `Spanned` does implement [`BorrowInput`], please be aware that, as you might anticipate, the slices
-    // /// will be those of the original input (usually `&[(T, S)]`) and not `&[T]` so as to avoid the need to copy
-    // /// around sections of the input.
-    // fn spanned<T, S>(self, eoi: S) -> SpannedInput<T, S, Self>
-    // where
-    //     Self: Input<'src, Token = (T, S)> + Sized,
-    //     T: 'src,
-    //     S: Span + Clone + 'src,
-    // {
-    //     SpannedInput {
-    //         input: self,
-    //         eoi,
-    //         phantom: PhantomData,
-    //     }
-    // }
+    /// Attempt to match an entire sequence of tokens against the input at once, advancing the cursor past it on
+    /// success.
+    ///
+    /// Inputs that are backed by contiguous memory can implement this to compare slices directly rather than pulling
+    /// tokens one by one. Returning `false` is always valid: it causes the caller to fall back to token-by-token
+    /// matching, which is also used to generate errors.
+    ///
+    /// # Safety
+    ///
+    /// As with [`Input::next_maybe`], the cursor passed to this function must be generated by [`Input::begin`] and
+    /// must not be shared between multiple inputs.
+    #[doc(hidden)]
+    #[inline(always)]
+    unsafe fn match_seq<'p, S>(
+        _cache: &mut Self::Cache,
+        _cursor: &mut Self::Cursor,
+        _seq: &S,
+    ) -> bool
+    where
+        Self::Token: PartialEq,
+        S: OrderedSeq<'p, Self::Token> + ?Sized,
+    {
+        false
+    }
+
+    /// Split an input that produces tokens of type `(T, S)` into one that produces tokens of type `T` and spans of
+    /// type `S`.
+    ///
+    /// This is commonly required for lexers that generate token-span tuples. For example, `logos`'
+    /// [`SpannedIter`](https://docs.rs/logos/0.12.0/logos/struct.Lexer.html#method.spanned) lexer generates such
+    /// pairs.
+    ///
+    /// Also required is an 'End Of Input' (EoI) span. This is the span reported by errors that occur at the end of
+    /// the input (such as "found end of input"), and is used by the input to produce sensible spans that extend to the
+    /// end of the input or are zero-width. The usual choice is a zero-width span at the end of the source text, but
+    /// you might prefer the span of the final token (so that such errors point at the last thing the user wrote), or a
+    /// synthetic span that your diagnostics renderer recognises as the end of the file. Consider that the context,
+    /// start, and end of the span will be recombined to create new spans as required by the parser.
+    ///
+    /// Although `SpannedInput` does implement [`SliceInput`], please be aware that, as you might anticipate, the
+    /// slices will be those of the original input (usually `&[(T, S)]`) and not `&[T]` so as to avoid the need to copy
+    /// around sections of the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// #[derive(Clone, Debug, PartialEq)]
+    /// enum Token { Num(u32), Plus }
+    ///
+    /// let tokens = [
+    ///     (Token::Num(1), SimpleSpan::new(0, 1)),
+    ///     (Token::Plus, SimpleSpan::new(2, 3)),
+    /// ];
+    ///
+    /// let num = select! { Token::Num(x) => x };
+    /// let sum = num
+    ///     .foldl(
+    ///         just::<_, _, extra::Err<Rich<_>>>(Token::Plus).ignore_then(num).repeated(),
+    ///         |a, b| a + b,
+    ///     )
+    ///     .then_end();
+    ///
+    /// // Point end-of-input errors at the last token, rather than at a zero-width span after it
+    /// let eoi = tokens.last().map(|(_, span)| *span).unwrap();
+    /// let errs = sum
+    ///     .parse(tokens.as_slice().spanned(eoi))
+    ///     .into_errors();
+    ///
+    /// assert_eq!(errs[0].found(), None);
+    /// assert_eq!(errs[0].span(), &SimpleSpan::new(2, 3));
+    /// ```
+    fn spanned<T, S>(self, eoi: S) -> SpannedInput<T, S, Self>
+    where
+        Self: Input<'src, Token = (T, S)> + Sized,
+        T: 'src,
+        S: Span + Clone + 'src,
+    {
+        SpannedInput {
+            input: self,
+            eoi,
+            phantom: EmptyPhantom::new(),
+        }
+    }
 
     /// Add extra context within spans generated by this input.
     ///
@@ -152,6 +217,40 @@ pub trait Input<'src>: 'src {
         }
     }
 
+    /// Track line and column information for this input, producing [`LineColSpan`]s.
+    ///
+    /// Line starts are recorded as the parser advances through the input, so producing a span only requires a short
+    /// scan from the start of its line rather than from the start of the input. Only `\n` is treated as a line
+    /// terminator, so `\r\n` line endings are counted once. By default, tabs count as a single column: see
+    /// [`LineColInput::with_tab_width`] to change this.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::{prelude::*, span::{LineCol, LineColSpan}};
+    /// let word = text::ascii::ident::<_, extra::Err<Rich<char, LineColSpan>>>()
+    ///     .map_with(|_, e| e.span())
+    ///     .padded();
+    ///
+    /// let spans = word.repeated().collect::<Vec<_>>()
+    ///     .parse("foo\r\n\tbar".line_col().with_tab_width(4))
+    ///     .into_result()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(spans[1].start, LineCol { offset: 6, line: 2, col: 5 });
+    /// assert_eq!(spans[1].end, LineCol { offset: 9, line: 2, col: 8 });
+    /// ```
+    fn line_col(self) -> LineColInput<Self>
+    where
+        Self: Input<'src, Cursor = usize> + Sized,
+        Self::Token: Char,
+    {
+        LineColInput {
+            input: self,
+            tab_width: 1,
+        }
+    }
+
     /// Map the spans output for this input to a different output span.
     ///
     /// This is useful if you wish to include extra context that applies to all spans emitted during a parse, such as
@@ -213,6 +312,23 @@ pub trait StrInput<'src>: Sealed + ValueInput<'src, Cursor = usize> + SliceInput
 where
     Self::Token: Char,
 {
+    /// Get the cursor at the start of the line containing the given cursor: just after the last token before it for
+    /// which [`Char::is_newline`] holds, or the start of the input if there is no such token.
+    ///
+    /// # Safety
+    ///
+    /// As with functions on [`Input`], the cursor provided must be generated by this input.
+    #[doc(hidden)]
+    unsafe fn line_start(cache: &mut Self::Cache, cursor: usize) -> usize;
+}
+
+/// Find the start of the line containing the byte at `cursor`, for inputs made of bytes.
+#[inline]
+fn byte_line_start(bytes: &[u8], cursor: usize) -> usize {
+    bytes[..cursor]
+        .iter()
+        .rposition(|b| b.is_newline())
+        .map_or(0, |i| i + 1)
 }
 
 /// Implemented by inputs that can have tokens borrowed from them.
@@ -281,6 +397,22 @@ impl<'src> Input<'src> for &'src str {
     unsafe fn span(_this: &mut Self::Cache, range: Range<&Self::Cursor>) -> Self::Span {
         (*range.start..*range.end).into()
     }
+
+    #[inline(always)]
+    unsafe fn match_seq<'p, S>(this: &mut Self::Cache, cursor: &mut Self::Cursor, seq: &S) -> bool
+    where
+        Self::Token: PartialEq,
+        S: OrderedSeq<'p, Self::Token> + ?Sized,
+    {
+        match seq.seq_as_str() {
+            // SAFETY: We only ever return cursors that are at a character boundary
+            Some(s) if this.get_unchecked(*cursor..).starts_with(s) => {
+                *cursor += s.len();
+                true
+            }
+            _ => false,
+        }
+    }
 }
 
 impl<'src> ExactSizeInput<'src> for &'src str {
@@ -298,7 +430,16 @@ impl<'src> ValueInput<'src> for &'src str {
 }
 
 impl Sealed for &str {}
-impl<'src> StrInput<'src> for &'src str {}
+impl<'src> StrInput<'src> for &'src str {
+    #[inline]
+    unsafe fn line_start(cache: &mut Self::Cache, cursor: usize) -> usize {
+        cache[..cursor]
+            .char_indices()
+            .rev()
+            .find(|(_, c)| c.is_newline())
+            .map_or(0, |(i, c)| i + c.len_utf8())
+    }
+}
 
 impl<'src> SliceInput<'src> for &'src str {
     type Slice = &'src str;
@@ -355,6 +496,35 @@ impl<'src, T> Input<'src> for &'src [T] {
     unsafe fn span(_this: &mut Self::Cache, range: Range<&Self::Cursor>) -> Self::Span {
         (*range.start..*range.end).into()
     }
+
+    #[inline(always)]
+    unsafe fn match_seq<'p, S>(this: &mut Self::Cache, cursor: &mut Self::Cursor, seq: &S) -> bool
+    where
+        Self::Token: PartialEq,
+        S: OrderedSeq<'p, Self::Token> + ?Sized,
+    {
+        match_slice_seq(this, cursor, seq)
+    }
+}
+
+/// [`Input::match_seq`] for inputs that are backed by a slice of tokens, indexed by cursor.
+#[inline(always)]
+fn match_slice_seq<'p, T, S>(tokens: &[T], cursor: &mut usize, seq: &S) -> bool
+where
+    T: PartialEq,
+    S: OrderedSeq<'p, T> + ?Sized,
+{
+    match seq.seq_as_slice() {
+        Some(s)
+            if tokens
+                .get(*cursor..)
+                .map_or(false, |rest| rest.starts_with(s)) =>
+        {
+            *cursor += s.len();
+            true
+        }
+        _ => false,
+    }
 }
 
 impl<'src, T> ExactSizeInput<'src> for &'src [T] {
@@ -365,7 +535,12 @@ impl<'src, T> ExactSizeInput<'src> for &'src [T] {
 }
 
 impl Sealed for &[u8] {}
-impl<'src> StrInput<'src> for &'src [u8] {}
+impl<'src> StrInput<'src> for &'src [u8] {
+    #[inline]
+    unsafe fn line_start(cache: &mut Self::Cache, cursor: usize) -> usize {
+        byte_line_start(cache, cursor)
+    }
+}
 
 impl<'src, T> SliceInput<'src> for &'src [T] {
     type Slice = &'src [T];
@@ -449,7 +624,12 @@ impl<'src, T: 'src, const N: usize> ExactSizeInput<'src> for &'src [T; N] {
 }
 
 impl<const N: usize> Sealed for &[u8; N] {}
-impl<'src, const N: usize> StrInput<'src> for &'src [u8; N] {}
+impl<'src, const N: usize> StrInput<'src> for &'src [u8; N] {
+    #[inline]
+    unsafe fn line_start(cache: &mut Self::Cache, cursor: usize) -> usize {
+        byte_line_start(*cache, cursor)
+    }
+}
 
 impl<'src, T: 'src, const N: usize> SliceInput<'src> for &'src [T; N] {
     type Slice = &'src [T];
@@ -487,6 +667,99 @@ impl<'src, T: 'src, const N: usize> BorrowInput<'src> for &'src [T; N] {
     }
 }
 
+impl<'src, T: 'src> Input<'src> for &'src Vec<T> {
+    type Cursor = usize;
+    type Span = SimpleSpan<usize>;
+
+    type Token = T;
+    type MaybeToken = &'src T;
+
+    type Cache = &'src [T];
+
+    #[inline]
+    fn begin(self) -> (Self::Cursor, Self::Cache) {
+        (0, self.as_slice())
+    }
+
+    #[inline]
+    fn cursor_location(cursor: &Self::Cursor) -> usize {
+        *cursor
+    }
+
+    #[inline(always)]
+    unsafe fn next_maybe(
+        this: &mut Self::Cache,
+        cursor: &mut Self::Cursor,
+    ) -> Option<Self::MaybeToken> {
+        <&[T]>::next_maybe(this, cursor)
+    }
+
+    #[inline(always)]
+    unsafe fn span(_this: &mut Self::Cache, range: Range<&Self::Cursor>) -> Self::Span {
+        (*range.start..*range.end).into()
+    }
+
+    #[inline(always)]
+    unsafe fn match_seq<'p, S>(this: &mut Self::Cache, cursor: &mut Self::Cursor, seq: &S) -> bool
+    where
+        Self::Token: PartialEq,
+        S: OrderedSeq<'p, Self::Token> + ?Sized,
+    {
+        match_slice_seq(this, cursor, seq)
+    }
+}
+
+impl<'src, T: 'src> ExactSizeInput<'src> for &'src Vec<T> {
+    #[inline(always)]
+    unsafe fn span_from(this: &mut Self::Cache, range: RangeFrom<&Self::Cursor>) -> Self::Span {
+        (*range.start..this.len()).into()
+    }
+}
+
+impl Sealed for &Vec<u8> {}
+impl<'src> StrInput<'src> for &'src Vec<u8> {
+    #[inline]
+    unsafe fn line_start(cache: &mut Self::Cache, cursor: usize) -> usize {
+        byte_line_start(cache, cursor)
+    }
+}
+
+impl<'src, T: 'src> SliceInput<'src> for &'src Vec<T> {
+    type Slice = &'src [T];
+
+    #[inline(always)]
+    fn full_slice(this: &mut Self::Cache) -> Self::Slice {
+        *this
+    }
+
+    #[inline(always)]
+    unsafe fn slice(this: &mut Self::Cache, range: Range<&Self::Cursor>) -> Self::Slice {
+        &this[*range.start..*range.end]
+    }
+
+    #[inline(always)]
+    unsafe fn slice_from(this: &mut Self::Cache, from: RangeFrom<&Self::Cursor>) -> Self::Slice {
+        &this[*from.start..]
+    }
+}
+
+impl<'src, T: Clone + 'src> ValueInput<'src> for &'src Vec<T> {
+    #[inline(always)]
+    unsafe fn next(this: &mut Self::Cache, cursor: &mut Self::Cursor) -> Option<Self::Token> {
+        Self::next_maybe(this, cursor).cloned()
+    }
+}
+
+impl<'src, T: 'src> BorrowInput<'src> for &'src Vec<T> {
+    #[inline(always)]
+    unsafe fn next_ref(
+        this: &mut Self::Cache,
+        cursor: &mut Self::Cursor,
+    ) -> Option<&'src Self::Token> {
+        Self::next_maybe(this, cursor)
+    }
+}
+
 /// See [`Input::map`].
 #[derive(Copy, Clone)]
 pub struct MappedInput<T, S, I, F> {
@@ -664,6 +937,139 @@ where
     }
 }
 
+/// See [`Input::spanned`].
+#[derive(Copy, Clone)]
+pub struct SpannedInput<T, S, I> {
+    input: I,
+    eoi: S,
+    #[allow(dead_code)]
+    phantom: EmptyPhantom<T>,
+}
+
+impl<'src, T, S, I> Input<'src> for SpannedInput<T, S, I>
+where
+    I: Input<'src, Token = (T, S)>,
+    T: 'src,
+    S: Span + Clone + 'src,
+{
+    type Cursor = (I::Cursor, Option<S::Offset>);
+    type Span = S;
+
+    type Token = T;
+    type MaybeToken = <I::MaybeToken as IntoMaybe<'src, I::Token>>::Proj<T>;
+
+    type Cache = (I::Cache, S);
+
+    #[inline]
+    fn begin(self) -> (Self::Cursor, Self::Cache) {
+        let (cursor, cache) = self.input.begin();
+        ((cursor, None), (cache, self.eoi))
+    }
+
+    #[inline]
+    fn cursor_location(cursor: &Self::Cursor) -> usize {
+        I::cursor_location(&cursor.0)
+    }
+
+    #[inline(always)]
+    unsafe fn next_maybe(
+        (cache, _): &mut Self::Cache,
+        cursor: &mut Self::Cursor,
+    ) -> Option<Self::MaybeToken> {
+        I::next_maybe(cache, &mut cursor.0).map(|tok| {
+            cursor.1 = Some(tok.borrow().1.end());
+            tok.map_maybe(|(tok, _)| tok, |(tok, _)| tok)
+        })
+    }
+
+    #[inline]
+    unsafe fn span((cache, eoi): &mut Self::Cache, range: Range<&Self::Cursor>) -> Self::Span {
+        let start = I::next_maybe(cache, &mut range.start.0.clone())
+            .map(|tok| tok.borrow().1.start())
+            .unwrap_or_else(|| eoi.start());
+        let end = range.end.1.clone().unwrap_or_else(|| eoi.end());
+        S::new(eoi.context(), start..end)
+    }
+}
+
+impl<'src, T, S, I> ExactSizeInput<'src> for SpannedInput<T, S, I>
+where
+    I: ExactSizeInput<'src, Token = (T, S)>,
+    T: 'src,
+    S: Span + Clone + 'src,
+{
+    #[inline(always)]
+    unsafe fn span_from(
+        (cache, eoi): &mut Self::Cache,
+        range: RangeFrom<&Self::Cursor>,
+    ) -> Self::Span {
+        let start = I::next_maybe(cache, &mut range.start.0.clone())
+            .map(|tok| tok.borrow().1.start())
+            .unwrap_or_else(|| eoi.start());
+        S::new(eoi.context(), start..eoi.end())
+    }
+}
+
+impl<'src, T, S, I> ValueInput<'src> for SpannedInput<T, S, I>
+where
+    I: ValueInput<'src, Token = (T, S)>,
+    T: 'src,
+    S: Span + Clone + 'src,
+{
+    #[inline(always)]
+    unsafe fn next((cache, _): &mut Self::Cache, cursor: &mut Self::Cursor) -> Option<Self::Token> {
+        I::next(cache, &mut cursor.0).map(|(tok, span)| {
+            cursor.1 = Some(span.end());
+            tok
+        })
+    }
+}
+
+impl<'src, T, S, I> BorrowInput<'src> for SpannedInput<T, S, I>
+where
+    I: BorrowInput<'src, Token = (T, S)>,
+    T: 'src,
+    S: Span + Clone + 'src,
+{
+    #[inline(always)]
+    unsafe fn next_ref(
+        (cache, _): &mut Self::Cache,
+        cursor: &mut Self::Cursor,
+    ) -> Option<&'src Self::Token> {
+        I::next_ref(cache, &mut cursor.0).map(|(tok, span)| {
+            cursor.1 = Some(span.end());
+            tok
+        })
+    }
+}
+
+impl<'src, T, S, I> SliceInput<'src> for SpannedInput<T, S, I>
+where
+    I: SliceInput<'src, Token = (T, S)>,
+    T: 'src,
+    S: Span + Clone + 'src,
+{
+    type Slice = I::Slice;
+
+    #[inline(always)]
+    fn full_slice((cache, _): &mut Self::Cache) -> Self::Slice {
+        I::full_slice(cache)
+    }
+
+    #[inline(always)]
+    unsafe fn slice((cache, _): &mut Self::Cache, range: Range<&Self::Cursor>) -> Self::Slice {
+        I::slice(cache, &range.start.0..&range.end.0)
+    }
+
+    #[inline(always)]
+    unsafe fn slice_from(
+        (cache, _): &mut Self::Cache,
+        from: RangeFrom<&Self::Cursor>,
+    ) -> Self::Slice {
+        I::slice_from(cache, &from.start.0..)
+    }
+}
+
 /// An input wrapper that maps the span type of your input
 /// into your custom span [`Input::map_span`].
 #[derive(Copy, Clone)]
@@ -807,7 +1213,11 @@ where
     S::Offset: From<<I::Span as Span>::Offset>,
     F: Fn(I::Span) -> S,
 {
-}
+    #[inline]
+    unsafe fn line_start(cache: &mut Self::Cache, cursor: usize) -> usize {
+        I::line_start(&mut cache.0, cursor)
+    }
+}
 
 /// An input wrapper that returns a custom span, with the user-defined context
 /// contained in the Span::Context. See [`Input::with_context`].
@@ -882,114 +1292,725 @@ where
     }
 }
 
-impl<'src, S, I: ValueInput<'src>> ValueInput<'src> for WithContext<S, I>
-where
-    S: Span + Clone + 'src,
-    S::Context: Clone + 'src,
-    S::Offset: From<<I::Span as Span>::Offset>,
-{
-    #[inline(always)]
-    unsafe fn next((cache, _): &mut Self::Cache, cursor: &mut Self::Cursor) -> Option<Self::Token> {
-        I::next(cache, cursor)
+impl<'src, S, I: ValueInput<'src>> ValueInput<'src> for WithContext<S, I>
+where
+    S: Span + Clone + 'src,
+    S::Context: Clone + 'src,
+    S::Offset: From<<I::Span as Span>::Offset>,
+{
+    #[inline(always)]
+    unsafe fn next((cache, _): &mut Self::Cache, cursor: &mut Self::Cursor) -> Option<Self::Token> {
+        I::next(cache, cursor)
+    }
+}
+
+impl<'src, S, I: BorrowInput<'src>> BorrowInput<'src> for WithContext<S, I>
+where
+    S: Span + Clone + 'src,
+    S::Context: Clone + 'src,
+    S::Offset: From<<I::Span as Span>::Offset>,
+{
+    #[inline(always)]
+    unsafe fn next_ref(
+        (cache, _): &mut Self::Cache,
+        cursor: &mut Self::Cursor,
+    ) -> Option<&'src Self::Token> {
+        I::next_ref(cache, cursor)
+    }
+}
+
+impl<'src, S, I: SliceInput<'src>> SliceInput<'src> for WithContext<S, I>
+where
+    S: Span + Clone + 'src,
+    S::Context: Clone + 'src,
+    S::Offset: From<<I::Span as Span>::Offset>,
+{
+    type Slice = I::Slice;
+
+    #[inline(always)]
+    fn full_slice((cache, _): &mut Self::Cache) -> Self::Slice {
+        I::full_slice(cache)
+    }
+
+    #[inline(always)]
+    unsafe fn slice((cache, _): &mut Self::Cache, range: Range<&Self::Cursor>) -> Self::Slice {
+        I::slice(cache, range)
+    }
+
+    #[inline(always)]
+    unsafe fn slice_from(
+        (cache, _): &mut Self::Cache,
+        from: RangeFrom<&Self::Cursor>,
+    ) -> Self::Slice {
+        I::slice_from(cache, from)
+    }
+}
+
+impl<'src, S, I> Sealed for WithContext<S, I>
+where
+    I: Input<'src>,
+    S: Span + Clone + 'src,
+    S::Context: Clone + 'src,
+    S::Offset: From<<I::Span as Span>::Offset>,
+{
+}
+impl<'src, S, I> StrInput<'src> for WithContext<S, I>
+where
+    I: StrInput<'src>,
+    I::Token: Char,
+    S: Span + Clone + 'src,
+    S::Context: Clone + 'src,
+    S::Offset: From<<I::Span as Span>::Offset>,
+{
+    #[inline]
+    unsafe fn line_start(cache: &mut Self::Cache, cursor: usize) -> usize {
+        I::line_start(&mut cache.0, cursor)
+    }
+}
+
+/// An input wrapper that tracks line and column information. See [`Input::line_col`].
+#[derive(Copy, Clone)]
+pub struct LineColInput<I> {
+    input: I,
+    tab_width: usize,
+}
+
+impl<I> LineColInput<I> {
+    /// Set the number of columns between tab stops. A tab advances the column to the next tab stop.
+    ///
+    /// A tab width of `1` (the default) treats tabs like any other token.
+    pub fn with_tab_width(self, tab_width: usize) -> Self {
+        assert!(tab_width > 0, "tab width must be greater than zero");
+        Self { tab_width, ..self }
+    }
+}
+
+/// Line information collected by a [`LineColInput`] as the input is advanced.
+#[doc(hidden)]
+pub struct LineTable {
+    tab_width: usize,
+    // The cursor at which each line begins, in ascending order
+    line_starts: Vec<usize>,
+    // Every token before this offset has been seen, so any newlines before it are present in `line_starts`
+    scanned: usize,
+    // The end of the input, if it has been reached
+    eoi: Option<usize>,
+}
+
+impl LineTable {
+    #[inline(always)]
+    fn track<'src, T: Char + 'src>(&mut self, before: usize, after: usize, tok: Option<&T>) {
+        match tok {
+            Some(tok) if before >= self.scanned => {
+                self.scanned = after;
+                if tok.to_ascii() == Some(b'\n') {
+                    self.line_starts.push(after);
+                }
+            }
+            Some(_) => {}
+            None => self.eoi = Some(before),
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `offset` must be a cursor generated by the input that this table is tracking.
+    unsafe fn locate<'src, I>(&self, cache: &mut I::Cache, offset: usize) -> LineCol
+    where
+        I: Input<'src, Cursor = usize>,
+        I::Token: Char,
+    {
+        let line = self.line_starts.partition_point(|start| *start <= offset);
+        let mut cursor = self.line_starts[line.saturating_sub(1)];
+        let mut col = 1;
+        while cursor < offset {
+            match I::next_maybe(cache, &mut cursor) {
+                Some(tok) if tok.borrow().to_ascii() == Some(b'\t') => {
+                    col = (col - 1) / self.tab_width * self.tab_width + self.tab_width + 1;
+                }
+                Some(_) => col += 1,
+                None => break,
+            }
+        }
+        LineCol { offset, line, col }
+    }
+}
+
+impl<'src, I> Input<'src> for LineColInput<I>
+where
+    I: Input<'src, Cursor = usize>,
+    I::Token: Char,
+{
+    type Cursor = usize;
+    type Span = LineColSpan;
+
+    type Token = I::Token;
+    type MaybeToken = I::MaybeToken;
+
+    type Cache = (I::Cache, LineTable);
+
+    #[inline]
+    fn begin(self) -> (Self::Cursor, Self::Cache) {
+        let (cursor, cache) = self.input.begin();
+        let table = LineTable {
+            tab_width: self.tab_width,
+            line_starts: alloc::vec![cursor],
+            scanned: cursor,
+            eoi: None,
+        };
+        (cursor, (cache, table))
+    }
+
+    #[inline]
+    fn cursor_location(cursor: &Self::Cursor) -> usize {
+        I::cursor_location(cursor)
+    }
+
+    #[inline(always)]
+    unsafe fn next_maybe(
+        (cache, table): &mut Self::Cache,
+        cursor: &mut Self::Cursor,
+    ) -> Option<Self::MaybeToken> {
+        let before = *cursor;
+        let tok = I::next_maybe(cache, cursor);
+        table.track(before, *cursor, tok.as_ref().map(|tok| tok.borrow()));
+        tok
+    }
+
+    #[inline]
+    unsafe fn span((cache, table): &mut Self::Cache, range: Range<&Self::Cursor>) -> Self::Span {
+        LineColSpan {
+            start: table.locate::<I>(cache, *range.start),
+            end: table.locate::<I>(cache, *range.end),
+        }
+    }
+}
+
+impl<'src, I> ExactSizeInput<'src> for LineColInput<I>
+where
+    I: ExactSizeInput<'src, Cursor = usize>,
+    I::Token: Char,
+{
+    #[inline]
+    unsafe fn span_from(
+        (cache, table): &mut Self::Cache,
+        range: RangeFrom<&Self::Cursor>,
+    ) -> Self::Span {
+        let end = match table.eoi {
+            Some(end) => end,
+            None => {
+                let mut cursor = table.scanned;
+                loop {
+                    let before = cursor;
+                    let tok = I::next_maybe(cache, &mut cursor);
+                    table.track(before, cursor, tok.as_ref().map(|tok| tok.borrow()));
+                    if tok.is_none() {
+                        break before;
+                    }
+                }
+            }
+        };
+        LineColSpan {
+            start: table.locate::<I>(cache, *range.start),
+            end: table.locate::<I>(cache, end),
+        }
+    }
+}
+
+impl<'src, I> ValueInput<'src> for LineColInput<I>
+where
+    I: ValueInput<'src, Cursor = usize>,
+    I::Token: Char,
+{
+    #[inline(always)]
+    unsafe fn next(
+        (cache, table): &mut Self::Cache,
+        cursor: &mut Self::Cursor,
+    ) -> Option<Self::Token> {
+        let before = *cursor;
+        let tok = I::next(cache, cursor);
+        table.track(before, *cursor, tok.as_ref());
+        tok
+    }
+}
+
+impl<'src, I> BorrowInput<'src> for LineColInput<I>
+where
+    I: BorrowInput<'src, Cursor = usize>,
+    I::Token: Char,
+{
+    #[inline(always)]
+    unsafe fn next_ref(
+        (cache, table): &mut Self::Cache,
+        cursor: &mut Self::Cursor,
+    ) -> Option<&'src Self::Token> {
+        let before = *cursor;
+        let tok = I::next_ref(cache, cursor);
+        table.track(before, *cursor, tok);
+        tok
+    }
+}
+
+impl<'src, I> SliceInput<'src> for LineColInput<I>
+where
+    I: SliceInput<'src, Cursor = usize>,
+    I::Token: Char,
+{
+    type Slice = I::Slice;
+
+    #[inline(always)]
+    fn full_slice((cache, _): &mut Self::Cache) -> Self::Slice {
+        I::full_slice(cache)
+    }
+
+    #[inline(always)]
+    unsafe fn slice((cache, _): &mut Self::Cache, range: Range<&Self::Cursor>) -> Self::Slice {
+        I::slice(cache, range)
+    }
+
+    #[inline(always)]
+    unsafe fn slice_from(
+        (cache, _): &mut Self::Cache,
+        from: RangeFrom<&Self::Cursor>,
+    ) -> Self::Slice {
+        I::slice_from(cache, from)
+    }
+}
+
+impl<I> Sealed for LineColInput<I> {}
+impl<'src, I> StrInput<'src> for LineColInput<I>
+where
+    I: StrInput<'src>,
+    I::Token: Char,
+{
+    #[inline]
+    unsafe fn line_start(cache: &mut Self::Cache, cursor: usize) -> usize {
+        I::line_start(&mut cache.0, cursor)
+    }
+}
+
+/// Input type which supports seekable readers. Uses a [`BufReader`] internally to buffer input and
+/// avoid unnecessary IO calls.
+///
+/// Only available with the `std` feature
+#[cfg(feature = "std")]
+pub struct IoInput<R> {
+    reader: BufReader<R>,
+    last_cursor: usize,
+}
+
+#[cfg(feature = "std")]
+impl<R: Read + Seek> IoInput<R> {
+    /// Create a new `IoReader` from a seekable reader.
+    pub fn new(reader: R) -> IoInput<R> {
+        IoInput {
+            reader: BufReader::new(reader),
+            last_cursor: 0,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'src, R: Read + Seek + 'src> Input<'src> for IoInput<R> {
+    type Cursor = usize;
+    type Span = SimpleSpan;
+
+    type Token = u8;
+    type MaybeToken = u8;
+
+    type Cache = Self;
+
+    fn begin(self) -> (Self::Cursor, Self::Cache) {
+        (0, self)
+    }
+
+    #[inline(always)]
+    fn cursor_location(cursor: &Self::Cursor) -> usize {
+        *cursor
+    }
+
+    #[inline(always)]
+    unsafe fn next_maybe(
+        this: &mut Self::Cache,
+        cursor: &mut Self::Cursor,
+    ) -> Option<Self::MaybeToken> {
+        Self::next(this, cursor)
+    }
+
+    #[inline]
+    unsafe fn span(_this: &mut Self::Cache, range: Range<&Self::Cursor>) -> Self::Span {
+        (*range.start..*range.end).into()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'src, R: Read + Seek + 'src> ValueInput<'src> for IoInput<R> {
+    unsafe fn next(this: &mut Self::Cache, cursor: &mut Self::Cursor) -> Option<Self::Token> {
+        if *cursor != this.last_cursor {
+            let seek = *cursor as i64 - this.last_cursor as i64;
+
+            this.reader.seek_relative(seek).unwrap();
+
+            this.last_cursor = *cursor;
+        }
+
+        let mut out = 0;
+
+        let r = this.reader.read_exact(std::slice::from_mut(&mut out));
+
+        match r {
+            Ok(()) => {
+                this.last_cursor += 1;
+                *cursor += 1;
+                Some(out)
+            }
+            Err(_) => None,
+        }
+    }
+}
+
+/// Input type which reads bytes on demand from any [`Read`]er, such as a file or a pipe, without requiring it to be
+/// seekable.
+///
+/// Bytes that have been read are retained so that the parser can backtrack, but only within a window (see
+/// [`ReadInput::with_window`]) behind the furthest position read so far. If a parser tries to backtrack further than
+/// this, or the reader produces an error, the input behaves as if it has ended and the [`ReadError`] can be retrieved
+/// after parsing with [`ReadInput::take_error`]. Parsers are given a reference to the input, i.e:
+/// `parser.parse(&read_input)`.
+///
+/// Only available with the `std` feature
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, input::{ReadInput, ReadError}};
+/// let input = ReadInput::new(&b"hello world"[..]);
+/// let words = any::<_, extra::Err<Simple<u8>>>()
+///     .filter(u8::is_ascii_alphabetic)
+///     .repeated()
+///     .at_least(1)
+///     .separated_by(just(b' '))
+///     .count();
+/// assert_eq!(words.parse(&input).into_result(), Ok(2));
+/// assert!(input.take_error().is_none());
+///
+/// // Backtracking further than the window is an error
+/// let input = ReadInput::new(&b"abce"[..]).with_window(2);
+/// let parser = just::<_, _, extra::Err<Simple<u8>>>(b"abcd").or(just(b"abce"));
+/// assert!(parser.parse(&input).has_errors());
+/// assert!(matches!(input.take_error(), Some(ReadError::WindowExceeded { pos: 0, .. })));
+/// ```
+#[cfg(feature = "std")]
+pub struct ReadInput<R> {
+    state: RefCell<ReadState<R>>,
+}
+
+#[cfg(feature = "std")]
+const READ_CHUNK_SIZE: usize = 8 * 1024;
+
+#[cfg(feature = "std")]
+struct ReadState<R> {
+    reader: R,
+    // The retained bytes, starting at offset `start`
+    buffer: alloc::collections::VecDeque<u8>,
+    start: usize,
+    window: usize,
+    eof: bool,
+    error: Option<ReadError>,
+}
+
+/// An error encountered while parsing from a [`ReadInput`].
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub enum ReadError {
+    /// The reader produced an error.
+    Io(std::io::Error),
+    /// The parser tried to backtrack to a position that is no longer retained.
+    WindowExceeded {
+        /// The position that the parser tried to read from.
+        pos: usize,
+        /// The earliest position that was still retained.
+        retained_from: usize,
+    },
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "failed to read input: {err}"),
+            Self::WindowExceeded { pos, retained_from } => write!(
+                f,
+                "tried to backtrack to offset {pos}, but only input from offset {retained_from} is retained"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::WindowExceeded { .. } => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: Read> ReadInput<R> {
+    /// The number of bytes retained for backtracking by default.
+    pub const DEFAULT_WINDOW: usize = 64 * 1024;
+
+    /// Create a new `ReadInput` from a reader, retaining [`ReadInput::DEFAULT_WINDOW`] bytes for backtracking.
+    pub fn new(reader: R) -> Self {
+        Self {
+            state: RefCell::new(ReadState {
+                reader,
+                buffer: alloc::collections::VecDeque::new(),
+                start: 0,
+                window: Self::DEFAULT_WINDOW,
+                eof: false,
+                error: None,
+            }),
+        }
+    }
+
+    /// Set the number of bytes behind the furthest position read that are retained for backtracking.
+    pub fn with_window(self, window: usize) -> Self {
+        self.state.borrow_mut().window = window;
+        self
+    }
+
+    /// Take the error that caused the input to end early, if any.
+    pub fn take_error(&self) -> Option<ReadError> {
+        self.state.borrow_mut().error.take()
+    }
+
+    /// Get back the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.state.into_inner().reader
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: Read> ReadState<R> {
+    fn get(&mut self, pos: usize) -> Option<u8> {
+        if pos < self.start {
+            self.error.get_or_insert(ReadError::WindowExceeded {
+                pos,
+                retained_from: self.start,
+            });
+            return None;
+        }
+        while pos >= self.start + self.buffer.len() {
+            if self.eof || self.error.is_some() {
+                return None;
+            }
+            let mut chunk = [0; READ_CHUNK_SIZE];
+            match self.reader.read(&mut chunk) {
+                Ok(0) => self.eof = true,
+                Ok(n) => self.buffer.extend(&chunk[..n]),
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(err) => self.error = Some(ReadError::Io(err)),
+            }
+        }
+        // Only retain the window behind the furthest position read
+        let discard = (pos - self.start).saturating_sub(self.window);
+        if discard > 0 {
+            self.buffer.drain(..discard);
+            self.start += discard;
+        }
+        self.buffer.get(pos - self.start).copied()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'src, R: Read + 'src> Input<'src> for &'src ReadInput<R> {
+    type Cursor = usize;
+    type Span = SimpleSpan;
+
+    type Token = u8;
+    type MaybeToken = u8;
+
+    type Cache = Self;
+
+    fn begin(self) -> (Self::Cursor, Self::Cache) {
+        (0, self)
+    }
+
+    #[inline(always)]
+    fn cursor_location(cursor: &Self::Cursor) -> usize {
+        *cursor
+    }
+
+    #[inline(always)]
+    unsafe fn next_maybe(
+        this: &mut Self::Cache,
+        cursor: &mut Self::Cursor,
+    ) -> Option<Self::MaybeToken> {
+        Self::next(this, cursor)
+    }
+
+    #[inline]
+    unsafe fn span(_this: &mut Self::Cache, range: Range<&Self::Cursor>) -> Self::Span {
+        (*range.start..*range.end).into()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'src, R: Read + 'src> ValueInput<'src> for &'src ReadInput<R> {
+    unsafe fn next(this: &mut Self::Cache, cursor: &mut Self::Cursor) -> Option<Self::Token> {
+        let byte = this.state.borrow_mut().get(*cursor)?;
+        *cursor += 1;
+        Some(byte)
+    }
+}
+
+/// Input type which produces the individual bits of a byte slice as `bool` tokens.
+///
+/// Bits are produced most-significant first within each byte, which is the convention used by most network
+/// protocols and media formats. Cursors and spans are measured in bit offsets from the start of the input. Use
+/// [`bits`](crate::primitive::bits) to parse multi-bit fields.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, input::BitInput, primitive::bits};
+/// // A DNS header flags word: QR (1 bit), opcode (4 bits), AA, TC, RD, RA (1 bit each), then 7 more bits
+/// let flags = bits::<_, extra::Err<Simple<bool>>>(1)
+///     .then(bits(4))
+///     .then(bits(1).repeated().exactly(4).collect::<Vec<_>>())
+///     .then_ignore(bits(7));
+///
+/// let ((qr, opcode), aa_tc_rd_ra) = flags.parse(BitInput::new(&[0x81, 0x80])).into_result().unwrap();
+/// assert_eq!(qr, 1);
+/// assert_eq!(opcode, 0);
+/// assert_eq!(aa_tc_rd_ra, vec![0, 0, 1, 1]);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BitInput<'src> {
+    bytes: &'src [u8],
+}
+
+impl<'src> BitInput<'src> {
+    /// Create a new bit input from a slice of bytes.
+    pub fn new(bytes: &'src [u8]) -> Self {
+        Self { bytes }
     }
-}
 
-impl<'src, S, I: BorrowInput<'src>> BorrowInput<'src> for WithContext<S, I>
-where
-    S: Span + Clone + 'src,
-    S::Context: Clone + 'src,
-    S::Offset: From<<I::Span as Span>::Offset>,
-{
-    #[inline(always)]
-    unsafe fn next_ref(
-        (cache, _): &mut Self::Cache,
-        cursor: &mut Self::Cursor,
-    ) -> Option<&'src Self::Token> {
-        I::next_ref(cache, cursor)
+    /// Get the underlying bytes of this input.
+    pub fn bytes(&self) -> &'src [u8] {
+        self.bytes
     }
 }
 
-impl<'src, S, I: SliceInput<'src>> SliceInput<'src> for WithContext<S, I>
-where
-    S: Span + Clone + 'src,
-    S::Context: Clone + 'src,
-    S::Offset: From<<I::Span as Span>::Offset>,
-{
-    type Slice = I::Slice;
+impl<'src> Input<'src> for BitInput<'src> {
+    type Cursor = usize;
+    type Span = SimpleSpan<usize>;
 
-    #[inline(always)]
-    fn full_slice((cache, _): &mut Self::Cache) -> Self::Slice {
-        I::full_slice(cache)
+    type Token = bool;
+    type MaybeToken = bool;
+
+    type Cache = &'src [u8];
+
+    #[inline]
+    fn begin(self) -> (Self::Cursor, Self::Cache) {
+        (0, self.bytes)
+    }
+
+    #[inline]
+    fn cursor_location(cursor: &Self::Cursor) -> usize {
+        *cursor
     }
 
     #[inline(always)]
-    unsafe fn slice((cache, _): &mut Self::Cache, range: Range<&Self::Cursor>) -> Self::Slice {
-        I::slice(cache, range)
+    unsafe fn next_maybe(
+        this: &mut Self::Cache,
+        cursor: &mut Self::Cursor,
+    ) -> Option<Self::MaybeToken> {
+        let byte = this.get(*cursor / 8)?;
+        let bit = byte & (0x80 >> (*cursor % 8)) != 0;
+        *cursor += 1;
+        Some(bit)
     }
 
     #[inline(always)]
-    unsafe fn slice_from(
-        (cache, _): &mut Self::Cache,
-        from: RangeFrom<&Self::Cursor>,
-    ) -> Self::Slice {
-        I::slice_from(cache, from)
+    unsafe fn span(_this: &mut Self::Cache, range: Range<&Self::Cursor>) -> Self::Span {
+        (*range.start..*range.end).into()
     }
 }
 
-impl<'src, S, I> Sealed for WithContext<S, I>
-where
-    I: Input<'src>,
-    S: Span + Clone + 'src,
-    S::Context: Clone + 'src,
-    S::Offset: From<<I::Span as Span>::Offset>,
-{
+impl<'src> ExactSizeInput<'src> for BitInput<'src> {
+    #[inline(always)]
+    unsafe fn span_from(this: &mut Self::Cache, range: RangeFrom<&Self::Cursor>) -> Self::Span {
+        (*range.start..this.len() * 8).into()
+    }
 }
-impl<'src, S, I> StrInput<'src> for WithContext<S, I>
-where
-    I: StrInput<'src>,
-    I::Token: Char,
-    S: Span + Clone + 'src,
-    S::Context: Clone + 'src,
-    S::Offset: From<<I::Span as Span>::Offset>,
-{
+
+impl<'src> ValueInput<'src> for BitInput<'src> {
+    #[inline(always)]
+    unsafe fn next(this: &mut Self::Cache, cursor: &mut Self::Cursor) -> Option<Self::Token> {
+        Self::next_maybe(this, cursor)
+    }
 }
 
-/// Input type which supports seekable readers. Uses a [`BufReader`] internally to buffer input and
-/// avoid unnecessary IO calls.
+/// Input type which parses directly from a memory-mapped file.
 ///
-/// Only available with the `std` feature
-#[cfg(feature = "std")]
-pub struct IoInput<R> {
-    reader: BufReader<R>,
-    last_cursor: usize,
+/// Tokens are bytes, cursors and spans are byte offsets into the file, and slices borrow straight from the mapping,
+/// so very large files (logs, CSV dumps, etc.) can be parsed without first reading them into memory. Parsers are
+/// given a reference to the input, i.e: `parser.parse(&mmap_input)`.
+///
+/// Only available with the `mmap` feature
+#[cfg(feature = "mmap")]
+pub struct MmapInput {
+    map: memmap2::Mmap,
 }
 
-#[cfg(feature = "std")]
-impl<R: Read + Seek> IoInput<R> {
-    /// Create a new `IoReader` from a seekable reader.
-    pub fn new(reader: R) -> IoInput<R> {
-        IoInput {
-            reader: BufReader::new(reader),
-            last_cursor: 0,
-        }
+#[cfg(feature = "mmap")]
+impl MmapInput {
+    /// Memory-map the file at the given path.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated (by this process or any other) for as long as the returned
+    /// `MmapInput` exists, as doing so is undefined behaviour. See [`memmap2::Mmap`] for more details.
+    pub unsafe fn open<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<Self> {
+        let file = std::fs::File::open(path)?;
+        // SAFETY: Upheld by the caller
+        let map = unsafe { memmap2::Mmap::map(&file)? };
+        Ok(Self { map })
+    }
+
+    /// Get the mapped bytes of this input.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.map
     }
 }
 
-#[cfg(feature = "std")]
-impl<'src, R: Read + Seek + 'src> Input<'src> for IoInput<R> {
+#[cfg(feature = "mmap")]
+impl From<memmap2::Mmap> for MmapInput {
+    fn from(map: memmap2::Mmap) -> Self {
+        Self { map }
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl<'src> Input<'src> for &'src MmapInput {
     type Cursor = usize;
-    type Span = SimpleSpan;
+    type Span = SimpleSpan<usize>;
 
     type Token = u8;
-    type MaybeToken = u8;
+    type MaybeToken = &'src u8;
 
-    type Cache = Self;
+    type Cache = &'src [u8];
 
+    #[inline]
     fn begin(self) -> (Self::Cursor, Self::Cache) {
-        (0, self)
+        (0, self.as_bytes())
     }
 
-    #[inline(always)]
+    #[inline]
     fn cursor_location(cursor: &Self::Cursor) -> usize {
         *cursor
     }
@@ -999,38 +2020,78 @@ impl<'src, R: Read + Seek + 'src> Input<'src> for IoInput<R> {
         this: &mut Self::Cache,
         cursor: &mut Self::Cursor,
     ) -> Option<Self::MaybeToken> {
-        Self::next(this, cursor)
+        <&[u8]>::next_maybe(this, cursor)
     }
 
-    #[inline]
+    #[inline(always)]
     unsafe fn span(_this: &mut Self::Cache, range: Range<&Self::Cursor>) -> Self::Span {
         (*range.start..*range.end).into()
     }
+
+    #[inline(always)]
+    unsafe fn match_seq<'p, S>(this: &mut Self::Cache, cursor: &mut Self::Cursor, seq: &S) -> bool
+    where
+        Self::Token: PartialEq,
+        S: OrderedSeq<'p, Self::Token> + ?Sized,
+    {
+        match_slice_seq(this, cursor, seq)
+    }
 }
 
-#[cfg(feature = "std")]
-impl<'src, R: Read + Seek + 'src> ValueInput<'src> for IoInput<R> {
-    unsafe fn next(this: &mut Self::Cache, cursor: &mut Self::Cursor) -> Option<Self::Token> {
-        if *cursor != this.last_cursor {
-            let seek = *cursor as i64 - this.last_cursor as i64;
+#[cfg(feature = "mmap")]
+impl<'src> ExactSizeInput<'src> for &'src MmapInput {
+    #[inline(always)]
+    unsafe fn span_from(this: &mut Self::Cache, range: RangeFrom<&Self::Cursor>) -> Self::Span {
+        (*range.start..this.len()).into()
+    }
+}
 
-            this.reader.seek_relative(seek).unwrap();
+#[cfg(feature = "mmap")]
+impl Sealed for &MmapInput {}
+#[cfg(feature = "mmap")]
+impl<'src> StrInput<'src> for &'src MmapInput {
+    #[inline]
+    unsafe fn line_start(cache: &mut Self::Cache, cursor: usize) -> usize {
+        byte_line_start(cache, cursor)
+    }
+}
 
-            this.last_cursor = *cursor;
-        }
+#[cfg(feature = "mmap")]
+impl<'src> SliceInput<'src> for &'src MmapInput {
+    type Slice = &'src [u8];
 
-        let mut out = 0;
+    #[inline(always)]
+    fn full_slice(this: &mut Self::Cache) -> Self::Slice {
+        *this
+    }
 
-        let r = this.reader.read_exact(std::slice::from_mut(&mut out));
+    #[inline(always)]
+    unsafe fn slice(this: &mut Self::Cache, range: Range<&Self::Cursor>) -> Self::Slice {
+        &this[*range.start..*range.end]
+    }
 
-        match r {
-            Ok(()) => {
-                this.last_cursor += 1;
-                *cursor += 1;
-                Some(out)
-            }
-            Err(_) => None,
-        }
+    #[inline(always)]
+    unsafe fn slice_from(this: &mut Self::Cache, from: RangeFrom<&Self::Cursor>) -> Self::Slice {
+        &this[*from.start..]
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl<'src> ValueInput<'src> for &'src MmapInput {
+    #[inline(always)]
+    unsafe fn next(this: &mut Self::Cache, cursor: &mut Self::Cursor) -> Option<Self::Token> {
+        Self::next_maybe(this, cursor).copied()
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl<'src> BorrowInput<'src> for &'src MmapInput {
+    #[inline(always)]
+    unsafe fn next_ref(
+        this: &mut Self::Cache,
+        cursor: &mut Self::Cursor,
+    ) -> Option<&'src Self::Token> {
+        Self::next_maybe(this, cursor)
     }
 }
 
@@ -1105,7 +2166,7 @@ impl<'src, I: Input<'src>> PartialEq for Cursor<'src, '_, I> {
 
 impl<'src, I: Input<'src>> PartialOrd for Cursor<'src, '_, I> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(I::cursor_location(&self.inner).cmp(&I::cursor_location(&other.inner)))
+        Some(self.cmp(other))
     }
 }
 
@@ -1118,6 +2179,8 @@ impl<'src, I: Input<'src>> Ord for Cursor<'src, '_, I> {
 pub(crate) struct Errors<T, E> {
     pub(crate) alt: Option<Located<T, E>>,
     pub(crate) secondary: Vec<Located<T, E>>,
+    /// Whether [`Parser::or_hole`] should fill holes, see [`Parser::parse_with_holes`].
+    pub(crate) holes: bool,
 }
 
 impl<T, E> Errors<T, E> {
@@ -1133,10 +2196,71 @@ impl<T, E> Default for Errors<T, E> {
         Self {
             alt: None,
             secondary: Vec::new(),
+            holes: false,
         }
     }
 }
 
+/// The key of a memoized parse: the input position, the address of the memoized parser, and an optional key provided
+/// by [`Parser::memoize_by_key`].
+#[cfg(feature = "memoization")]
+pub(crate) type MemoKey = (usize, usize, Option<UserMemoKey>);
+
+/// A key provided by [`Parser::memoize_by_key`].
+///
+/// Keys of any type share the same memo table, so they're compared by type and then by value: two keys are only equal
+/// if they have the same type and compare equal.
+#[cfg(feature = "memoization")]
+#[derive(Clone)]
+pub(crate) struct UserMemoKey(Rc<dyn DynMemoKey>);
+
+#[cfg(feature = "memoization")]
+impl UserMemoKey {
+    pub(crate) fn new<K: Hash + Eq + 'static>(key: K) -> Self {
+        Self(Rc::new(key))
+    }
+}
+
+#[cfg(feature = "memoization")]
+impl PartialEq for UserMemoKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.dyn_eq(&*other.0)
+    }
+}
+
+#[cfg(feature = "memoization")]
+impl Eq for UserMemoKey {}
+
+#[cfg(feature = "memoization")]
+impl Hash for UserMemoKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.dyn_hash(state)
+    }
+}
+
+#[cfg(feature = "memoization")]
+trait DynMemoKey {
+    fn as_any(&self) -> &dyn Any;
+    fn dyn_eq(&self, other: &dyn DynMemoKey) -> bool;
+    fn dyn_hash(&self, state: &mut dyn Hasher);
+}
+
+#[cfg(feature = "memoization")]
+impl<K: Hash + Eq + 'static> DynMemoKey for K {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn dyn_eq(&self, other: &dyn DynMemoKey) -> bool {
+        other.as_any().downcast_ref::<K>() == Some(self)
+    }
+
+    fn dyn_hash(&self, mut state: &mut dyn Hasher) {
+        TypeId::of::<K>().hash(&mut state);
+        self.hash(&mut state);
+    }
+}
+
 /// Internal type representing the owned parts of an input - used at the top level by a call to
 /// `parse`.
 pub(crate) struct InputOwn<'src, 's, I: Input<'src>, E: ParserExtra<'src, I>> {
@@ -1146,7 +2270,7 @@ pub(crate) struct InputOwn<'src, 's, I: Input<'src>, E: ParserExtra<'src, I>> {
     pub(crate) state: MaybeMut<'s, E::State>,
     pub(crate) ctx: E::Context,
     #[cfg(feature = "memoization")]
-    pub(crate) memos: HashMap<(usize, usize), Option<Located<I::Cursor, E::Error>>>,
+    pub(crate) memos: HashMap<MemoKey, Option<Located<I::Cursor, E::Error>>>,
 }
 
 impl<'src, 's, I, E> InputOwn<'src, 's, I, E>
@@ -1176,13 +2300,21 @@ where
     where
         E::Context: Default,
     {
+        Self::new_state_ctx(input, state, E::Context::default())
+    }
+
+    pub(crate) fn new_state_ctx(
+        input: I,
+        state: &'s mut E::State,
+        ctx: E::Context,
+    ) -> InputOwn<'src, 's, I, E> {
         let (start, cache) = input.begin();
         InputOwn {
             start,
             cache,
             errors: Errors::default(),
             state: MaybeMut::Ref(state),
-            ctx: E::Context::default(),
+            ctx,
             #[cfg(feature = "memoization")]
             memos: HashMap::default(),
         }
@@ -1200,12 +2332,49 @@ where
         }
     }
 
-    pub(crate) fn into_errs(self) -> Vec<E::Error> {
-        self.errors
-            .secondary
-            .into_iter()
-            .map(|err| err.err)
-            .collect()
+    /// Split the secondary errors into fatal errors and non-fatal warnings (or hints).
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn into_errs(self) -> (Vec<(usize, E::Error)>, Vec<(Severity, E::Error)>) {
+        let mut errs = Vec::new();
+        let mut warnings = Vec::new();
+        for err in self.errors.secondary {
+            match err.severity {
+                Severity::Error => errs.push((I::cursor_location(&err.pos), err.err)),
+                severity => warnings.push((severity, err.err)),
+            }
+        }
+        (errs, warnings)
+    }
+
+    /// Parse the input from the start with `go`, collecting the output and errors into a [`ParseResult`].
+    ///
+    /// `go` may also report something else about the parse, such as how much of the input it consumed.
+    pub(crate) fn run<O, R>(
+        mut self,
+        go: impl FnOnce(&mut InputRef<'src, '_, I, E>) -> (Result<O, ()>, R),
+    ) -> (ParseResult<O, E::Error>, R) {
+        let mut inp = self.as_ref_start();
+        let (res, extra) = go(&mut inp);
+        let alt = inp
+            .take_alt()
+            .map(|alt| (I::cursor_location(&alt.pos), alt.err))
+            .unwrap_or_else(|| {
+                let cursor = inp.cursor();
+                let fake_span = inp.span_since(&cursor);
+                (
+                    I::cursor_location(cursor.inner()),
+                    E::Error::expected_found([], None, fake_span),
+                )
+            });
+        let (mut errs, warnings) = self.into_errs();
+        let out = match res {
+            Ok(out) => Some(out),
+            Err(()) => {
+                errs.push(alt);
+                None
+            }
+        };
+        (ParseResult::new(out, errs).with_warnings(warnings), extra)
     }
 }
 
@@ -1217,7 +2386,7 @@ pub struct InputRef<'src, 'parse, I: Input<'src>, E: ParserExtra<'src, I>> {
     pub(crate) state: &'parse mut E::State,
     pub(crate) ctx: &'parse E::Context,
     #[cfg(feature = "memoization")]
-    pub(crate) memos: &'parse mut HashMap<(usize, usize), Option<Located<I::Cursor, E::Error>>>,
+    pub(crate) memos: &'parse mut HashMap<MemoKey, Option<Located<I::Cursor, E::Error>>>,
 }
 
 impl<'src, 'parse, I: Input<'src>, E: ParserExtra<'src, I>> InputRef<'src, 'parse, I, E> {
@@ -1277,7 +2446,7 @@ impl<'src, 'parse, I: Input<'src>, E: ParserExtra<'src, I>> InputRef<'src, 'pars
         new_errors: &'sub_parse mut Errors<J::Cursor, F::Error>,
         f: impl FnOnce(&mut InputRef<'src, 'sub_parse, J, F>) -> O,
         #[cfg(feature = "memoization")] memos: &'sub_parse mut HashMap<
-            (usize, usize),
+            MemoKey,
             Option<Located<J::Cursor, E::Error>>,
         >,
     ) -> O
@@ -1286,6 +2455,7 @@ impl<'src, 'parse, I: Input<'src>, E: ParserExtra<'src, I>> InputRef<'src, 'pars
         J: Input<'src>,
         F: ParserExtra<'src, J, State = E::State, Context = E::Context, Error = E::Error>,
     {
+        new_errors.holes = self.errors.holes;
         let mut new_inp = InputRef {
             cursor: start,
             cache,
@@ -1301,7 +2471,7 @@ impl<'src, 'parse, I: Input<'src>, E: ParserExtra<'src, I>> InputRef<'src, 'pars
                 .errors
                 .secondary
                 .drain(..)
-                .map(|err| Located::at(self.cursor.clone(), err.err)),
+                .map(|err| Located::with_severity(self.cursor.clone(), err.err, err.severity)),
         );
         if let Some(alt) = new_inp.errors.alt.take() {
             self.errors.alt = Some(Located::at(self.cursor.clone(), alt.err));
@@ -1410,6 +2580,25 @@ impl<'src, 'parse, I: Input<'src>, E: ParserExtra<'src, I>> InputRef<'src, 'pars
         token
     }
 
+    /// Attempt to match an entire sequence against the input at once. See [`Input::match_seq`].
+    #[inline(always)]
+    pub(crate) fn match_seq<'p, S>(&mut self, seq: &S) -> bool
+    where
+        I::Token: PartialEq,
+        S: OrderedSeq<'p, I::Token> + ?Sized,
+    {
+        // SAFETY: cursor was generated by previous call to `Input::next`
+        if unsafe { I::match_seq(self.cache, &mut self.cursor, seq) } {
+            // Inspectors still need to observe each token that was matched
+            for tok in seq.seq_iter() {
+                self.state.on_token(tok.borrow());
+            }
+            true
+        } else {
+            false
+        }
+    }
+
     #[inline(always)]
     pub(crate) fn next_ref_inner(&mut self) -> Option<&'src I::Token>
     where
@@ -1511,7 +2700,7 @@ impl<'src, 'parse, I: Input<'src>, E: ParserExtra<'src, I>> InputRef<'src, 'pars
         I: ValueInput<'src>,
     {
         // SAFETY: cursor was generated by previous call to `Input::next`
-        unsafe { I::next(self.cache, &mut self.cursor.clone()).map(Into::into) }
+        unsafe { I::next(self.cache, &mut self.cursor.clone()) }
     }
 
     /// Peek the next token in the input. Returns `None` if the end of the input has been reached.
@@ -1521,7 +2710,7 @@ impl<'src, 'parse, I: Input<'src>, E: ParserExtra<'src, I>> InputRef<'src, 'pars
         I: BorrowInput<'src>,
     {
         // SAFETY: cursor was generated by previous call to `Input::next`
-        unsafe { I::next_ref(self.cache, &mut self.cursor.clone()).map(Into::into) }
+        unsafe { I::next_ref(self.cache, &mut self.cursor.clone()) }
     }
 
     /// Skip the next token in the input.
@@ -1582,13 +2771,13 @@ impl<'src, 'parse, I: Input<'src>, E: ParserExtra<'src, I>> InputRef<'src, 'pars
         unsafe { I::slice_from(self.cache, &self.cursor..) }
     }
 
-    // /// Get a span over the input that covers the given cursor range.
-    // #[inline(always)]
-    // pub fn span(&self, range: Range<&Cursor<'src, 'parse, I>>) -> I::Span {
-    //     // SAFETY: `Cursor` is invariant over 'parse, so we know that this cursor came from the same input
-    //     // See `https://plv.mpi-sws.org/rustbelt/ghostcell/`
-    //     unsafe { I::span(self.cache, &range.start.inner..&range.end.inner) }
-    // }
+    /// Get a span over the input that covers the given cursor range.
+    #[inline(always)]
+    pub fn span(&mut self, range: Range<&Cursor<'src, 'parse, I>>) -> I::Span {
+        // SAFETY: `Cursor` is invariant over 'parse, so we know that this cursor came from the same input
+        // See `https://plv.mpi-sws.org/rustbelt/ghostcell/`
+        unsafe { I::span(self.cache, &range.start.inner..&range.end.inner) }
+    }
 
     /// Get a span over the input that goes from the given cursor to the end of the input.
     // TODO: Unify with `InputRef::span`
@@ -1610,6 +2799,50 @@ impl<'src, 'parse, I: Input<'src>, E: ParserExtra<'src, I>> InputRef<'src, 'pars
         unsafe { I::span(self.cache, &before.inner..&self.cursor) }
     }
 
+    /// Get the column of the current position in the input, counting from `1`.
+    ///
+    /// Each token advances the column by one, except for tabs, which advance it to the next multiple of `tab_width`
+    /// (plus one). Any token for which [`Char::is_newline`] holds begins a new line.
+    ///
+    /// The column is found by scanning the current line from its start, so prefer [`Input::line_col`] if columns are
+    /// needed for every span of an input with very long lines.
+    pub fn current_column(&mut self, tab_width: usize) -> usize
+    where
+        I: StrInput<'src>,
+        I::Token: Char,
+    {
+        // SAFETY: `self.cursor` was generated by this input
+        let mut cursor = unsafe { I::line_start(self.cache, self.cursor) };
+        let mut col = 1;
+        while cursor < self.cursor {
+            // SAFETY: `cursor` starts at the beginning of a line, which is a valid cursor, and is only ever advanced by
+            // the input itself
+            match unsafe { I::next(self.cache, &mut cursor) } {
+                Some(tok) => col = crate::text::next_column(col, &tok, tab_width),
+                None => break,
+            }
+        }
+        col
+    }
+
+    /// Get the token at the given cursor, or `None` if the cursor is at the end of the input.
+    #[inline]
+    pub(crate) fn token_at(&mut self, at: &I::Cursor) -> Option<MaybeRef<'src, I::Token>> {
+        // SAFETY: `at` was generated by a previous call to `Input::next` on this input
+        unsafe { I::next_maybe(self.cache, &mut at.clone()).map(Into::into) }
+    }
+
+    /// Get the span of the token at the given cursor, or an empty span if the cursor is at the end of the input.
+    #[inline]
+    pub(crate) fn span_of_token_at(&mut self, at: &I::Cursor) -> I::Span {
+        let mut end = at.clone();
+        // SAFETY: `at` was generated by a previous call to `Input::next` on this input
+        unsafe {
+            I::next_maybe(self.cache, &mut end);
+            I::span(self.cache, at..&end)
+        }
+    }
+
     /// SAFETY: Previous cursor + skip must not exceed length
     #[inline(always)]
     #[cfg(any(feature = "regex", feature = "lexical-numbers"))]
@@ -1625,12 +2858,24 @@ impl<'src, 'parse, I: Input<'src>, E: ParserExtra<'src, I>> InputRef<'src, 'pars
         &mut self,
         cursor: impl Into<Option<Cursor<'src, 'parse, I>>>,
         error: E::Error,
+    ) {
+        self.emit_with_severity(cursor, error, Severity::Error)
+    }
+
+    #[inline]
+    pub(crate) fn emit_with_severity(
+        &mut self,
+        cursor: impl Into<Option<Cursor<'src, 'parse, I>>>,
+        error: E::Error,
+        severity: Severity,
     ) {
         let cursor = cursor
             .into()
             .map(|c| c.inner)
             .unwrap_or_else(|| self.cursor.clone());
-        self.errors.secondary.push(Located::at(cursor, error));
+        self.errors
+            .secondary
+            .push(Located::with_severity(cursor, error, severity));
     }
 
     #[inline]
@@ -1648,7 +2893,7 @@ impl<'src, 'parse, I: Input<'src>, E: ParserExtra<'src, I>> InputRef<'src, 'pars
 
         // Prioritize errors before choosing whether to generate the alt (avoids unnecessary error creation)
         self.errors.alt = Some(match self.errors.alt.take() {
-            Some(alt) => match { I::cursor_location(&alt.pos).cmp(&I::cursor_location(at)) } {
+            Some(alt) => match I::cursor_location(&alt.pos).cmp(&I::cursor_location(at)) {
                 Ordering::Equal => {
                     Located::at(alt.pos, alt.err.merge_expected_found(expected, found, span))
                 }
@@ -1687,7 +2932,7 @@ impl<'src, 'parse, I: Input<'src>, E: ParserExtra<'src, I>> InputRef<'src, 'pars
 
 /// Struct used in [`Parser::validate`] to collect user-emitted errors
 pub struct Emitter<E> {
-    emitted: Vec<E>,
+    emitted: Vec<(Severity, E)>,
 }
 
 impl<E> Emitter<E> {
@@ -1699,14 +2944,29 @@ impl<E> Emitter<E> {
     }
 
     #[inline]
-    pub(crate) fn errors(self) -> Vec<E> {
+    pub(crate) fn errors(self) -> Vec<(Severity, E)> {
         self.emitted
     }
 
     /// Emit a non-fatal error
     #[inline]
     pub fn emit(&mut self, err: E) {
-        self.emitted.push(err)
+        self.emitted.push((Severity::Error, err))
+    }
+
+    /// Emit a warning.
+    ///
+    /// Unlike errors emitted with [`Emitter::emit`], warnings do not cause the parse to be considered failed: they
+    /// are returned separately via [`ParseResult::warnings`](crate::ParseResult::warnings).
+    #[inline]
+    pub fn warn(&mut self, err: E) {
+        self.emitted.push((Severity::Warning, err))
+    }
+
+    /// Emit a hint. Like warnings, hints do not cause the parse to be considered failed.
+    #[inline]
+    pub fn hint(&mut self, err: E) {
+        self.emitted.push((Severity::Hint, err))
     }
 }
 
@@ -6,9 +6,16 @@
 //! beside his pillar and woken him with a start."*
 use crate::{
     input::{Checkpoint, Cursor},
+    span::Span,
     Input,
 };
-use core::ops::{Deref, DerefMut};
+#[cfg(feature = "intern")]
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::{
+    cell::Cell,
+    ops::{Deref, DerefMut},
+};
 
 #[allow(unused)] // for intra-doc links
 use crate::Parser;
@@ -44,9 +51,31 @@ impl<'src, I: Input<'src>> Inspector<'src, I> for () {
     fn on_rewind<'parse>(&mut self, _: &Checkpoint<'src, 'parse, I, Self>) {}
 }
 
+/// Forwards every hook to the borrowed inspector, so that caller-owned state can be used without moving it into
+/// the parser.
+impl<'src, I: Input<'src>, T: Inspector<'src, I> + ?Sized> Inspector<'src, I> for &mut T {
+    type Checkpoint = T::Checkpoint;
+    #[inline(always)]
+    fn on_token(&mut self, token: &I::Token) {
+        (**self).on_token(token)
+    }
+    #[inline(always)]
+    fn on_save<'parse>(&self, cursor: &Cursor<'src, 'parse, I>) -> Self::Checkpoint {
+        (**self).on_save(cursor)
+    }
+    #[inline(always)]
+    fn on_rewind<'parse>(&mut self, marker: &Checkpoint<'src, 'parse, I, Self::Checkpoint>) {
+        (**self).on_rewind(marker)
+    }
+}
+
 /// A state type that should be accessible directly from `parser.state()` and has no special behavior.
 ///
 /// This wrapper implements the [`Inspector`] trait for you so you don't have to.
+///
+/// `SimpleState` can also wrap a mutable reference, such as `SimpleState<&mut SymbolTable>`, which lets a parser fill
+/// in data owned by the caller.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub struct SimpleState<T>(pub T);
 impl<'src, T, I: Input<'src>> Inspector<'src, I> for SimpleState<T> {
     type Checkpoint = ();
@@ -77,3 +106,424 @@ impl<T> From<T> for SimpleState<T> {
         Self(value)
     }
 }
+
+/// An [`Inspector`] that counts parsing events, useful for profiling the behaviour of a parser.
+///
+/// Excessive backtracking is a common cause of poor parser performance. By using this type as the parser state,
+/// you can see how many tokens were pulled from the input, how many times combinators created checkpoints, and how
+/// many times the parser was forced to rewind. Comparing these figures between versions of a grammar (or of chumsky
+/// itself) is a cheap way to catch performance regressions.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, inspector::Profiler};
+/// let digits = text::digits::<_, extra::Full<EmptyErr, Profiler, ()>>(10).to_slice();
+/// let parser = digits.then_ignore(just('+')).or(digits);
+///
+/// let mut profiler = Profiler::default();
+/// parser.parse_with_state("12345", &mut profiler).into_result().unwrap();
+///
+/// // The first branch of the `or` failed, forcing the digits to be parsed twice
+/// assert!(profiler.rewinds() >= 1);
+/// assert!(profiler.tokens() > 5);
+/// ```
+#[derive(Debug, Default)]
+pub struct Profiler {
+    tokens: usize,
+    saves: Cell<usize>,
+    rewinds: usize,
+}
+
+impl Profiler {
+    /// The number of tokens that have been pulled from the input, including tokens that were later backtracked over.
+    pub fn tokens(&self) -> usize {
+        self.tokens
+    }
+
+    /// The number of checkpoints that combinators have created.
+    pub fn saves(&self) -> usize {
+        self.saves.get()
+    }
+
+    /// The number of times that the parser rewound to an earlier checkpoint.
+    pub fn rewinds(&self) -> usize {
+        self.rewinds
+    }
+
+    /// Reset all counts to zero.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+impl<'src, I: Input<'src>> Inspector<'src, I> for Profiler {
+    type Checkpoint = ();
+    #[inline(always)]
+    fn on_token(&mut self, _: &<I as Input<'src>>::Token) {
+        self.tokens += 1;
+    }
+    #[inline(always)]
+    fn on_save<'parse>(&self, _: &Cursor<'src, 'parse, I>) -> Self::Checkpoint {
+        self.saves.set(self.saves.get() + 1);
+    }
+    #[inline(always)]
+    fn on_rewind<'parse>(&mut self, _: &Checkpoint<'src, 'parse, I, Self::Checkpoint>) {
+        self.rewinds += 1;
+    }
+}
+
+/// A parser state that can intern slices of the input, used by [`Parser::intern`].
+///
+/// Interning maps equal values to the same small, cheaply-comparable symbol, so that each distinct value (such as an
+/// identifier) only needs to be stored once. A simple implementation for strings, [`StringInterner`], is available
+/// with the `intern` feature.
+pub trait Interner<T> {
+    /// The symbol produced when a value is interned.
+    type Symbol;
+
+    /// Intern a value, returning its symbol. Interning equal values must produce equal symbols.
+    fn intern(&mut self, value: T) -> Self::Symbol;
+}
+
+impl<T, U: Interner<T> + ?Sized> Interner<T> for &mut U {
+    type Symbol = U::Symbol;
+    #[inline(always)]
+    fn intern(&mut self, value: T) -> Self::Symbol {
+        (**self).intern(value)
+    }
+}
+
+impl<T, U: Interner<T>> Interner<T> for SimpleState<U> {
+    type Symbol = U::Symbol;
+    #[inline(always)]
+    fn intern(&mut self, value: T) -> Self::Symbol {
+        self.0.intern(value)
+    }
+}
+
+/// A symbol produced by a [`StringInterner`].
+#[cfg(feature = "intern")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Symbol(u32);
+
+#[cfg(feature = "intern")]
+impl Symbol {
+    /// The index of this symbol. Symbols are numbered in the order that their strings were first interned.
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// A simple string [`Interner`] that can be used directly as the parser state.
+///
+/// Each distinct string is only allocated once, the first time it is interned.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, inspector::StringInterner};
+/// let idents = text::ascii::ident::<_, extra::Full<EmptyErr, StringInterner, ()>>()
+///     .intern()
+///     .padded()
+///     .repeated()
+///     .collect::<Vec<_>>();
+///
+/// let mut interner = StringInterner::new();
+/// let syms = idents.parse_with_state("foo bar foo", &mut interner).unwrap();
+/// assert_eq!(syms[0], syms[2]);
+/// assert_ne!(syms[0], syms[1]);
+/// assert_eq!(interner.resolve(syms[1]), Some("bar"));
+/// assert_eq!(interner.len(), 2);
+/// ```
+#[cfg(feature = "intern")]
+#[derive(Clone, Debug, Default)]
+pub struct StringInterner {
+    symbols: hashbrown::HashMap<Rc<str>, Symbol>,
+    strings: Vec<Rc<str>>,
+}
+
+#[cfg(feature = "intern")]
+impl StringInterner {
+    /// Create a new, empty interner.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Intern a string, returning its symbol.
+    pub fn intern(&mut self, s: &str) -> Symbol {
+        if let Some(symbol) = self.symbols.get(s) {
+            return *symbol;
+        }
+        let symbol = Symbol(
+            self.strings
+                .len()
+                .try_into()
+                .expect("too many strings interned"),
+        );
+        let s: Rc<str> = Rc::from(s);
+        self.strings.push(s.clone());
+        self.symbols.insert(s, symbol);
+        symbol
+    }
+
+    /// Get the symbol of a string, if it has been interned.
+    pub fn get(&self, s: &str) -> Option<Symbol> {
+        self.symbols.get(s).copied()
+    }
+
+    /// Get the string that a symbol was interned from, or `None` if the symbol did not come from this interner.
+    pub fn resolve(&self, symbol: Symbol) -> Option<&str> {
+        self.strings.get(symbol.index()).map(|s| &**s)
+    }
+
+    /// The number of distinct strings that have been interned.
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Returns `true` if no strings have been interned.
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+}
+
+#[cfg(feature = "intern")]
+impl Interner<&str> for StringInterner {
+    type Symbol = Symbol;
+    #[inline(always)]
+    fn intern(&mut self, value: &str) -> Self::Symbol {
+        StringInterner::intern(self, value)
+    }
+}
+
+#[cfg(feature = "intern")]
+impl<'src, I: Input<'src>> Inspector<'src, I> for StringInterner {
+    type Checkpoint = ();
+    #[inline(always)]
+    fn on_token(&mut self, _: &<I as Input<'src>>::Token) {}
+    #[inline(always)]
+    fn on_save<'parse>(&self, _: &Cursor<'src, 'parse, I>) -> Self::Checkpoint {}
+    #[inline(always)]
+    fn on_rewind<'parse>(&mut self, _: &Checkpoint<'src, 'parse, I, Self::Checkpoint>) {}
+}
+
+/// An [`Inspector`] that is notified when nodes created with [`Parser::node`] begin and end.
+pub trait NodeInspector<'src, I: Input<'src>, K>: Inspector<'src, I> {
+    /// This function is called when the parser begins parsing a node of the given kind.
+    fn on_enter(&mut self, kind: &K);
+    /// This function is called when the parser finishes parsing a node of the given kind.
+    ///
+    /// `span` is the span of the node if it was parsed successfully, or `None` if it failed to parse.
+    fn on_exit(&mut self, kind: &K, span: Option<I::Span>);
+}
+
+impl<'src, I: Input<'src>, K> NodeInspector<'src, I, K> for () {
+    #[inline(always)]
+    fn on_enter(&mut self, _: &K) {}
+    #[inline(always)]
+    fn on_exit(&mut self, _: &K, _: Option<I::Span>) {}
+}
+
+/// An event emitted during parsing. See [`Events`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Event<K, T, S> {
+    /// The parser began parsing a node of the given kind.
+    Enter(K),
+    /// The parser consumed a token.
+    Token(T),
+    /// The parser finished parsing a node of the given kind, which covered the given span.
+    Exit(K, S),
+}
+
+/// An [`Inspector`] that records a stream of structured [`Event`]s as parsing progresses.
+///
+/// Mark the nodes you're interested in with [`Parser::node`], then parse with an `Events` as the parser state. When
+/// the parse completes, the recorded events describe the structure of the input, which is useful for tasks like
+/// syntax highlighting or building a concrete syntax tree without changing the output types of the grammar. Because
+/// the parser does not need to produce output, [`Parser::check_with_state`] may be used to avoid building it entirely.
+///
+/// Events produced by parsers that were later backtracked over are discarded, as are the events of nodes that failed
+/// to parse, so the recorded events always form a well-nested sequence of [`Event::Enter`]s and [`Event::Exit`]s.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, inspector::{Event, Events}};
+/// type Extra = extra::Full<EmptyErr, Events<&'static str, char, SimpleSpan>, ()>;
+///
+/// let num = text::int::<_, Extra>(10).node("num");
+/// let sum = num.then_ignore(just('+')).then(num).ignored().node("sum");
+/// let expr = sum.or(num.ignored());
+///
+/// let mut events = Events::default();
+/// assert!(!expr.check_with_state("1+2", &mut events).has_errors());
+/// assert_eq!(
+///     events.into_events(),
+///     vec![
+///         Event::Enter("sum"),
+///         Event::Enter("num"),
+///         Event::Token('1'),
+///         Event::Exit("num", SimpleSpan::new(0, 1)),
+///         Event::Token('+'),
+///         Event::Enter("num"),
+///         Event::Token('2'),
+///         Event::Exit("num", SimpleSpan::new(2, 3)),
+///         Event::Exit("sum", SimpleSpan::new(0, 3)),
+///     ],
+/// );
+///
+/// // Events from the failed `sum` branch are discarded
+/// let mut events = Events::default();
+/// assert!(!expr.check_with_state("7", &mut events).has_errors());
+/// assert_eq!(
+///     events.into_events(),
+///     vec![
+///         Event::Enter("num"),
+///         Event::Token('7'),
+///         Event::Exit("num", SimpleSpan::new(0, 1)),
+///     ],
+/// );
+/// ```
+#[derive(Debug)]
+pub struct Events<K, T, S> {
+    events: Vec<Event<K, T, S>>,
+    // The indices of the `Enter` events of nodes that are currently being parsed
+    open: Vec<usize>,
+}
+
+impl<K, T, S> Default for Events<K, T, S> {
+    fn default() -> Self {
+        Self {
+            events: Vec::new(),
+            open: Vec::new(),
+        }
+    }
+}
+
+impl<K, T, S> Events<K, T, S> {
+    /// The events that have been recorded so far.
+    pub fn events(&self) -> &[Event<K, T, S>] {
+        &self.events
+    }
+
+    /// Take the recorded events, leaving this inspector empty.
+    pub fn into_events(self) -> Vec<Event<K, T, S>> {
+        self.events
+    }
+
+    /// Pass each recorded event, in order, to the given callback.
+    pub fn for_each(self, f: impl FnMut(Event<K, T, S>)) {
+        self.events.into_iter().for_each(f)
+    }
+
+    /// Classify the input according to the nodes that were recorded, for the purposes of syntax highlighting.
+    ///
+    /// The result is a sequence of non-overlapping spans, in order, each paired with the kind of the innermost node
+    /// that covers it. Parts of a node that are covered by a nested node take on the kind of the nested node. Parts
+    /// of the input that are not covered by any node (such as whitespace between nodes) are omitted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::{prelude::*, inspector::Events};
+    /// type Extra = extra::Full<EmptyErr, Events<&'static str, char, SimpleSpan>, ()>;
+    ///
+    /// let stmt = just::<_, _, Extra>("let")
+    ///     .node("keyword")
+    ///     .then(text::ascii::ident().node("ident").padded())
+    ///     .then_ignore(just('='))
+    ///     .then(text::int(10).node("number").padded())
+    ///     .node("stmt");
+    ///
+    /// let mut events = Events::default();
+    /// assert!(!stmt.check_with_state("let x = 5", &mut events).has_errors());
+    /// assert_eq!(
+    ///     events.highlights(),
+    ///     vec![
+    ///         (SimpleSpan::new(0, 3), "keyword"),
+    ///         (SimpleSpan::new(3, 4), "stmt"),
+    ///         (SimpleSpan::new(4, 5), "ident"),
+    ///         (SimpleSpan::new(5, 8), "stmt"),
+    ///         (SimpleSpan::new(8, 9), "number"),
+    ///     ],
+    /// );
+    /// ```
+    pub fn highlights(&self) -> Vec<(S, K)>
+    where
+        S: Span + Clone,
+        S::Offset: Ord,
+        K: Clone,
+    {
+        // For each open node, the highlights of the nodes nested within it that have been closed so far
+        let mut open = Vec::<Vec<(S, K)>>::new();
+        let mut highlights = Vec::new();
+        for event in &self.events {
+            match event {
+                Event::Enter(_) => open.push(Vec::new()),
+                Event::Token(_) => {}
+                Event::Exit(kind, span) => {
+                    let children = open.pop().unwrap_or_default();
+                    let out = open.last_mut().unwrap_or(&mut highlights);
+                    let gap = |out: &mut Vec<_>, range: core::ops::Range<S::Offset>| {
+                        if range.start < range.end {
+                            out.push((S::new(span.context(), range), kind.clone()));
+                        }
+                    };
+
+                    let mut pos = span.start();
+                    for (child, child_kind) in children {
+                        gap(out, pos.clone()..child.start());
+                        pos = pos.max(child.end());
+                        out.push((child, child_kind));
+                    }
+                    gap(out, pos..span.end());
+                }
+            }
+        }
+        highlights
+    }
+}
+
+impl<'src, I, K> Inspector<'src, I> for Events<K, I::Token, I::Span>
+where
+    I: Input<'src>,
+    I::Token: Clone,
+{
+    type Checkpoint = (usize, usize);
+    #[inline]
+    fn on_token(&mut self, token: &I::Token) {
+        self.events.push(Event::Token(token.clone()));
+    }
+    #[inline]
+    fn on_save<'parse>(&self, _: &Cursor<'src, 'parse, I>) -> Self::Checkpoint {
+        (self.events.len(), self.open.len())
+    }
+    #[inline]
+    fn on_rewind<'parse>(&mut self, marker: &Checkpoint<'src, 'parse, I, Self::Checkpoint>) {
+        let (events, open) = *marker.inspector();
+        self.events.truncate(events);
+        self.open.truncate(open);
+    }
+}
+
+impl<'src, I, K> NodeInspector<'src, I, K> for Events<K, I::Token, I::Span>
+where
+    I: Input<'src>,
+    I::Token: Clone,
+    K: Clone,
+{
+    #[inline]
+    fn on_enter(&mut self, kind: &K) {
+        self.open.push(self.events.len());
+        self.events.push(Event::Enter(kind.clone()));
+    }
+    #[inline]
+    fn on_exit(&mut self, kind: &K, span: Option<I::Span>) {
+        let enter = self.open.pop();
+        match span {
+            Some(span) => self.events.push(Event::Exit(kind.clone(), span)),
+            None => self.events.truncate(enter.unwrap_or(0)),
+        }
+    }
+}
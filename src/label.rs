@@ -18,11 +18,12 @@ pub trait LabelError<'src, I: Input<'src>, L>: Error<'src, I> {
     fn in_context(&mut self, label: L, span: I::Span);
 }
 
-/// See [`Parser::labelled`].
+/// See [`Parser::labelled`] and [`Parser::in_context`].
 #[derive(Copy, Clone)]
 pub struct Labelled<A, L> {
     pub(crate) parser: A,
     pub(crate) label: L,
+    pub(crate) is_label: bool,
     pub(crate) is_context: bool,
 }
 
@@ -60,7 +61,9 @@ where
             let before_loc = I::cursor_location(&before.cursor().inner);
             let new_alt_loc = I::cursor_location(&new_alt.pos);
             if new_alt_loc == before_loc {
-                new_alt.err.label_with(self.label.clone());
+                if self.is_label {
+                    new_alt.err.label_with(self.label.clone());
+                }
             } else if self.is_context && new_alt_loc > before_loc {
                 // SAFETY: cursors generated by previous call to `InputRef::next` (or similar).
                 let span = unsafe { I::span(inp.cache, &before.cursor().inner..&new_alt.pos) };
@@ -1,9 +1,6 @@
 #![cfg_attr(not(any(doc, feature = "std", test)), no_std)]
 #![cfg_attr(docsrs, feature(doc_auto_cfg, doc_cfg), deny(rustdoc::all))]
-#![cfg_attr(
-    feature = "nightly",
-    feature(never_type, fn_traits, tuple_trait, unboxed_closures)
-)]
+#![cfg_attr(feature = "nightly", feature(never_type))]
 #![doc = include_str!("../README2.md")]
 #![doc = "## Examples"]
 #![doc = concat!("-", "[Brainfuck parser](", env!("CHUMSKY_REPO_URL"), "/examples/brainfuck.rs", ")\n\n")]
@@ -59,12 +56,16 @@ mod blanket;
 pub mod cache;
 pub mod combinator;
 pub mod container;
+pub mod dynamic;
 #[cfg(feature = "either")]
 mod either;
 pub mod error;
+#[cfg(feature = "excerpt")]
+pub mod excerpt;
 #[cfg(feature = "extension")]
 pub mod extension;
 pub mod extra;
+pub mod formats;
 #[cfg(docsrs)]
 pub mod guide;
 pub mod input;
@@ -81,10 +82,15 @@ pub mod recovery;
 pub mod recursive;
 #[cfg(feature = "regex")]
 pub mod regex;
+pub mod rules;
 pub mod span;
 mod stream;
+#[cfg(feature = "std")]
+pub mod test;
 pub mod text;
 pub mod util;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 /// Commonly used functions, traits and types.
 ///
@@ -100,9 +106,13 @@ pub mod prelude {
         extra,
         input::Input,
         primitive::{
-            any, any_ref, choice, custom, empty, end, group, just, map_ctx, none_of, one_of, todo,
+            all_of, any, any_ref, choice, current_column, custom, empty, end, filter_map, group,
+            just, lazy, map_ctx, nested, none_of, one_of, range, skip_while, todo, when_ctx,
+        },
+        recovery::{
+            insert_missing, nested_delimiters, skip_one, skip_then_retry_until, skip_to_sync,
+            skip_until, via_parser,
         },
-        recovery::{nested_delimiters, skip_then_retry_until, skip_until, via_parser},
         recursive::{recursive, Recursive},
         span::{SimpleSpan, Span as _},
         text, Boxed, ConfigIterParser, ConfigParser, IterParser, ParseResult, Parser,
@@ -110,16 +120,73 @@ pub mod prelude {
     pub use crate::{select, select_ref};
 }
 
+#[cfg(feature = "macros")]
+pub use chumsky_macros::{grammar, FromChars};
+
+// Items used by the code that `chumsky-macros` generates. Not part of the public API.
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub mod __private {
+    use super::*;
+
+    pub use alloc::vec::Vec;
+
+    pub fn item<'src, I: Input<'src>, E: ParserExtra<'src, I>, O, P: Parser<'src, I, O, E>>(
+        parser: P,
+    ) -> P {
+        parser
+    }
+
+    pub fn downgrade<'src, 'b, I: Input<'src>, O, E: ParserExtra<'src, I>>(
+        parser: &Recursive<recursive::Indirect<'src, 'b, I, O, E>>,
+    ) -> Recursive<recursive::Indirect<'src, 'b, I, O, E>> {
+        parser.downgrade()
+    }
+
+    /// Check whether `parser` matches at the current position, recording it as the best candidate if it matches more
+    /// input than the candidates before it. The input is left where it was.
+    pub fn try_longest<'src, 'parse, I, O, E, P>(
+        inp: &mut InputRef<'src, 'parse, I, E>,
+        parser: &P,
+        idx: usize,
+        best: &mut Option<(usize, usize)>,
+    ) where
+        I: Input<'src>,
+        E: ParserExtra<'src, I>,
+        P: Parser<'src, I, O, E>,
+    {
+        let before = inp.save();
+        if parser.go::<Check>(inp).is_ok() {
+            let end = I::cursor_location(&inp.cursor().inner);
+            match best {
+                Some((_, best_end)) if *best_end >= end => {}
+                _ => *best = Some((idx, end)),
+            }
+        }
+        inp.rewind(before);
+    }
+
+    /// The error to produce when none of the candidates given to [`try_longest`] matched.
+    pub fn longest_error<'src, 'parse, I, E>(inp: &mut InputRef<'src, 'parse, I, E>) -> E::Error
+    where
+        I: Input<'src>,
+        E: ParserExtra<'src, I>,
+    {
+        inp.take_alt()
+            .expect("failed candidates always produce an error")
+            .err
+    }
+}
+
 use crate::input::InputOwn;
+#[cfg(not(feature = "std"))]
+use alloc::vec;
 use alloc::{
     boxed::Box,
     rc::{self, Rc},
     string::String,
-    vec,
     vec::Vec,
 };
-#[cfg(feature = "nightly")]
-use core::marker::Tuple;
 use core::{
     borrow::Borrow,
     cell::{Cell, RefCell},
@@ -128,7 +195,7 @@ use core::{
     hash::Hash,
     marker::PhantomData,
     mem::MaybeUninit,
-    ops::{Range, RangeFrom},
+    ops::{Range, RangeFrom, RangeInclusive},
     panic::Location,
     str::FromStr,
 };
@@ -136,19 +203,20 @@ use hashbrown::HashMap;
 #[cfg(feature = "serde")]
 use serde::{de::Visitor, Deserialize, Deserializer, Serialize, Serializer};
 
+#[cfg(feature = "pratt")]
+use self::inspector::Inspector;
 #[cfg(feature = "label")]
 use self::label::{LabelError, Labelled};
 use self::{
     combinator::*,
     container::*,
-    error::Error,
+    error::{Error, Severity},
     extra::ParserExtra,
     input::{
         BorrowInput, Emitter, ExactSizeInput, InputRef, MapExtra, SliceInput, StrInput, ValueInput,
     },
-    inspector::Inspector,
     prelude::*,
-    primitive::Any,
+    primitive::{Any, End},
     private::{Check, Emit, IPResult, Located, MaybeUninitExt, Mode, PResult, Sealed},
     recovery::{RecoverWith, Strategy},
     span::Span,
@@ -194,15 +262,26 @@ pub(crate) type DynOperator<'src, 'b, I, O, E> = dyn pratt::Operator<'src, I, O,
 ///
 /// If you don't care for recovered outputs and you with to treat success/failure as a binary, you may use
 /// [`ParseResult::into_result`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ParseResult<T, E> {
     output: Option<T>,
-    errs: Vec<E>,
+    errs: Vec<(usize, E)>,
+    warnings: Vec<(Severity, E)>,
 }
 
 impl<T, E> ParseResult<T, E> {
-    pub(crate) fn new(output: Option<T>, errs: Vec<E>) -> ParseResult<T, E> {
-        ParseResult { output, errs }
+    pub(crate) fn new(output: Option<T>, errs: Vec<(usize, E)>) -> ParseResult<T, E> {
+        ParseResult {
+            output,
+            errs,
+            warnings: Vec::new(),
+        }
+    }
+
+    pub(crate) fn with_warnings(mut self, warnings: Vec<(Severity, E)>) -> Self {
+        self.warnings = warnings;
+        self
     }
 
     /// Whether this result contains output
@@ -223,7 +302,185 @@ impl<T, E> ParseResult<T, E> {
     /// Get an iterator over the parse errors for this result. The iterator will produce no items if there were no
     /// errors.
     pub fn errors(&self) -> impl ExactSizeIterator<Item = &E> + DoubleEndedIterator {
-        self.errs.iter()
+        self.errs.iter().map(|(_, err)| err)
+    }
+
+    /// Get an iterator over the parse errors for this result, along with the offset in the input at which each error
+    /// was generated.
+    ///
+    /// The offset is the [cursor location](Input::cursor_location) (i.e: the number of tokens or bytes into the input)
+    /// that the parser had reached when the error was produced. Unlike the span of an error, which may be widened or
+    /// relocated by combinators like [`Parser::map_err_with_state`] or [`Parser::labelled`], the offset always
+    /// reflects how far the parser actually got. This makes it useful for selecting the 'best' error to show the
+    /// user (for example, in a REPL).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let parser = just::<_, _, extra::Err<Rich<char>>>("let")
+    ///     .then(just(" x = 1"))
+    ///     .map_err_with_state(|e, span, _| Rich::custom(span, format!("invalid binding: {e}")));
+    ///
+    /// let res = parser.parse("let y = 1");
+    /// let (offset, err) = res.errors_with_offsets().next().unwrap();
+    /// // The span starts at the beginning of the binding...
+    /// assert_eq!(err.span().start, 0);
+    /// // ...but the offset records that the parser failed at the `y`
+    /// assert_eq!(offset, 4);
+    /// ```
+    pub fn errors_with_offsets(
+        &self,
+    ) -> impl ExactSizeIterator<Item = (usize, &E)> + DoubleEndedIterator {
+        self.errs.iter().map(|(offset, err)| (*offset, err))
+    }
+
+    /// Get an iterator over the non-fatal warnings and hints for this result, along with their [`Severity`].
+    ///
+    /// Warnings do not count as errors: they do not affect [`ParseResult::has_errors`] or
+    /// [`ParseResult::into_result`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::{prelude::*, error::Severity};
+    /// let parser = just::<_, _, extra::Err<Rich<char>>>("var")
+    ///     .validate(|kw, e, emitter| {
+    ///         emitter.warn(Rich::custom(e.span(), "`var` is deprecated"));
+    ///         kw
+    ///     });
+    ///
+    /// let res = parser.parse("var");
+    /// assert!(!res.has_errors());
+    /// let warnings = res.warnings().collect::<Vec<_>>();
+    /// assert_eq!(warnings.len(), 1);
+    /// assert_eq!(warnings[0].0, Severity::Warning);
+    /// assert_eq!(warnings[0].1.to_string(), "`var` is deprecated");
+    /// assert_eq!(res.into_result(), Ok("var"));
+    /// ```
+    pub fn warnings(&self) -> impl ExactSizeIterator<Item = (Severity, &E)> + DoubleEndedIterator {
+        self.warnings.iter().map(|(severity, err)| (*severity, err))
+    }
+
+    /// Convert this `ParseResult` into a vector containing any warnings and hints, along with their [`Severity`].
+    pub fn into_warnings(self) -> Vec<(Severity, E)> {
+        self.warnings
+    }
+
+    /// Remove errors that are identical to an earlier error, preserving the order of the remaining errors.
+    ///
+    /// Parsers that make heavy use of error recovery can often produce the same error several times over: this
+    /// method is useful for removing such duplicates before reporting errors to the user.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let parser = any::<_, extra::Err<Rich<char>>>()
+    ///     .validate(|c, e, emitter| {
+    ///         if c == '!' {
+    ///             // Oops, the same error gets emitted twice
+    ///             emitter.emit(Rich::custom(e.span(), "unexpected '!'"));
+    ///             emitter.emit(Rich::custom(e.span(), "unexpected '!'"));
+    ///         }
+    ///     })
+    ///     .repeated();
+    ///
+    /// assert_eq!(parser.parse("a!b!").errors().len(), 4);
+    /// assert_eq!(parser.parse("a!b!").dedup_errors().errors().len(), 2);
+    /// ```
+    pub fn dedup_errors(mut self) -> Self
+    where
+        E: PartialEq,
+    {
+        let mut i = 0;
+        while i < self.errs.len() {
+            if self.errs[..i].iter().any(|(_, err)| *err == self.errs[i].1) {
+                self.errs.remove(i);
+            } else {
+                i += 1;
+            }
+        }
+        self
+    }
+
+    /// Sort errors using the given key function, such as the start of each error's span.
+    ///
+    /// The sort is stable: errors with equal keys retain their original (emission) order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let parser = empty::<&str, extra::Err<Rich<char>>>().validate(|_, _, emitter| {
+    ///     emitter.emit(Rich::custom(SimpleSpan::new(4, 5), "second"));
+    ///     emitter.emit(Rich::custom(SimpleSpan::new(0, 1), "first"));
+    /// });
+    ///
+    /// let errs = parser.parse("").sort_errors_by_key(|e| e.span().start).into_errors();
+    /// assert_eq!(errs[0].to_string(), "first");
+    /// assert_eq!(errs[1].to_string(), "second");
+    /// ```
+    pub fn sort_errors_by_key<K: Ord>(mut self, mut f: impl FnMut(&E) -> K) -> Self {
+        self.errs.sort_by_key(|(_, err)| f(err));
+        self
+    }
+
+    /// Merge consecutive errors together using the given function.
+    ///
+    /// The function is given each pair of consecutive errors. It should return `Ok` with a single error if the two
+    /// errors should be merged (for example, because they have the same reason and overlapping spans), or `Err` with
+    /// both errors otherwise. This is commonly used after [`ParseResult::sort_errors_by_key`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::{prelude::*, error::Error};
+    /// let a = Rich::<char>::custom(SimpleSpan::new(0, 3), "bad thing");
+    /// let b = Rich::<char>::custom(SimpleSpan::new(2, 5), "bad thing");
+    /// let c = Rich::<char>::custom(SimpleSpan::new(8, 9), "bad thing");
+    ///
+    /// let parser = empty::<&str, extra::Err<Rich<char>>>().validate(move |_, _, emitter| {
+    ///     emitter.emit(c.clone());
+    ///     emitter.emit(b.clone());
+    ///     emitter.emit(a.clone());
+    /// });
+    ///
+    /// let errs = parser
+    ///     .parse("")
+    ///     .sort_errors_by_key(|e| e.span().start)
+    ///     .coalesce_errors(|a, b| {
+    ///         if a.reason() == b.reason() && b.span().start <= a.span().end {
+    ///             let span = a.span().union(*b.span());
+    ///             Ok(Rich::custom(span, "bad thing"))
+    ///         } else {
+    ///             Err((a, b))
+    ///         }
+    ///     })
+    ///     .into_errors();
+    ///
+    /// assert_eq!(errs.iter().map(|e| *e.span()).collect::<Vec<_>>(), vec![
+    ///     SimpleSpan::new(0, 5),
+    ///     SimpleSpan::new(8, 9),
+    /// ]);
+    /// ```
+    pub fn coalesce_errors(mut self, mut f: impl FnMut(E, E) -> Result<E, (E, E)>) -> Self {
+        let mut errs = Vec::with_capacity(self.errs.len());
+        for (offset, err) in self.errs.drain(..) {
+            match errs.pop() {
+                Some((last_offset, last)) => match f(last, err) {
+                    // The merged error is considered to have got as far as the furthest of the two
+                    Ok(merged) => errs.push((offset.max(last_offset), merged)),
+                    Err((last, err)) => {
+                        errs.push((last_offset, last));
+                        errs.push((offset, err));
+                    }
+                },
+                None => errs.push((offset, err)),
+            }
+        }
+        self.errs = errs;
+        self
     }
 
     /// Convert this `ParseResult` into an option containing the output, if any exists
@@ -234,22 +491,31 @@ impl<T, E> ParseResult<T, E> {
     /// Convert this `ParseResult` into a vector containing any errors. The vector will be empty if there were no
     /// errors.
     pub fn into_errors(self) -> Vec<E> {
+        self.errs.into_iter().map(|(_, err)| err).collect()
+    }
+
+    /// Convert this `ParseResult` into a vector containing any errors, along with the offset at which each was
+    /// generated. See [`ParseResult::errors_with_offsets`].
+    pub fn into_errors_with_offsets(self) -> Vec<(usize, E)> {
         self.errs
     }
 
     /// Convert this `ParseResult` into a tuple containing the output, if any existed, and errors, if any were
     /// encountered.
     pub fn into_output_errors(self) -> (Option<T>, Vec<E>) {
-        (self.output, self.errs)
+        (
+            self.output,
+            self.errs.into_iter().map(|(_, err)| err).collect(),
+        )
     }
 
     /// Convert this `ParseResult` into a standard `Result`. This discards output if parsing generated any errors,
     /// matching the old behavior of [`Parser::parse`].
     pub fn into_result(self) -> Result<T, Vec<E>> {
         if self.errs.is_empty() {
-            self.output.ok_or(self.errs)
+            self.output.ok_or_else(Vec::new)
         } else {
-            Err(self.errs)
+            Err(self.errs.into_iter().map(|(_, err)| err).collect())
         }
     }
 
@@ -265,7 +531,7 @@ impl<T, E> ParseResult<T, E> {
         if self.has_errors() {
             panic!(
                 "called `ParseResult::unwrap` on a parse result containing errors: {:?}",
-                &self.errs
+                self.errors().collect::<Vec<_>>()
             )
         } else {
             self.output.expect("parser generated no errors or output")
@@ -325,6 +591,18 @@ pub trait Parser<'src, I: Input<'src>, O, E: ParserExtra<'src, I> = extra::Defau
     #[doc(hidden)]
     fn go_check(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<Check, O>;
 
+    /// Returns `true` if this parser can be seen to fail at the current position by peeking at the next token alone,
+    /// having reported the same error that parsing would have. No input is consumed.
+    ///
+    /// Returning `false` means only that the parser must be run to find out. This is used by combinators like
+    /// [`Parser::separated_by`] to avoid creating a checkpoint for a separator that isn't there.
+    #[doc(hidden)]
+    #[inline(always)]
+    fn fails_on_peek(&self, inp: &mut InputRef<'src, '_, I, E>) -> bool {
+        #![allow(unused_variables)]
+        false
+    }
+
     /// Parse a stream of tokens, yielding an output if possible, and any errors encountered along the way.
     ///
     /// If `None` is returned (i.e: parsing failed) then there will *always* be at least one item in the error `Vec`.
@@ -347,29 +625,163 @@ pub trait Parser<'src, I: Input<'src>, O, E: ParserExtra<'src, I> = extra::Defau
     /// If `None` is returned (i.e: parsing failed) then there will *always* be at least one item in the error `Vec`.
     /// If you want to just use a default state value, use [`Parser::parse`] instead.
     ///
+    /// This is the recommended way to thread things like string interners or arena allocators through a parser, since
+    /// the state is still available once parsing has finished. To give part of a parser its own state instead, see
+    /// [`Parser::with_state`] and [`Parser::with_fresh_state`].
+    ///
     /// Although the signature of this function looks complicated, it's simpler than you think! You can pass a
     /// [`&[T]`], a [`&str`], [`Stream`], or anything implementing [`Input`] to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::{prelude::*, extra::SimpleState};
+    /// // A simple string interner
+    /// let ident = text::ascii::ident::<_, extra::Full<EmptyErr, SimpleState<Vec<&str>>, ()>>()
+    ///     .map_with(|name, e| {
+    ///         let names: &mut Vec<&str> = e.state();
+    ///         names.iter().position(|n| *n == name).unwrap_or_else(|| {
+    ///             names.push(name);
+    ///             names.len() - 1
+    ///         })
+    ///     })
+    ///     .padded()
+    ///     .repeated()
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let mut names = SimpleState(Vec::new());
+    /// assert_eq!(ident.parse_with_state("a b a c", &mut names).into_result(), Ok(vec![0, 1, 0, 2]));
+    /// assert_eq!(names.0, vec!["a", "b", "c"]);
+    /// ```
     fn parse_with_state(&self, input: I, state: &mut E::State) -> ParseResult<O, E::Error>
     where
         I: Input<'src>,
         E::Context: Default,
     {
-        let mut own = InputOwn::new_state(input, state);
-        let mut inp = own.as_ref_start();
-        let res = self.then_ignore(end()).go::<Emit>(&mut inp);
-        let alt = inp.take_alt().map(|alt| alt.err).unwrap_or_else(|| {
-            let fake_span = inp.span_since(&inp.cursor());
-            E::Error::expected_found([], None, fake_span)
-        });
-        let mut errs = own.into_errs();
-        let out = match res {
-            Ok(out) => Some(out),
-            Err(()) => {
-                errs.push(alt);
-                None
-            }
-        };
-        ParseResult::new(out, errs)
+        self.parse_with_state_and_ctx(input, state, E::Context::default())
+    }
+
+    /// Parse a stream of tokens with the given [context](extra::ParserExtra::Context), yielding an output if possible,
+    /// and any errors encountered along the way.
+    ///
+    /// Context is read-only configuration (such as a language edition or a set of enabled extensions) that parsers can
+    /// inspect via [`ConfigParser::configure`], [`MapExtra::ctx`] and similar. Unlike state, it cannot be changed by
+    /// the parser, but it can be overridden for part of a parser with [`Parser::with_ctx`].
+    ///
+    /// If you also want to include non-default state, use [`Parser::parse_with_state_and_ctx`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// #[derive(Clone, Copy, PartialEq)]
+    /// enum Edition { E2015, E2018 }
+    ///
+    /// // `async` is only a keyword from the 2018 edition onwards
+    /// let ident = text::ascii::ident::<_, extra::Context<Edition>>()
+    ///     .try_map_with(|name, e| match name {
+    ///         "async" if *e.ctx() == Edition::E2018 => Err(EmptyErr::default()),
+    ///         name => Ok(name),
+    ///     });
+    ///
+    /// assert_eq!(ident.parse_with_ctx("async", Edition::E2015).into_result(), Ok("async"));
+    /// assert!(ident.parse_with_ctx("async", Edition::E2018).has_errors());
+    ///
+    /// // Context can be overridden for part of a parser
+    /// let pair = ident
+    ///     .then_ignore(just(' '))
+    ///     .then(ident.with_ctx(Edition::E2015));
+    /// assert_eq!(
+    ///     pair.parse_with_ctx("foo async", Edition::E2018).into_result(),
+    ///     Ok(("foo", "async")),
+    /// );
+    /// ```
+    fn parse_with_ctx(&self, input: I, ctx: E::Context) -> ParseResult<O, E::Error>
+    where
+        I: Input<'src>,
+        E::State: Default,
+    {
+        self.parse_with_state_and_ctx(input, &mut E::State::default(), ctx)
+    }
+
+    /// Parse a stream of tokens with the given state and [context](extra::ParserExtra::Context), yielding an output if
+    /// possible, and any errors encountered along the way.
+    ///
+    /// See [`Parser::parse_with_state`] and [`Parser::parse_with_ctx`].
+    fn parse_with_state_and_ctx(
+        &self,
+        input: I,
+        state: &mut E::State,
+        ctx: E::Context,
+    ) -> ParseResult<O, E::Error>
+    where
+        I: Input<'src>,
+    {
+        InputOwn::new_state_ctx(input, state, ctx)
+            .run(|inp| (self.then_ignore(end()).go::<Emit>(inp), ()))
+            .0
+    }
+
+    /// Parse a prefix of the input, yielding an output if possible, any errors encountered along the way, and the rest
+    /// of the input that the parser did not consume.
+    ///
+    /// Unlike [`Parser::parse`], this does not require the parser to consume the whole input. This is useful when only
+    /// the start of the input is written in your grammar (such as a front-matter block at the top of a document), and
+    /// the rest should be handed to another parser, or to another library entirely.
+    ///
+    /// If the parser fails without recovering, nothing is considered to have been consumed and the remaining input is
+    /// the whole input. If you want to include non-default state, use [`Parser::parse_partial_with_state`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let front_matter = text::ascii::ident::<_, extra::Err<Simple<char>>>()
+    ///     .then_ignore(just(": "))
+    ///     .then(none_of('\n').repeated().to_slice())
+    ///     .then_ignore(just('\n'))
+    ///     .repeated()
+    ///     .collect::<Vec<_>>()
+    ///     .delimited_by(just("---\n"), just("---\n"));
+    ///
+    /// let (result, rest) = front_matter.parse_partial("---\ntitle: Hello\n---\n# Hello, world!\n");
+    /// assert_eq!(result.into_result(), Ok(vec![("title", "Hello")]));
+    /// assert_eq!(rest, "# Hello, world!\n");
+    /// ```
+    fn parse_partial(&self, input: I) -> (ParseResult<O, E::Error>, I::Slice)
+    where
+        I: SliceInput<'src>,
+        E::State: Default,
+        E::Context: Default,
+    {
+        self.parse_partial_with_state(input, &mut E::State::default())
+    }
+
+    /// Parse a prefix of the input with the given state, yielding an output if possible, any errors encountered along
+    /// the way, and the rest of the input that the parser did not consume.
+    ///
+    /// See [`Parser::parse_partial`] and [`Parser::parse_with_state`].
+    fn parse_partial_with_state(
+        &self,
+        input: I,
+        state: &mut E::State,
+    ) -> (ParseResult<O, E::Error>, I::Slice)
+    where
+        I: SliceInput<'src>,
+        E::Context: Default,
+    {
+        InputOwn::new_state(input, state).run(|inp| {
+            let start = inp.cursor();
+            let res = self.go_emit(inp);
+            let rest = match res {
+                Ok(_) => {
+                    let end = inp.cursor();
+                    inp.slice_from(&end..)
+                }
+                Err(()) => inp.slice_from(&start..),
+            };
+            (res, rest)
+        })
     }
 
     /// Parse a stream of tokens, ignoring any output, and returning any errors encountered along the way.
@@ -389,6 +801,27 @@ pub trait Parser<'src, I: Input<'src>, O, E: ParserExtra<'src, I> = extra::Defau
         self.check_with_state(input, &mut E::State::default())
     }
 
+    /// Parse a stream of tokens, filling any [holes](Parser::or_hole) in the output where parsing failed rather than
+    /// failing entirely.
+    ///
+    /// This is intended for tools such as IDEs, which want to make use of as much of a broken input as possible (to
+    /// provide an outline or completions, say). The errors that caused holes to be filled are returned alongside the
+    /// partial output. If the input is not entirely consumed, an error is produced, but the output is still returned.
+    ///
+    /// Parsers without holes behave exactly as they do with [`Parser::parse`].
+    fn parse_with_holes(&self, input: I) -> ParseResult<O, E::Error>
+    where
+        I: Input<'src>,
+        E::State: Default,
+        E::Context: Default,
+    {
+        let mut state = E::State::default();
+        let mut own = InputOwn::new_state(input, &mut state);
+        own.errors.holes = true;
+        own.run(|inp| (self.then_ignore(end().or_hole()).go::<Emit>(inp), ()))
+            .0
+    }
+
     /// Parse a stream of tokens, ignoring any output, and returning any errors encountered along the way.
     ///
     /// If parsing failed, then there will *always* be at least one item in the returned `Vec`.
@@ -402,26 +835,17 @@ pub trait Parser<'src, I: Input<'src>, O, E: ParserExtra<'src, I> = extra::Defau
         I: Input<'src>,
         E::Context: Default,
     {
-        let mut own = InputOwn::new_state(input, state);
-        let mut inp = own.as_ref_start();
-        let res = self.then_ignore(end()).go::<Check>(&mut inp);
-        let alt = inp.take_alt().map(|alt| alt.err).unwrap_or_else(|| {
-            let fake_span = inp.span_since(&inp.cursor());
-            E::Error::expected_found([], None, fake_span)
-        });
-        let mut errs = own.into_errs();
-        let out = match res {
-            Ok(()) => Some(()),
-            Err(()) => {
-                errs.push(alt);
-                None
-            }
-        };
-        ParseResult::new(out, errs)
+        InputOwn::new_state(input, state)
+            .run(|inp| (self.then_ignore(end()).go::<Check>(inp), ()))
+            .0
     }
 
     /// Convert the output of this parser into a slice of the input, based on the current parser's
     /// span.
+    ///
+    /// The output type of this parser is [`SliceInput::Slice`]. For in-memory inputs like [`&str`] and [`&[T]`], this
+    /// borrows from the input. Inputs that cannot lend out borrowed slices, such as [`Stream`](input::Stream),
+    /// reconstruct an owned buffer (a [`Vec`] of tokens) instead.
     fn to_slice(self) -> ToSlice<Self, O>
     where
         Self: Sized,
@@ -432,6 +856,52 @@ pub trait Parser<'src, I: Input<'src>, O, E: ParserExtra<'src, I> = extra::Defau
         }
     }
 
+    /// Intern the slice of the input that this parser matched, using the [`Interner`](inspector::Interner) held by
+    /// the parser state.
+    ///
+    /// This is a convenient way to turn identifiers into small, cheaply-comparable symbols without allocating a new
+    /// string for every occurrence. The slice is only interned when the output of this parser is needed.
+    ///
+    /// The output type of this parser is the interner's symbol type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::{prelude::*, inspector::{Interner, SimpleState}};
+    /// // A minimal interner built on top of a `Vec`
+    /// #[derive(Default)]
+    /// struct Names<'src>(Vec<&'src str>);
+    ///
+    /// impl<'src> Interner<&'src str> for Names<'src> {
+    ///     type Symbol = usize;
+    ///     fn intern(&mut self, name: &'src str) -> usize {
+    ///         self.0.iter().position(|n| *n == name).unwrap_or_else(|| {
+    ///             self.0.push(name);
+    ///             self.0.len() - 1
+    ///         })
+    ///     }
+    /// }
+    ///
+    /// let idents = text::ascii::ident::<_, extra::Full<EmptyErr, SimpleState<Names>, ()>>()
+    ///     .intern()
+    ///     .padded()
+    ///     .repeated()
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let mut names = SimpleState(Names::default());
+    /// assert_eq!(idents.parse_with_state("a b a c", &mut names).into_result(), Ok(vec![0, 1, 0, 2]));
+    /// assert_eq!(names.0 .0, vec!["a", "b", "c"]);
+    /// ```
+    fn intern(self) -> Intern<Self, O>
+    where
+        Self: Sized,
+    {
+        Intern {
+            parser: self,
+            phantom: EmptyPhantom::new(),
+        }
+    }
+
     /// Filter the output of this parser, accepting only inputs that match the given predicate.
     ///
     /// The output type of this parser is `I`, the input that was found.
@@ -593,9 +1063,58 @@ pub trait Parser<'src, I: Input<'src>, O, E: ParserExtra<'src, I> = extra::Defau
         }
     }
 
-    /// Map the output of this parser to another value.
+    /// Mark this parser as producing a node of the given kind, notifying the parser state when the node begins and
+    /// ends.
+    ///
+    /// The state must implement [`NodeInspector`](inspector::NodeInspector). The output of this parser is unchanged,
+    /// so nodes can be added to a grammar without affecting its output types. This is most useful with the
+    /// [`Events`](inspector::Events) inspector, which records a stream of node and token events that can be used for
+    /// syntax highlighting or the construction of a concrete syntax tree.
+    ///
+    /// The output type of this parser is `O`, the same as the original parser.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::{prelude::*, inspector::{Event, Events}};
+    /// type Extra = extra::Full<EmptyErr, Events<&'static str, char, SimpleSpan>, ()>;
+    ///
+    /// let ident = text::ascii::ident::<_, Extra>().node("ident");
+    /// let idents = ident.padded().repeated().collect::<Vec<_>>();
+    ///
+    /// let mut events = Events::default();
+    /// assert_eq!(idents.parse_with_state("a bc", &mut events).into_result(), Ok(vec!["a", "bc"]));
+    ///
+    /// let spans = events
+    ///     .into_events()
+    ///     .into_iter()
+    ///     .filter_map(|event| match event {
+    ///         Event::Exit(kind, span) => Some((kind, span)),
+    ///         _ => None,
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(spans, vec![("ident", SimpleSpan::new(0, 1)), ("ident", SimpleSpan::new(2, 4))]);
+    /// ```
+    fn node<K>(self, kind: K) -> Node<Self, K>
+    where
+        Self: Sized,
+        E::State: inspector::NodeInspector<'src, I, K>,
+    {
+        Node { parser: self, kind }
+    }
+
+    /// Map the output of this parser to another value, passing the elements of its output tuple to the function as
+    /// separate arguments.
     /// If the output of this parser isn't a tuple, use [`Parser::map`].
     ///
+    /// Both flat tuples (like those produced by [`group`]) and the nested tuples produced by chains of
+    /// [`Parser::then`] (like `((a, b), c)`) are supported, so there's no need to destructure deeply nested tuples by
+    /// hand. The nesting is determined by the number of arguments the function takes: a function taking two arguments
+    /// will receive `(a, b)` and `c`, while a function taking three will receive `a`, `b` and `c`.
+    ///
+    /// Because the number of arguments picks between several implementations of [`GroupFn`], the types of a closure's
+    /// arguments are sometimes not known early enough to call methods on them. In that case, annotate them.
+    ///
     /// The output type of this parser is `U`, the same as the function's output.
     ///
     /// # Examples
@@ -632,12 +1151,20 @@ pub trait Parser<'src, I: Input<'src>, O, E: ParserExtra<'src, I> = extra::Defau
     ///         Value::Two(21, 69)
     ///     ])
     /// );
+    ///
+    /// // Nested tuples from `then` chains are flattened too
+    /// let assign = text::ascii::ident::<_, extra::Err<Simple<char>>>()
+    ///     .then_ignore(just('=').padded())
+    ///     .then(text::int(10))
+    ///     .then_ignore(just(':').padded())
+    ///     .then(text::ascii::ident())
+    ///     .map_group(|name: &str, value, ty| format!("{name}: {ty} = {value}"));
+    ///
+    /// assert_eq!(assign.parse("x = 42 : int").into_result(), Ok("x: int = 42".to_string()));
     /// ```
-    #[cfg(feature = "nightly")]
-    fn map_group<F: Fn<O>>(self, f: F) -> MapGroup<Self, O, F>
+    fn map_group<U, F: GroupFn<O, U, M>, M>(self, f: F) -> MapGroup<Self, O, F, M>
     where
         Self: Sized,
-        O: Tuple,
     {
         MapGroup {
             parser: self,
@@ -697,6 +1224,37 @@ pub trait Parser<'src, I: Input<'src>, O, E: ParserExtra<'src, I> = extra::Defau
         }
     }
 
+    /// After a successful parse, apply a function to the output that may reject it by returning `None`, in which case
+    /// an error is produced that spans the rejected input and reports its first token as having been found.
+    ///
+    /// This is a convenient alternative to [`Parser::try_map`] for the common case where the specific error produced
+    /// doesn't matter. To reject individual tokens, see the [`filter_map`](primitive::filter_map) primitive.
+    ///
+    /// The output type of this parser is `U`, the [`Some`] return value of the function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let byte = text::int::<_, extra::Err<Rich<char>>>(10)
+    ///     .filter_map(|s: &str| s.parse::<u8>().ok());
+    ///
+    /// assert_eq!(byte.parse("255").into_result(), Ok(255));
+    /// let errs = byte.parse("256").into_errors();
+    /// assert_eq!(errs[0].span(), &(0..3).into());
+    /// assert_eq!(errs[0].found(), Some(&'2'));
+    /// ```
+    fn filter_map<U, F: Fn(O) -> Option<U>>(self, f: F) -> FilterMap<Self, O, F>
+    where
+        Self: Sized,
+    {
+        FilterMap {
+            parser: self,
+            mapper: f,
+            phantom: EmptyPhantom::new(),
+        }
+    }
+
     /// After a successful parse, apply a fallible function to the output. If the function produces an error, treat it
     /// as a parsing error.
     ///
@@ -717,7 +1275,6 @@ pub trait Parser<'src, I: Input<'src>, O, E: ParserExtra<'src, I> = extra::Defau
     /// assert!(byte.parse("255").has_output());
     /// assert!(byte.parse("256").has_errors()); // Out of range
     /// ```
-    #[doc(alias = "filter_map")]
     fn try_map<U, F: Fn(O, I::Span) -> Result<U, E::Error>>(self, f: F) -> TryMap<Self, O, F>
     where
         Self: Sized,
@@ -757,6 +1314,10 @@ pub trait Parser<'src, I: Input<'src>, O, E: ParserExtra<'src, I> = extra::Defau
     /// [do not allocate](https://doc.rust-lang.org/std/vec/struct.Vec.html#guarantees)). For example, it's common to
     /// want to ignore whitespace in many grammars (see [`text::whitespace`]).
     ///
+    /// The original parser is run without generating any output at all: collections within it are not built and
+    /// functions passed to combinators like [`Parser::map`] are not called. The same is true of [`Parser::to`], so
+    /// patterns like `a.then(b).to(x)` and `a.map(f).ignored()` cost no more than recognising the input.
+    ///
     /// The output type of this parser is `()`.
     ///
     /// # Examples
@@ -801,19 +1362,56 @@ pub trait Parser<'src, I: Input<'src>, O, E: ParserExtra<'src, I> = extra::Defau
         Memoized { parser: self }
     }
 
-    /// Transform all outputs of this parser to a predetermined value.
+    /// Memoize the parser like [`Parser::memoized`], but keyed by a value derived from the current
+    /// [context](extra::ParserExtra::Context) and [state](extra::ParserExtra::State) as well as the input position.
     ///
-    /// The output type of this parser is `U`, the type of the predetermined value.
+    /// In context-sensitive grammars, the same parser at the same position may succeed under one context (such as an
+    /// indentation level or a language edition) and fail under another. [`Parser::memoized`] would wrongly reuse the
+    /// result of the first attempt, but here each distinct key gets its own entry. Keys can be of any type that
+    /// implements [`Hash`] and [`Eq`], and the key function must return distinct keys whenever the parser could behave
+    /// differently.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use chumsky::{prelude::*, error::Simple};
-    /// #[derive(Clone, Debug, PartialEq)]
-    /// enum Op { Add, Sub, Mul, Div }
-    ///
-    /// let op = just::<_, _, extra::Err<Simple<char>>>('+').to(Op::Add)
-    ///     .or(just('-').to(Op::Sub))
+    /// # use chumsky::prelude::*;
+    /// // A line indented by exactly the number of spaces given by the context
+    /// let line = just::<_, _, extra::Full<EmptyErr, (), usize>>(' ')
+    ///     .repeated()
+    ///     .configure(|cfg, indent: &usize| cfg.exactly(*indent))
+    ///     .ignore_then(text::ascii::ident())
+    ///     .memoize_by_key(|indent: &usize, _| *indent);
+    ///
+    /// // Both alternatives share the same memoized parser
+    /// let block: Boxed<&str, &str, extra::Err<EmptyErr>> =
+    ///     choice(((&line).with_ctx(4), (&line).with_ctx(2))).boxed();
+    /// assert_eq!(block.parse("  foo").into_result(), Ok("foo"));
+    /// ```
+    #[cfg(feature = "memoization")]
+    fn memoize_by_key<F, K>(self, key: F) -> MemoizeByKey<Self, F>
+    where
+        Self: Sized,
+        F: Fn(&E::Context, &E::State) -> K,
+        K: Hash + Eq + 'static,
+    {
+        MemoizeByKey { parser: self, key }
+    }
+
+    /// Transform all outputs of this parser to a predetermined value.
+    ///
+    /// As with [`Parser::ignored`], the original parser is run without generating its output.
+    ///
+    /// The output type of this parser is `U`, the type of the predetermined value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::{prelude::*, error::Simple};
+    /// #[derive(Clone, Debug, PartialEq)]
+    /// enum Op { Add, Sub, Mul, Div }
+    ///
+    /// let op = just::<_, _, extra::Err<Simple<char>>>('+').to(Op::Add)
+    ///     .or(just('-').to(Op::Sub))
     ///     .or(just('*').to(Op::Mul))
     ///     .or(just('/').to(Op::Div));
     ///
@@ -846,10 +1444,57 @@ pub trait Parser<'src, I: Input<'src>, O, E: ParserExtra<'src, I> = extra::Defau
         Labelled {
             parser: self,
             label,
+            is_label: true,
             is_context: false,
         }
     }
 
+    /// Annotate errors that occur within this parser with the given label as context, without otherwise changing
+    /// them.
+    ///
+    /// Contexts form a stack (similar to a backtrace) that can be retrieved from [`Rich`] errors with
+    /// [`Rich::contexts`], allowing diagnostics to explain what was being parsed when an error occurred ("while parsing
+    /// function argument list"). Errors that occur before this parser has consumed any input are not annotated, since
+    /// the context was never really entered.
+    ///
+    /// Unlike [`Parser::labelled`], this does not replace the expected patterns of errors with the label: use
+    /// `.labelled(label).as_context()` to do both.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let args = text::int::<_, extra::Err<Rich<char>>>(10)
+    ///     .separated_by(just(','))
+    ///     .collect::<Vec<_>>()
+    ///     .delimited_by(just('('), just(')'))
+    ///     .in_context("function argument list");
+    /// let call = text::ascii::ident()
+    ///     .then(args)
+    ///     .in_context("function call");
+    ///
+    /// let errs = call.parse("foo(1,x)").into_errors();
+    /// assert_eq!(
+    ///     errs[0].contexts().map(|(l, s)| (*l, s.into_range())).collect::<Vec<_>>(),
+    ///     vec![("function argument list", 3..6), ("function call", 0..6)],
+    /// );
+    /// // The expected patterns are left alone
+    /// assert_eq!(errs[0].expected().count(), 1);
+    /// ```
+    #[cfg(feature = "label")]
+    fn in_context<L>(self, label: L) -> Labelled<Self, L>
+    where
+        Self: Sized,
+        E::Error: LabelError<'src, I, L>,
+    {
+        Labelled {
+            parser: self,
+            label,
+            is_label: false,
+            is_context: true,
+        }
+    }
+
     /// Parse one thing and then another thing, yielding a tuple of the two outputs.
     ///
     /// The output type of this parser is `(O, U)`, a combination of the outputs of both parsers.
@@ -962,6 +1607,31 @@ pub trait Parser<'src, I: Input<'src>, O, E: ParserExtra<'src, I> = extra::Defau
         }
     }
 
+    /// Parse one thing and then require that the end of the input has been reached, yielding the output of the
+    /// former.
+    ///
+    /// This is sugar for [`.then_ignore(end())`](Self::then_ignore), and is commonly used on top-level parsers to
+    /// ensure that all input is consumed.
+    ///
+    /// The output type of this parser is `O`, the same as the original parser.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::{prelude::*, error::Simple};
+    /// let digits = text::digits::<_, extra::Err<Simple<char>>>(10).to_slice();
+    ///
+    /// assert_eq!(digits.then_end().parse("1234").into_result(), Ok("1234"));
+    /// // Trailing input is an error
+    /// assert!(digits.then_end().parse("1234abc").has_errors());
+    /// ```
+    fn then_end(self) -> ThenIgnore<Self, End<I, E>, (), E>
+    where
+        Self: Sized,
+    {
+        self.then_ignore(end())
+    }
+
     /// Parse input as part of a token-tree - using an input generated from within the current
     /// input. In other words, this parser will attempt to create a *new* input stream from within
     /// the one it is being run on, and the parser it was called on will be provided this *new* input.
@@ -1118,7 +1788,35 @@ pub trait Parser<'src, I: Input<'src>, O, E: ParserExtra<'src, I> = extra::Defau
         WithCtx { parser: self, ctx }
     }
 
-    /// TODO
+    /// Run this parser with its own state, independent of the state of the surrounding parser.
+    ///
+    /// Each time this parser is invoked, it is given a clone of the provided state. Any changes made to that state
+    /// are discarded when the parser finishes, so the state of the parser can only be observed via its output. If
+    /// cloning the state is expensive or not possible, see [`Parser::with_fresh_state`].
+    ///
+    /// For state that should persist across an entire parse (such as a string interner or an arena allocator), use
+    /// [`Parser::parse_with_state`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::{prelude::*, extra::SimpleState};
+    /// // Count the items in each list separately, starting from zero each time
+    /// let count = any::<_, extra::Full<EmptyErr, SimpleState<usize>, ()>>()
+    ///     .filter(char::is_ascii_alphabetic)
+    ///     .map_with(|_, e| **e.state() += 1)
+    ///     .separated_by(just(','))
+    ///     .collect::<()>()
+    ///     .map_with(|_, e| **e.state());
+    ///
+    /// let lists = count
+    ///     .with_state(SimpleState(0))
+    ///     .delimited_by(just::<_, _, extra::Default>('['), just(']'))
+    ///     .repeated()
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(lists.parse("[a,b,c][d][]").into_result(), Ok(vec![3, 1, 0]));
+    /// ```
     fn with_state<State>(self, state: State) -> WithState<Self, State>
     where
         Self: Sized,
@@ -1130,6 +1828,49 @@ pub trait Parser<'src, I: Input<'src>, O, E: ParserExtra<'src, I> = extra::Defau
         }
     }
 
+    /// Run this parser with its own state, created by calling the given function each time the parser is invoked.
+    ///
+    /// This is like [`Parser::with_state`], but does not require the state to be [`Clone`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::{prelude::*, extra::SimpleState};
+    /// use std::collections::HashSet;
+    ///
+    /// // Each block may not declare the same name twice, but names may be reused in different blocks
+    /// let decl = text::ascii::ident::<_, extra::Full<Rich<char>, SimpleState<HashSet<&str>>, ()>>()
+    ///     .validate(|name, e, emitter| {
+    ///         if !e.state().insert(name) {
+    ///             emitter.emit(Rich::custom(e.span(), format!("'{name}' declared twice")));
+    ///         }
+    ///         name
+    ///     })
+    ///     .padded()
+    ///     .repeated()
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let blocks = decl
+    ///     .with_fresh_state(|| SimpleState(HashSet::new()))
+    ///     .delimited_by(just::<_, _, extra::Err<Rich<char>>>('{'), just('}'))
+    ///     .repeated()
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert!(!blocks.parse("{a b}{a b}").has_errors());
+    /// assert!(blocks.parse("{a b a}").has_errors());
+    /// ```
+    fn with_fresh_state<State, F>(self, make_state: F) -> WithFreshState<Self, F>
+    where
+        Self: Sized,
+        F: Fn() -> State,
+        State: 'src,
+    {
+        WithFreshState {
+            parser: self,
+            make_state,
+        }
+    }
+
     /// Applies both parsers to the same position in the input, succeeding
     /// only if both succeed. The returned value will be that of the first parser,
     /// and the input will be at the end of the first parser if `and_is` succeeds.
@@ -1140,6 +1881,8 @@ pub trait Parser<'src, I: Input<'src>, O, E: ParserExtra<'src, I> = extra::Defau
     /// The motivating use-case is in combination with [`Parser::not`], allowing a parser
     /// to consume something only if it isn't also something like an escape sequence or a nested block.
     ///
+    /// To apply several constraints to the same input, see [`all_of`].
+    ///
     /// # Examples
     ///
     /// ```
@@ -1176,6 +1919,42 @@ pub trait Parser<'src, I: Input<'src>, O, E: ParserExtra<'src, I> = extra::Defau
         }
     }
 
+    /// Parse the pattern, but fail if the given parser matches exactly the same input. This is set subtraction for
+    /// grammars: "identifiers, except keywords".
+    ///
+    /// Unlike `self.and_is(other.not())`, inputs are only excluded if `other` consumes the whole of the input that
+    /// this parser consumed, so excluding the keyword `let` from identifiers does not also exclude `letter`. The
+    /// resulting error covers the excluded input; use [`Except::with_message`] to describe why it was rejected.
+    ///
+    /// The output type of this parser is `O`, the same as the original parser.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let ident = text::ascii::ident::<_, extra::Err<Rich<char>>>()
+    ///     .except(choice((just("let"), just("fn"))))
+    ///     .with_message("reserved word used as identifier");
+    ///
+    /// assert_eq!(ident.parse("letter").into_result(), Ok("letter"));
+    ///
+    /// let errs = ident.parse("let").into_errors();
+    /// assert_eq!(errs[0].to_string(), "reserved word used as identifier");
+    /// assert_eq!(errs[0].span(), &SimpleSpan::from(0..3));
+    /// ```
+    fn except<U, B>(self, other: B) -> Except<Self, B, U>
+    where
+        Self: Sized,
+        B: Parser<'src, I, U, E>,
+    {
+        Except {
+            parser_a: self,
+            parser_b: other,
+            message: None,
+            phantom: EmptyPhantom::new(),
+        }
+    }
+
     /// Parse the pattern surrounded by the given delimiters.
     ///
     /// The output type of this parser is `O`, the same as the original parser.
@@ -1234,6 +2013,7 @@ pub trait Parser<'src, I: Input<'src>, O, E: ParserExtra<'src, I> = extra::Defau
             parser: self,
             start,
             end,
+            report_unclosed: false,
             phantom: EmptyPhantom::new(),
         }
     }
@@ -1262,6 +2042,105 @@ pub trait Parser<'src, I: Input<'src>, O, E: ParserExtra<'src, I> = extra::Defau
         PaddedBy {
             parser: self,
             padding,
+            leading: true,
+            trailing: true,
+            phantom: EmptyPhantom::new(),
+        }
+    }
+
+    /// Parse a pattern, but with an instance of another pattern before it, yielding the output of the pattern.
+    ///
+    /// Together with [`Parser::padded_by_trailing`], this allows different padding to be accepted on either side of
+    /// a pattern.
+    ///
+    /// The output type of this parser is `O`, the same as the original parser.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::{prelude::*, error::Simple};
+    /// let comment = just("//")
+    ///     .then(any().and_is(just('\n').not()).repeated())
+    ///     .then(just('\n'));
+    /// // Comments may appear before a statement, but the newline after it is significant
+    /// let stmt = text::ascii::ident::<_, extra::Err<Simple<char>>>()
+    ///     .padded_by_leading(comment.repeated())
+    ///     .padded_by_trailing(just(' ').repeated())
+    ///     .then_ignore(just('\n'));
+    ///
+    /// assert_eq!(stmt.parse("// Hello!\nfoo  \n").into_result(), Ok("foo"));
+    /// assert_eq!(stmt.parse("foo\n").into_result(), Ok("foo"));
+    /// assert!(stmt.parse("foo\n// Goodbye!\n").has_errors());
+    /// ```
+    fn padded_by_leading<U, B>(self, padding: B) -> PaddedBy<Self, B, U>
+    where
+        Self: Sized,
+        B: Parser<'src, I, U, E>,
+    {
+        PaddedBy {
+            parser: self,
+            padding,
+            leading: true,
+            trailing: false,
+            phantom: EmptyPhantom::new(),
+        }
+    }
+
+    /// Parse a pattern, but with an instance of another pattern after it, yielding the output of the pattern.
+    ///
+    /// See [`Parser::padded_by_leading`] for an example.
+    ///
+    /// The output type of this parser is `O`, the same as the original parser.
+    fn padded_by_trailing<U, B>(self, padding: B) -> PaddedBy<Self, B, U>
+    where
+        Self: Sized,
+        B: Parser<'src, I, U, E>,
+    {
+        PaddedBy {
+            parser: self,
+            padding,
+            leading: false,
+            trailing: true,
+            phantom: EmptyPhantom::new(),
+        }
+    }
+
+    /// Parse a pattern as a lexeme, ignoring any trivia (comments, whitespace, etc.) that follows it.
+    ///
+    /// Consistently consuming trivia *after* each token (and once at the start of the input) means that every token
+    /// parser can assume that it begins at the start of a meaningful token, avoiding the need to sprinkle
+    /// [`Parser::padded`] throughout a grammar. If the trivia is plain whitespace, see [`Parser::lexeme`].
+    ///
+    /// The trivia parser is expected to consume *all* trivia at that position (for example, by being
+    /// [`repeated`](Parser::repeated)) and must succeed when no trivia is present.
+    ///
+    /// The output type of this parser is `O`, the same as the original parser.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::{prelude::*, error::Simple};
+    /// let comment = just::<_, _, extra::Err<Simple<char>>>("//")
+    ///     .then(any().and_is(just('\n').not()).repeated())
+    ///     .ignored();
+    /// let trivia = comment.padded().repeated().then(text::whitespace());
+    ///
+    /// let ident = text::ascii::ident().lexeme_with(trivia);
+    /// let idents = ident.repeated().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     idents.parse("foo // a comment\nbar\n// another\nbaz ").into_result(),
+    ///     Ok(vec!["foo", "bar", "baz"]),
+    /// );
+    /// ```
+    fn lexeme_with<U, B>(self, trivia: B) -> LexemeWith<Self, B, U>
+    where
+        Self: Sized,
+        B: Parser<'src, I, U, E>,
+    {
+        LexemeWith {
+            parser: self,
+            trivia,
             phantom: EmptyPhantom::new(),
         }
     }
@@ -1281,6 +2160,9 @@ pub trait Parser<'src, I: Input<'src>, O, E: ParserExtra<'src, I> = extra::Defau
     /// Please note that long chains of [`Parser::or`] combinators have been known to result in poor compilation times.
     /// If you feel you are experiencing this, consider using [`choice`] instead.
     ///
+    /// By default, the second parser is attempted even if the first parser consumed input before failing. See
+    /// [`Or::committed`] for an alternative that does not backtrack once the first parser has made progress.
+    ///
     /// The output type of this parser is `O`, the output of both parsers.
     ///
     /// # Examples
@@ -1303,6 +2185,9 @@ pub trait Parser<'src, I: Input<'src>, O, E: ParserExtra<'src, I> = extra::Defau
     {
         Or {
             choice: choice((self, other)),
+            committed: false,
+            #[cfg(debug_assertions)]
+            check_ambiguity: None,
         }
     }
 
@@ -1334,6 +2219,122 @@ pub trait Parser<'src, I: Input<'src>, O, E: ParserExtra<'src, I> = extra::Defau
         OrNot { parser: self }
     }
 
+    /// Attempt to parse something, producing the default value of the output type if it does not exist.
+    ///
+    /// If parsing of the pattern fails, no input is consumed and the output is [`Default::default`]. This is
+    /// equivalent to `parser.or_not().map(Option::unwrap_or_default)`.
+    ///
+    /// The output type of this parser is `O`, the same as the original parser.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// // Indentation is optional, with no indentation meaning a depth of zero
+    /// let indent = just::<_, _, extra::Err<Simple<char>>>(' ')
+    ///     .repeated()
+    ///     .at_least(1)
+    ///     .count()
+    ///     .or_default();
+    /// let line = indent.then(text::ascii::ident());
+    ///
+    /// assert_eq!(line.parse("   foo").into_result(), Ok((3, "foo")));
+    /// assert_eq!(line.parse("foo").into_result(), Ok((0, "foo")));
+    /// ```
+    fn or_default(self) -> OrDefault<Self>
+    where
+        Self: Sized,
+        O: Default,
+    {
+        OrDefault { parser: self }
+    }
+
+    /// Attempt to parse something, producing a fallback value from the error that was generated if it does not exist.
+    ///
+    /// If parsing of the pattern fails, no input is consumed and the output is the result of calling `f` with the
+    /// error that the pattern produced. The error is not emitted: this parser always succeeds.
+    ///
+    /// The output type of this parser is `O`, the same as the original parser.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let sign = just::<_, _, extra::Err<Rich<char>>>('-')
+    ///     .to(-1)
+    ///     .or(just('+').to(1))
+    ///     // No sign means positive
+    ///     .or_else_with(|_| 1);
+    /// let num = sign.then(text::int(10).from_str::<i32>().unwrapped());
+    ///
+    /// assert_eq!(num.parse("-5").into_result(), Ok((-1, 5)));
+    /// assert_eq!(num.parse("5").into_result(), Ok((1, 5)));
+    /// ```
+    fn or_else_with<F>(self, f: F) -> OrElseWith<Self, F>
+    where
+        Self: Sized,
+        F: Fn(&E::Error) -> O,
+    {
+        OrElseWith {
+            parser: self,
+            fallback: f,
+        }
+    }
+
+    /// Mark a part of a pattern that may be left as a hole when parsing a broken input with
+    /// [`Parser::parse_with_holes`].
+    ///
+    /// When parsing with [`Parser::parse_with_holes`], if the pattern fails then no input is consumed, its error is
+    /// emitted, and the output is `None`: the parts of a larger pattern that *did* parse are kept, producing a
+    /// best-effort output with holes in it. When parsing normally, failure is reported as usual and the output is
+    /// always `Some(_)`.
+    ///
+    /// Holes should usually appear after the point at which a pattern commits to a particular interpretation of the
+    /// input (such as after a keyword), since a pattern containing holes is able to succeed wherever its non-hole
+    /// prefix does, and so will no longer fall back to alternatives given by [`Parser::or`] or [`choice`].
+    ///
+    /// The output type of this parser is `Option<O>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// #[derive(Debug, PartialEq)]
+    /// struct Func<'src> {
+    ///     name: Option<&'src str>,
+    ///     params: Option<Vec<&'src str>>,
+    /// }
+    ///
+    /// let ident = text::ascii::ident::<_, extra::Err<Rich<char>>>().padded();
+    /// let func = text::ascii::keyword("fn")
+    ///     .padded()
+    ///     .ignore_then(ident.or_hole())
+    ///     .then(
+    ///         ident
+    ///             .separated_by(just(','))
+    ///             .collect()
+    ///             .delimited_by(just('('), just(')'))
+    ///             .or_hole(),
+    ///     )
+    ///     .map(|(name, params)| Func { name, params });
+    ///
+    /// // Normal parsing treats holes as failures...
+    /// assert!(func.parse("fn (a, b)").has_errors());
+    /// // ...but parsing with holes produces as much output as possible
+    /// let (out, errs) = func.parse_with_holes("fn (a, b)").into_output_errors();
+    /// assert_eq!(out, Some(Func { name: None, params: Some(vec!["a", "b"]) }));
+    /// assert_eq!(errs.len(), 1);
+    ///
+    /// let (out, _) = func.parse_with_holes("fn foo(a b)").into_output_errors();
+    /// assert_eq!(out, Some(Func { name: Some("foo"), params: None }));
+    /// ```
+    fn or_hole(self) -> OrHole<Self>
+    where
+        Self: Sized,
+    {
+        OrHole { parser: self }
+    }
+
     /// Invert the result of the contained parser, failing if it succeeds and succeeding if it fails.
     /// The output of this parser is always `()`, the unit type.
     ///
@@ -1408,6 +2409,11 @@ pub trait Parser<'src, I: Input<'src>, O, E: ParserExtra<'src, I> = extra::Defau
     /// Input is eagerly parsed. Be aware that the parser will accept no occurrences of the pattern too. Consider using
     /// [`Repeated::at_least`] instead if you wish to parse a minimum number of elements.
     ///
+    /// Repeating a pattern that can succeed without consuming input (such as `text::whitespace()` or an `or_not`)
+    /// without an upper bound would never terminate. In debug builds, this panics with the location of the offending
+    /// `repeated`. In release builds, the repetition fails with an error instead of hanging. The same applies to
+    /// [`Parser::separated_by`], [`Parser::repeated_till`], and the folds and collections built on top of them.
+    ///
     /// The output type of this parser is, by default, `()`. If you want to collect the items into a [`Container`]
     /// (such as a [`Vec`]), use [`IterParser::collect`].
     ///
@@ -1443,51 +2449,175 @@ pub trait Parser<'src, I: Input<'src>, O, E: ParserExtra<'src, I> = extra::Defau
         }
     }
 
-    /// Parse a pattern, separated by another, any number of times.
+    /// Parse a pattern any number of times, until a terminator is found.
     ///
-    /// You can use [`SeparatedBy::allow_leading`] or [`SeparatedBy::allow_trailing`] to allow leading or trailing
-    /// separators.
+    /// Before each item, the terminator is tried: if it matches, parsing stops. This means that, unlike
+    /// `.repeated().then(terminator)`, it doesn't matter if the pattern could also match the terminator (such as when
+    /// parsing the contents of a comment with `any()`). If neither the terminator nor the pattern match, the error
+    /// reports both as being expected.
     ///
-    /// The output type of this parser can be any [`Container`].
+    /// The output type of this parser is `(Vec<O>, OB)`: the items and the output of the terminator. Use
+    /// [`RepeatedTill::collect`] to collect the items into a different [`Container`], and
+    /// [`RepeatedTill::consume_terminator`] to leave the terminator in the input.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use chumsky::{prelude::*, error::Simple};
-    /// let shopping = text::ascii::ident::<_, extra::Err<Simple<char>>>()
-    ///     .padded()
-    ///     .separated_by(just(','))
-    ///     .collect::<Vec<_>>();
+    /// # use chumsky::prelude::*;
+    /// let comment = just::<_, _, extra::Err<Simple<char>>>("/*")
+    ///     .ignore_then(any().repeated_till(just("*/")))
+    ///     .map(|(chars, _)| chars.into_iter().collect::<String>());
     ///
-    /// assert_eq!(shopping.parse("eggs").into_result(), Ok(vec!["eggs"]));
-    /// assert_eq!(shopping.parse("eggs, flour, milk").into_result(), Ok(vec!["eggs", "flour", "milk"]));
+    /// assert_eq!(comment.parse("/* Don't * Panic */").into_result(), Ok(" Don't * Panic ".to_string()));
+    /// assert!(comment.parse("/* unterminated").has_errors());
     /// ```
-    ///
-    /// See [`SeparatedBy::allow_leading`] and [`SeparatedBy::allow_trailing`] for more examples.
     #[cfg_attr(debug_assertions, track_caller)]
-    fn separated_by<U, B>(self, separator: B) -> SeparatedBy<Self, B, O, U, I, E>
+    fn repeated_till<B, OB>(self, terminator: B) -> RepeatedTill<Self, B, O, OB, Vec<O>, I, E>
     where
         Self: Sized,
-        B: Parser<'src, I, U, E>,
+        B: Parser<'src, I, OB, E>,
     {
-        SeparatedBy {
+        RepeatedTill {
             parser: self,
-            separator,
-            at_least: 0,
-            at_most: !0,
-            allow_leading: false,
-            allow_trailing: false,
+            terminator,
+            consume_terminator: true,
             #[cfg(debug_assertions)]
             location: *Location::caller(),
             phantom: EmptyPhantom::new(),
         }
     }
 
-    /// Left-fold the output of the parser into a single value.
+    /// Parse a pattern as few times as possible, stopping as soon as another pattern can match (like regex's `*?`).
     ///
-    /// The output of the original parser must be of type `(A, impl IntoIterator<Item = B>)`.
+    /// Before each item, the following pattern is tried: if it matches, repetition stops. The following pattern is only
+    /// peeked at and is left in the input for the next parser, so this is the non-greedy counterpart of
+    /// [`Parser::repeated`], useful for patterns like "everything up to the first `-->`". If neither the following
+    /// pattern nor the item match, the error reports both as being expected. Use [`LazyRepeated::at_least`] to require a
+    /// minimum number of items.
     ///
-    /// The output type of this parser is `A`, the left-hand component of the original parser's output.
+    /// The output type of this parser is, by default, `()`. If you want to collect the items into a [`Container`]
+    /// (such as a [`Vec`]), use [`IterParser::collect`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let comment = just::<_, _, extra::Err<Simple<char>>>("<!--")
+    ///     .ignore_then(any().lazy_repeated(just("-->")).collect::<String>())
+    ///     .then_ignore(just("-->"));
+    ///
+    /// assert_eq!(comment.parse("<!-- a -> b -->").into_result(), Ok(" a -> b ".to_string()));
+    /// assert!(comment.parse("<!-- a --> b -->").has_errors());
+    /// assert!(comment.parse("<!-- unterminated").has_errors());
+    /// ```
+    #[cfg_attr(debug_assertions, track_caller)]
+    fn lazy_repeated<B, OB>(self, follow: B) -> LazyRepeated<Self, B, O, OB, I, E>
+    where
+        Self: Sized,
+        B: Parser<'src, I, OB, E>,
+    {
+        LazyRepeated {
+            parser: self,
+            follow,
+            at_least: 0,
+            #[cfg(debug_assertions)]
+            location: *Location::caller(),
+            phantom: EmptyPhantom::new(),
+        }
+    }
+
+    /// Parse a pattern, separated by another, any number of times.
+    ///
+    /// You can use [`SeparatedBy::allow_leading`] or [`SeparatedBy::allow_trailing`] to allow leading or trailing
+    /// separators.
+    ///
+    /// The output type of this parser can be any [`Container`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::{prelude::*, error::Simple};
+    /// let shopping = text::ascii::ident::<_, extra::Err<Simple<char>>>()
+    ///     .padded()
+    ///     .separated_by(just(','))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(shopping.parse("eggs").into_result(), Ok(vec!["eggs"]));
+    /// assert_eq!(shopping.parse("eggs, flour, milk").into_result(), Ok(vec!["eggs", "flour", "milk"]));
+    /// ```
+    ///
+    /// See [`SeparatedBy::allow_leading`] and [`SeparatedBy::allow_trailing`] for more examples.
+    #[cfg_attr(debug_assertions, track_caller)]
+    fn separated_by<U, B>(self, separator: B) -> SeparatedBy<Self, B, O, U, I, E>
+    where
+        Self: Sized,
+        B: Parser<'src, I, U, E>,
+    {
+        SeparatedBy {
+            parser: self,
+            separator,
+            at_least: 0,
+            at_most: !0,
+            allow_leading: false,
+            allow_trailing: false,
+            #[cfg(debug_assertions)]
+            location: *Location::caller(),
+            phantom: EmptyPhantom::new(),
+        }
+    }
+
+    /// Parse a pattern, separated by another pattern, preserving both the items and the separators along with their
+    /// spans.
+    ///
+    /// Unlike [`Parser::separated_by`], which discards its separators, this parser is intended for cases where the
+    /// separators carry meaning or must be reproduced later, such as a pretty-printer that round-trips a list whose
+    /// items may be separated by either commas or newlines.
+    ///
+    /// Zero or more items are accepted, and there is always one fewer separator than there are items unless
+    /// [`Interleave::allow_trailing`] is used. A separator that isn't followed by an item is not consumed.
+    ///
+    /// The output type of this parser is `(Vec<(O, I::Span)>, Vec<(U, I::Span)>)`: the items and the separators, in
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// #[derive(Clone, Debug, PartialEq)]
+    /// enum Sep { Comma, Newline }
+    ///
+    /// let list = text::ascii::ident::<_, extra::Err<Simple<char>>>().interleave(
+    ///     just(',').to(Sep::Comma).or(just('\n').to(Sep::Newline)),
+    /// );
+    ///
+    /// let (items, seps) = list.parse("a,b\nc").into_result().unwrap();
+    /// assert_eq!(
+    ///     items,
+    ///     vec![("a", SimpleSpan::new(0, 1)), ("b", SimpleSpan::new(2, 3)), ("c", SimpleSpan::new(4, 5))],
+    /// );
+    /// assert_eq!(seps, vec![(Sep::Comma, SimpleSpan::new(1, 2)), (Sep::Newline, SimpleSpan::new(3, 4))]);
+    /// ```
+    #[cfg_attr(debug_assertions, track_caller)]
+    fn interleave<U, B>(self, separator: B) -> Interleave<Self, B, O, U, I, E>
+    where
+        Self: Sized,
+        B: Parser<'src, I, U, E>,
+    {
+        Interleave {
+            parser: self,
+            separator,
+            allow_trailing: false,
+            #[cfg(debug_assertions)]
+            location: *Location::caller(),
+            phantom: EmptyPhantom::new(),
+        }
+    }
+
+    /// Left-fold the output of the parser into a single value.
+    ///
+    /// The output of the original parser must be of type `(A, impl IntoIterator<Item = B>)`.
+    ///
+    /// The output type of this parser is `A`, the left-hand component of the original parser's output.
     ///
     /// # Examples
     ///
@@ -1688,6 +2818,33 @@ pub trait Parser<'src, I: Input<'src>, O, E: ParserExtra<'src, I> = extra::Defau
         Padded { parser: self }
     }
 
+    /// Parse a pattern as a lexeme, ignoring any amount of whitespace that follows it.
+    ///
+    /// This is the whitespace-only equivalent of [`Parser::lexeme_with`]. Unlike [`Parser::padded`], leading
+    /// whitespace is not consumed: it is assumed to have been consumed by the previous lexeme.
+    ///
+    /// The output type of this parser is `O`, the same as the original parser.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let ident = text::ascii::ident::<_, extra::Err<Simple<char>>>().lexeme();
+    /// let assign = ident.then_ignore(just('=').lexeme()).then(ident);
+    ///
+    /// assert_eq!(assign.parse("x =  y \n").into_result(), Ok(("x", "y")));
+    /// // Leading whitespace is not part of a lexeme
+    /// assert!(assign.parse(" x = y").has_errors());
+    /// ```
+    fn lexeme(self) -> Lexeme<Self>
+    where
+        Self: Sized,
+        I: Input<'src>,
+        I::Token: Char,
+    {
+        Lexeme { parser: self }
+    }
+
     // /// Flatten a nested collection.
     // ///
     // /// This use-cases of this method are broadly similar to those of [`Iterator::flatten`].
@@ -1782,25 +2939,43 @@ pub trait Parser<'src, I: Input<'src>, O, E: ParserExtra<'src, I> = extra::Defau
         }
     }
 
-    // /// Map the primary error of this parser to another value, making use of the span from the start of the attempted
-    // /// to the point at which the error was encountered.
-    // ///
-    // /// This function is useful for augmenting errors to allow them to display the span of the initial part of a
-    // /// pattern, for example to add a "while parsing" clause to your error messages.
-    // ///
-    // /// The output type of this parser is `O`, the same as the original parser.
-    // ///
-    // // TODO: Map E -> D, not E -> E
-    // fn map_err_with_span<F>(self, f: F) -> MapErrWithSpan<Self, F>
-    // where
-    //     Self: Sized,
-    //     F: Fn(E::Error, I::Span) -> E::Error,
-    // {
-    //     MapErrWithSpan {
-    //         parser: self,
-    //         mapper: f,
-    //     }
-    // }
+    /// Map the primary error of this parser to another value, making use of the span and position of the token at
+    /// which the error occurred.
+    ///
+    /// Unlike [`Parser::map_err_with_state`], which provides the span of the entire region from the start of this
+    /// parser, the span given here is that of the token the error was actually generated at (or an empty span, if
+    /// the error occurred at the end of the input). This is useful for relocating or annotating errors without
+    /// blaming the entire enclosing pattern.
+    ///
+    /// The position is the offset of the error in the input, as given by [`Input::cursor_location`]: a byte offset for
+    /// `&str`, and a token index for most other inputs.
+    ///
+    /// The output type of this parser is `O`, the same as the original parser.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let pair = just::<_, _, extra::Err<Rich<char>>>("ab")
+    ///     .then(just('c'))
+    ///     .map_err_with_span(|e, span, pos| Rich::custom(span, format!("bad pair at {pos}: {e}")));
+    ///
+    /// let errs = pair.parse("abx").into_errors();
+    /// // The error points at the failing token, not at the whole pair
+    /// assert_eq!(errs[0].span(), &SimpleSpan::new(2, 3));
+    /// assert!(errs[0].to_string().starts_with("bad pair at 2:"));
+    /// ```
+    // TODO: Map E -> D, not E -> E
+    fn map_err_with_span<F>(self, f: F) -> MapErrWithSpan<Self, F>
+    where
+        Self: Sized,
+        F: Fn(E::Error, I::Span, usize) -> E::Error,
+    {
+        MapErrWithSpan {
+            parser: self,
+            mapper: f,
+        }
+    }
 
     /// Map the primary error of this parser to another value, making use of the parser state.
     ///
@@ -1821,6 +2996,67 @@ pub trait Parser<'src, I: Input<'src>, O, E: ParserExtra<'src, I> = extra::Defau
         }
     }
 
+    /// Observe the output of this parser without changing it, such as to log it while debugging a grammar.
+    ///
+    /// The function is only called when the output of the parser is actually produced: parsers whose outputs are
+    /// discarded (such as those passed to [`Parser::ignore_then`] or run via [`Parser::check`]) do not call it.
+    ///
+    /// The output type of this parser is `O`, the same as the original parser.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// # use std::cell::RefCell;
+    /// let seen = RefCell::new(Vec::new());
+    /// let ident = text::ascii::ident::<_, extra::Default>()
+    ///     .inspect(|name: &&str| seen.borrow_mut().push(name.to_string()))
+    ///     .padded()
+    ///     .repeated()
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(ident.parse("foo bar").into_result(), Ok(vec!["foo", "bar"]));
+    /// assert_eq!(*seen.borrow(), vec!["foo", "bar"]);
+    /// ```
+    fn inspect<F: Fn(&O)>(self, f: F) -> Inspect<Self, O, F>
+    where
+        Self: Sized,
+    {
+        Inspect {
+            parser: self,
+            inspector: f,
+            phantom: EmptyPhantom::new(),
+        }
+    }
+
+    /// Observe the primary error of this parser when it fails, without changing it, such as to log it while debugging
+    /// a grammar.
+    ///
+    /// The output type of this parser is `O`, the same as the original parser.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// # use std::cell::Cell;
+    /// let failures = Cell::new(0);
+    /// let digit = one_of::<_, _, extra::Err<Simple<char>>>('0'..='9')
+    ///     .inspect_err(|_| failures.set(failures.get() + 1));
+    /// let parser = digit.or(just('x'));
+    ///
+    /// assert!(!parser.parse("x").has_errors());
+    /// assert_eq!(failures.get(), 1);
+    /// ```
+    fn inspect_err<F: Fn(&E::Error)>(self, f: F) -> InspectErr<Self, F>
+    where
+        Self: Sized,
+    {
+        InspectErr {
+            parser: self,
+            inspector: f,
+        }
+    }
+
     /// Validate an output, producing non-terminal errors if it does not fulfill certain criteria.
     /// The errors will not immediately halt parsing on this path, but instead it will continue,
     /// potentially emitting one or more other errors, only failing after the pattern has otherwise
@@ -2880,6 +4116,87 @@ macro_rules! select_ref {
     });
 }
 
+/// Create a parser that accepts any one of the given tokens or inclusive token ranges.
+///
+/// Each item may be a single literal (like `'_'`) or an inclusive range of literals (like `'a'..='z'`). The tokens
+/// are collected into a [`CharSet`](container::CharSet) or [`ByteSet`](container::ByteSet), depending on the type of
+/// the literals, so membership is checked quickly no matter how many items are given. Errors produced by the parser
+/// list every token in the set.
+///
+/// Unlike the function of the same name, this macro is not part of the [`prelude`] and must be imported with
+/// `use chumsky::one_of;`.
+///
+/// See [`one_of`](primitive::one_of) for the function form, which accepts any [`Seq`](container::Seq) of tokens.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// use chumsky::one_of;
+///
+/// fn ident<'src>() -> impl Parser<'src, &'src str, &'src str> {
+///     one_of!('a'..='z', 'A'..='Z', '_')
+///         .then(one_of!('a'..='z', 'A'..='Z', '0'..='9', '_').repeated())
+///         .to_slice()
+/// }
+///
+/// assert_eq!(ident().parse("foo_42").into_result(), Ok("foo_42"));
+/// assert!(ident().parse("42").has_errors());
+///
+/// fn hex<'src>() -> impl Parser<'src, &'src [u8], &'src [u8]> {
+///     one_of!(b'0'..=b'9', b'a'..=b'f').repeated().at_least(1).to_slice()
+/// }
+///
+/// assert_eq!(hex().parse(b"c0ffee").into_result(), Ok(&b"c0ffee"[..]));
+///
+/// // Errors list every token in the set
+/// fn digit<'src>() -> impl Parser<'src, &'src str, char, extra::Err<Rich<'src, char>>> {
+///     one_of!('0'..='2')
+/// }
+///
+/// let errs = digit().parse("x").into_errors();
+/// assert_eq!(errs[0].expected().count(), 3);
+/// ```
+#[macro_export]
+macro_rules! one_of {
+    ($($start:literal $(..= $end:literal)?),+ $(,)?) => {
+        $crate::primitive::one_of(
+            <_ as $crate::container::SetToken>::set_from_ranges(&[
+                $(($start, { $start $(; $end)? })),+
+            ])
+        )
+    };
+}
+
+/// Create a parser that accepts any token that is not one of the given tokens or inclusive token ranges.
+///
+/// This accepts the same syntax as [`one_of!`]. See [`none_of`](primitive::none_of) for the function form.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// use chumsky::none_of;
+///
+/// fn not_space<'src>() -> impl Parser<'src, &'src str, char> {
+///     none_of!(' ', '\t', '\n', '\0'..='\x08')
+/// }
+///
+/// assert_eq!(not_space().parse("a").into_result(), Ok('a'));
+/// assert!(not_space().parse("\t").has_errors());
+/// assert!(not_space().parse("\x05").has_errors());
+/// ```
+#[macro_export]
+macro_rules! none_of {
+    ($($start:literal $(..= $end:literal)?),+ $(,)?) => {
+        $crate::primitive::none_of(
+            <_ as $crate::container::SetToken>::set_from_ranges(&[
+                $(($start, { $start $(; $end)? })),+
+            ])
+        )
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
@@ -3176,28 +4493,56 @@ mod tests {
     mod debug_asserts {
         use crate::prelude::*;
 
-        // TODO panic when left recursive parser is detected
-        // #[test]
-        // #[should_panic]
-        // fn debug_assert_left_recursive() {
-        //     recursive(|expr| {
-        //         let atom = any::<&str, extra::Default>()
-        //             .filter(|c: &char| c.is_alphabetic())
-        //             .repeated()
-        //             .at_least(1)
-        //             .collect();
-
-        //         let sum = expr
-        //             .clone()
-        //             .then_ignore(just('+'))
-        //             .then(expr)
-        //             .map(|(a, b)| format!("{}{}", a, b));
-
-        //         sum.or(atom)
-        //     })
-        //     .then_ignore(end())
-        //     .parse("a+b+c");
-        // }
+        #[test]
+        #[should_panic(expected = "without consuming any input")]
+        fn debug_assert_left_recursive() {
+            recursive(|expr| {
+                let atom = any::<&str, extra::Default>()
+                    .filter(|c: &char| c.is_alphabetic())
+                    .repeated()
+                    .at_least(1)
+                    .collect();
+
+                let sum = expr
+                    .clone()
+                    .then_ignore(just('+'))
+                    .then(expr)
+                    .map(|(a, b)| format!("{}{}", a, b));
+
+                sum.or(atom)
+            })
+            .then_ignore(end())
+            .parse("a+b+c");
+        }
+
+        #[test]
+        #[should_panic(expected = "the first ends at offset 2 while the second ends at offset 3")]
+        fn debug_assert_ambiguous_or() {
+            just::<_, &str, extra::Default>("in")
+                .to_slice()
+                .or(text::ascii::ident())
+                .check_ambiguity()
+                .parse("int");
+        }
+
+        #[test]
+        #[should_panic(expected = "used before being defined")]
+        fn debug_assert_recursive_undefined() {
+            let expr = Recursive::<crate::recursive::Indirect<&str, (), extra::Default>>::declare();
+            expr.parse("");
+        }
+
+        #[test]
+        #[should_panic(expected = "Recursive parser `expr` declared at")]
+        fn debug_assert_left_recursive_named() {
+            let mut expr = Recursive::declare().with_name("expr");
+            expr.define(
+                expr.clone()
+                    .then_ignore(just::<_, &str, extra::Default>('+')),
+            );
+            let expr: Recursive<crate::recursive::Indirect<&str, (), _>> = expr;
+            expr.parse("+");
+        }
 
         #[test]
         #[should_panic]
@@ -3293,12 +4638,167 @@ mod tests {
                 .parse("a+b+c");
         }
 
+        #[test]
+        #[should_panic(expected = "found Repeated combinator making no progress")]
+        fn debug_assert_repeated_at_least() {
+            empty::<&str, extra::Default>()
+                .repeated()
+                .at_least(1)
+                .collect::<Vec<_>>()
+                .parse("a");
+        }
+
+        #[test]
+        #[should_panic(expected = "found RepeatedTill combinator making no progress")]
+        fn debug_assert_repeated_till() {
+            empty::<&str, extra::Default>()
+                .repeated_till(just('!'))
+                .parse("a");
+        }
+
+        #[test]
+        #[should_panic(expected = "found SeparatedBy combinator making no progress")]
+        fn debug_assert_separated_by_iter() {
+            empty::<&str, extra::Default>()
+                .separated_by(empty())
+                .count()
+                .parse("a");
+        }
+
         // TODO what about IterConfigure and TryIterConfigure?
     }
 
-    #[test]
-    #[should_panic]
-    fn recursive_define_twice() {
+    // In release builds, repetitions that make no progress fail rather than hanging
+    #[cfg(not(debug_assertions))]
+    mod release_no_progress {
+        use crate::prelude::*;
+
+        #[test]
+        fn repeated_no_progress_fails() {
+            let errs = empty::<&str, extra::Err<Rich<char>>>()
+                .repeated()
+                .collect::<Vec<_>>()
+                .parse("a")
+                .into_errors();
+            assert_eq!(errs[0].to_string(), "Repeated combinator made no progress");
+
+            assert!(empty::<&str, extra::Default>()
+                .repeated()
+                .parse("")
+                .has_errors());
+            assert!(empty::<&str, extra::Default>()
+                .to(0)
+                .foldl(empty().repeated(), |n, ()| n + 1)
+                .parse("")
+                .has_errors());
+            assert!(empty::<&str, extra::Default>()
+                .separated_by(empty())
+                .count()
+                .parse("")
+                .has_errors());
+            assert!(empty::<&str, extra::Default>()
+                .repeated_till(just('!'))
+                .parse("")
+                .has_errors());
+        }
+
+        #[test]
+        fn bounded_no_progress_terminates() {
+            assert_eq!(
+                empty::<&str, extra::Default>()
+                    .repeated()
+                    .exactly(3)
+                    .count()
+                    .parse("")
+                    .into_result(),
+                Ok(3),
+            );
+        }
+    }
+
+    #[test]
+    fn recursive_reentered_in_nested_input() {
+        #[derive(Clone, Debug, PartialEq)]
+        enum Tok {
+            Num(u64),
+            Group(Vec<Tok>),
+        }
+
+        #[derive(Debug, PartialEq)]
+        enum Tree {
+            Num(u64),
+            Group(Vec<Tree>),
+        }
+
+        let tokens = [Tok::Group(vec![Tok::Num(1)])];
+        // The nested input starts again at offset 0, which must not be mistaken for left recursion
+        let tree = recursive::<_, _, extra::Default, _, _>(|tree| {
+            let num = select_ref! { Tok::Num(n) => Tree::Num(*n) };
+            num.or(tree
+                .repeated()
+                .collect()
+                .nested_in(select_ref! { Tok::Group(ts) => ts.as_slice() })
+                .map(Tree::Group))
+        });
+
+        assert_eq!(
+            tree.parse(&tokens[..]).into_result(),
+            Ok(Tree::Group(vec![Tree::Num(1)])),
+        );
+    }
+
+    #[test]
+    fn recursive_reentered_with_bounded_state() {
+        use crate::inspector::SimpleState;
+
+        // Each entry bumps the counter and the recursion stops once it reaches 3, so re-entering the parser at the
+        // same position is not left recursion
+        let parser = recursive::<_, _, extra::Full<EmptyErr, SimpleState<u32>, ()>, _, _>(|p| {
+            just('a').to(()).or(empty()
+                .try_map_with(|(), e| {
+                    let depth: &mut SimpleState<u32> = e.state();
+                    **depth += 1;
+                    if **depth < 3 {
+                        Ok(())
+                    } else {
+                        Err(EmptyErr::default())
+                    }
+                })
+                .ignore_then(p))
+        });
+
+        let mut state = SimpleState(0);
+        assert!(parser.parse_with_state("b", &mut state).has_errors());
+        assert_eq!(state.0, 3);
+        assert_eq!(
+            parser
+                .parse_with_state("a", &mut SimpleState(0))
+                .into_result(),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn holes_in_nested_input() {
+        let arg = text::ascii::ident::<&str, extra::Err<Rich<char>>>()
+            .or_hole()
+            .then_ignore(just(';'))
+            .nested_in(
+                none_of(')')
+                    .repeated()
+                    .to_slice()
+                    .delimited_by(just('('), just(')')),
+            );
+
+        assert!(arg.parse("(;)").has_errors());
+        let (out, errs) = arg.parse_with_holes("(;)").into_output_errors();
+        assert_eq!(out, Some(None));
+        assert_eq!(errs.len(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn recursive_define_twice() {
         let mut expr = Recursive::declare();
         expr.define({
             let atom = any::<&str, extra::Default>()
@@ -3326,6 +4826,176 @@ mod tests {
         expr.then_ignore(end()).parse("a+b+c");
     }
 
+    #[test]
+    fn line_col_input() {
+        use crate::span::{LineCol, LineColSpan};
+
+        let parser = just::<_, _, extra::Err<Rich<char, LineColSpan>>>("let")
+            .padded()
+            .then(text::ascii::ident())
+            .then_ignore(just(';'))
+            .then_end();
+
+        let errs = parser.parse("\n  let x\n".line_col()).into_errors();
+        assert_eq!(errs.len(), 1);
+        assert_eq!(
+            errs[0].span().start,
+            LineCol {
+                offset: 8,
+                line: 2,
+                col: 8
+            }
+        );
+
+        let errs = parser
+            .parse("let\r\n\tx;y".line_col().with_tab_width(8))
+            .into_errors();
+        assert_eq!(
+            *errs[0].span(),
+            LineColSpan {
+                start: LineCol {
+                    offset: 8,
+                    line: 2,
+                    col: 11
+                },
+                end: LineCol {
+                    offset: 9,
+                    line: 2,
+                    col: 12
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn token_slice_inputs() {
+        // Not `Clone`, so may only be parsed by reference
+        #[derive(Debug, PartialEq)]
+        enum Token {
+            Num(u64),
+            Plus,
+        }
+
+        fn sum<'src, I>() -> impl Parser<'src, I, u64, extra::Err<Simple<'src, Token>>>
+        where
+            I: crate::input::BorrowInput<'src, Token = Token, Span = SimpleSpan>,
+        {
+            let num = select_ref! { Token::Num(x) => *x };
+            num.foldl(
+                select_ref! { Token::Plus => () }
+                    .ignore_then(num)
+                    .repeated(),
+                |a, b| a + b,
+            )
+        }
+
+        let tokens = vec![
+            Token::Num(1),
+            Token::Plus,
+            Token::Num(2),
+            Token::Plus,
+            Token::Num(3),
+        ];
+        assert_eq!(sum().parse(&tokens).into_result(), Ok(6));
+        assert_eq!(sum().parse(tokens.as_slice()).into_result(), Ok(6));
+        assert_eq!(
+            any_ref::<_, extra::Default>()
+                .repeated()
+                .at_least(1)
+                .to_slice()
+                .parse(&tokens)
+                .into_result(),
+            Ok(tokens.as_slice()),
+        );
+
+        let bytes = b"abc".to_vec();
+        assert_eq!(
+            text::ascii::ident::<_, extra::Default>()
+                .parse(&bytes)
+                .into_result(),
+            Ok(&b"abc"[..]),
+        );
+    }
+
+    #[test]
+    fn just_contiguous_seq() {
+        // Sequences are compared against slice inputs in one go, but errors must still point at the mismatch
+        let kw = just::<_, _, extra::Err<Rich<char>>>("hello");
+        assert_eq!(kw.parse("hello").into_result(), Ok("hello"));
+        let errs = kw.parse("help").into_errors();
+        assert_eq!(errs[0].span(), &SimpleSpan::new(3, 4));
+        assert_eq!(errs[0].found(), Some(&'p'));
+
+        let magic = just::<_, &[u8], extra::Err<Simple<u8>>>(b"\x7fELF");
+        assert_eq!(
+            magic.parse(b"\x7fELF".as_slice()).into_result(),
+            Ok(b"\x7fELF")
+        );
+        assert_eq!(
+            magic.parse(b"\x7fEL".as_slice()).into_errors()[0].span(),
+            &SimpleSpan::new(3, 3)
+        );
+    }
+
+    #[test]
+    fn profiler_counts() {
+        use crate::inspector::Profiler;
+
+        // Regression test for the amount of work done by common combinators: if these numbers go up, a combinator
+        // has probably started doing redundant work.
+        let parser = text::int::<_, extra::Full<EmptyErr, Profiler, ()>>(10)
+            .separated_by(just(','))
+            .collect::<Vec<_>>()
+            .delimited_by(just('['), just(']'));
+
+        let mut profiler = Profiler::default();
+        assert_eq!(
+            parser
+                .parse_with_state("[1,23,456]", &mut profiler)
+                .into_result(),
+            Ok(vec!["1", "23", "456"]),
+        );
+        assert_eq!(
+            (profiler.tokens(), profiler.saves(), profiler.rewinds()),
+            (10, 11, 0)
+        );
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn mmap_input() {
+        use crate::input::MmapInput;
+
+        let path = std::env::temp_dir().join(format!("chumsky-mmap-{}.txt", std::process::id()));
+        std::fs::write(&path, b"key=value\nfoo=bar\n").unwrap();
+        // SAFETY: The file is not modified while it is mapped
+        let input = unsafe { MmapInput::open(&path) }.unwrap();
+
+        let parser = text::ascii::ident::<_, extra::Err<Simple<u8>>>()
+            .then_ignore(just(b'='))
+            .then(
+                any()
+                    .filter(|c: &u8| *c != b'\n')
+                    .repeated()
+                    .to_slice()
+                    .map_with(|v, e| (v, e.span())),
+            )
+            .then_ignore(just(b'\n'))
+            .repeated()
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            parser.parse(&input).into_result(),
+            Ok(vec![
+                (&b"key"[..], (&b"value"[..], SimpleSpan::from(4..9))),
+                (&b"foo"[..], (&b"bar"[..], SimpleSpan::from(14..17))),
+            ]),
+        );
+
+        drop(input);
+        std::fs::remove_file(&path).unwrap();
+    }
+
     #[test]
     fn box_impl() {
         fn parser<'src>() -> impl Parser<'src, &'src str, Vec<u64>> {
@@ -3569,7 +5239,7 @@ mod tests {
 
         let mut err = <Rich<_> as crate::Error<&str>>::expected_found(
             Some(Some('h'.into())),
-            None,
+            Some('g'.into()),
             (0..7).into(),
         );
         <Rich<_, _, _> as LabelError<&str, _>>::label_with(&mut err, "greeting");
@@ -3616,7 +5286,7 @@ mod tests {
                     Error::<&str>::expected_found(
                         vec![Some('n'.into())],
                         e.found().map(|i| From::from(*i)),
-                        e.span().clone(),
+                        *e.span(),
                     )
                 }))
                 .ignored()
@@ -3632,6 +5302,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn rich_merged_reasons() {
+        use crate::error::{Error, RichReason};
+
+        let span = SimpleSpan::new(0, 1);
+        let a = Rich::<char>::custom(span, "not a").with_note("note a");
+        let b = Rich::<char>::custom(span, "not b").with_note("note b");
+        let err = Error::<&str>::merge(a.clone(), b);
+        let err = Error::<&str>::merge(err, a);
+
+        assert_eq!(
+            err.reason(),
+            &RichReason::Many(vec![
+                RichReason::Custom("not a".to_string()),
+                RichReason::Custom("not b".to_string()),
+            ]),
+        );
+        assert_eq!(err.notes().collect::<Vec<_>>(), vec!["note a", "note b"]);
+        assert_eq!(
+            err.to_string(),
+            "not a; not b (note: note a) (note: note b)"
+        );
+
+        // Custom reasons continue to take priority over expected inputs
+        let expected = <Rich<char> as Error<&str>>::expected_found(
+            Some(Some('x'.into())),
+            Some('y'.into()),
+            span,
+        );
+        let err = Error::<&str>::merge(expected, err);
+        assert_eq!(
+            err.to_string(),
+            "not a; not b (note: note a) (note: note b)"
+        );
+    }
+
     #[test]
     fn map_err() {
         use crate::{error::Error, util::Maybe::Val};
@@ -3652,4 +5358,767 @@ mod tests {
             )])
         );
     }
+
+    #[test]
+    fn token_sets() {
+        use crate::container::{ByteSet, CharSet, Seq};
+
+        let set =
+            CharSet::from_ranges(&[('α', 'γ'), ('a', 'c'), ('β', 'ε'), ('ζ', 'ζ'), ('z', 'z')]);
+        assert_eq!(
+            <CharSet as Seq<char>>::seq_iter(&set).collect::<String>(),
+            "abczαβγδεζ".to_string()
+        );
+        assert!(set.contains('δ'));
+        assert!(!set.contains('η'));
+        assert!(!set.contains('d'));
+
+        let parser = one_of::<_, &str, extra::Err<Simple<char>>>(set)
+            .repeated()
+            .collect::<String>();
+        assert_eq!(parser.parse("azβζ").into_result(), Ok("azβζ".to_string()));
+        assert!(parser.parse("aη").has_errors());
+
+        let set = ByteSet::from_ranges(&[(b'0', b'9'), (0xFF, 0xFF)]);
+        assert_eq!(<ByteSet as Seq<u8>>::seq_iter(&set).count(), 11);
+        assert!(set.contains(0xFF));
+        assert!(!set.contains(b'a'));
+    }
+
+    #[test]
+    fn separated_by_exactly_with_separators() {
+        let parser = text::ascii::ident::<_, extra::Err<Simple<char>>>()
+            .map(str::to_string)
+            .separated_by(one_of(",;").map(String::from))
+            .allow_leading()
+            .allow_trailing()
+            .collect_exactly_with_separators::<[String; 3], [String; 2]>();
+
+        assert_eq!(
+            parser.parse(",a;b,c;").into_result(),
+            Ok((
+                ["a".to_string(), "b".to_string(), "c".to_string()],
+                [";".to_string(), ",".to_string()]
+            )),
+        );
+        // Partially-initialized outputs are dropped on failure
+        assert!(parser.parse("a,b,").has_errors());
+        assert!(parser.parse("a,b").has_errors());
+        assert!(parser.parse("a,b,c,d").has_errors());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn read_input() {
+        use crate::input::{ReadError, ReadInput};
+
+        // Spans several reads, and backtracks within the window at each item
+        let data = "ab,".repeat(10_000);
+        let input = ReadInput::new(data.as_bytes()).with_window(16);
+        let parser = just::<_, _, extra::Err<Simple<u8>>>(b"ax")
+            .or(just(b"ab"))
+            .separated_by(just(b','))
+            .allow_trailing()
+            .count();
+        assert_eq!(parser.parse(&input).into_result(), Ok(10_000));
+        assert!(input.take_error().is_none());
+
+        // Backtracking over a whole item exceeds a small window
+        let input = ReadInput::new(data.as_bytes()).with_window(2);
+        let parser = just::<_, _, extra::Err<Simple<u8>>>(b"ab,ab,x")
+            .or(just(b"ab,ab,a"))
+            .ignored();
+        assert!(parser.parse(&input).has_errors());
+        assert!(matches!(
+            input.take_error(),
+            Some(ReadError::WindowExceeded {
+                pos: 0,
+                retained_from: 4
+            })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_helpers_snapshot() {
+        use crate::test::{check_snapshot, render_errors};
+
+        let parser = just::<_, _, extra::Err<Rich<char>>>('a')
+            .repeated()
+            .collect::<Vec<_>>();
+        let path =
+            std::env::temp_dir().join(format!("chumsky-snapshot-{}.txt", std::process::id()));
+
+        // Pass `bless` explicitly so that the test doesn't depend on whether `CHUMSKY_BLESS` is set
+        check_snapshot(&render_errors(&parser, "aab"), &path, true);
+        check_snapshot(&render_errors(&parser, "aab"), &path, false);
+        assert!(std::panic::catch_unwind(|| {
+            check_snapshot(&render_errors(&parser, "aa"), &path, false)
+        })
+        .is_err());
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(render_errors(&parser, "aa"), "");
+    }
+
+    #[test]
+    fn repeated_till_reports_item_and_terminator() {
+        let digits = one_of::<_, _, extra::Err<Rich<char>>>('0'..='9')
+            .repeated_till(just(';'))
+            .collect::<String>();
+
+        assert_eq!(
+            digits.parse("42;").into_result(),
+            Ok(("42".to_string(), ';'))
+        );
+        assert_eq!(digits.parse(";").into_result(), Ok((String::new(), ';')));
+
+        let errs = digits.parse("4x;").into_errors();
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs[0].span(), &SimpleSpan::from(1..2));
+        let expected = errs[0].expected().collect::<Vec<_>>();
+        assert!(expected.contains(&&crate::error::RichPattern::Token(';'.into())));
+        assert!(expected.contains(&&crate::error::RichPattern::Token('0'.into())));
+    }
+
+    #[test]
+    fn lazy_repeated_stops_at_first_follow() {
+        let until_arrow = any::<_, extra::Err<Rich<char>>>()
+            .lazy_repeated(just("->"))
+            .collect::<String>()
+            .then(just("->").ignore_then(any().repeated().collect::<String>()));
+
+        assert_eq!(
+            until_arrow.parse("a->b->c").into_result(),
+            Ok(("a".to_string(), "b->c".to_string()))
+        );
+        assert_eq!(
+            until_arrow.parse("->").into_result(),
+            Ok((String::new(), String::new()))
+        );
+
+        let digits = one_of::<_, _, extra::Err<Rich<char>>>('0'..='9')
+            .lazy_repeated(just(';'))
+            .count();
+        let errs = digits.then_ignore(just(';')).parse("4x;").into_errors();
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs[0].span(), &SimpleSpan::from(1..2));
+        let expected = errs[0].expected().collect::<Vec<_>>();
+        assert!(expected.contains(&&crate::error::RichPattern::Token(';'.into())));
+        assert!(expected.contains(&&crate::error::RichPattern::Token('0'.into())));
+    }
+
+    #[test]
+    fn current_column_counts_from_line_start() {
+        let column = current_column::<_, extra::Err<Simple<u8>>>().with_tab_width(8);
+        let indent = just(b' ').or(just(b'\t')).repeated();
+        let col_after_indent = none_of(b"\n")
+            .repeated()
+            .then(just(b'\n').or_not())
+            .ignore_then(indent)
+            .ignore_then(column)
+            .lazy();
+
+        assert_eq!(
+            col_after_indent
+                .parse(b"a\r\n\t x".as_slice())
+                .into_result(),
+            Ok(10)
+        );
+        assert_eq!(
+            col_after_indent.parse(b"a\r  \t".as_slice()).into_result(),
+            Ok(9)
+        );
+        assert_eq!(column.parse(b"".as_slice()).into_result(), Ok(1));
+
+        // Multi-byte characters, including newlines, count as a single column
+        let column = current_column::<&str, extra::Err<Simple<char>>>().with_tab_width(8);
+        let col_before_x = none_of('x')
+            .repeated()
+            .ignore_then(column)
+            .then_ignore(just('x'));
+        assert_eq!(col_before_x.parse("é\u{2028}ü\tx").into_result(), Ok(9));
+        assert_eq!(col_before_x.parse("éü x").into_result(), Ok(4));
+    }
+
+    #[test]
+    fn node_events_discard_failures() {
+        use crate::inspector::{Event, Events};
+
+        type Extra = extra::Full<EmptyErr, Events<&'static str, char, SimpleSpan>, ()>;
+        let digit = any::<_, Extra>().filter(char::is_ascii_digit).node("digit");
+        let pair = digit.then(digit).node("pair");
+
+        // A node that fails without being backtracked over leaves no events behind
+        let mut events = Events::default();
+        assert!(pair.check_with_state("1x", &mut events).has_errors());
+        assert_eq!(events.events(), &[]);
+
+        let mut events = Events::default();
+        // The partial pair at the end is backtracked over, so only the final digit remains
+        let pairs = pair.repeated().then(digit);
+        assert!(!pairs.check_with_state("123", &mut events).has_errors());
+        let mut kinds = Vec::new();
+        events.for_each(|event| {
+            if let Event::Exit(kind, span) = event {
+                kinds.push((kind, span.into_range()));
+            }
+        });
+        assert_eq!(
+            kinds,
+            vec![
+                ("digit", 0..1),
+                ("digit", 1..2),
+                ("pair", 0..2),
+                ("digit", 2..3)
+            ]
+        );
+    }
+
+    #[test]
+    fn insert_missing_delimiter() {
+        let list = text::int::<_, extra::Err<Rich<char>>>(10)
+            .separated_by(just(','))
+            .collect::<Vec<_>>()
+            .delimited_by(just('['), just(']').recover_with(insert_missing(']')));
+        let lists = list.padded().repeated().collect::<Vec<_>>();
+
+        let res = lists.parse("[1,2 [3]");
+        assert_eq!(res.output(), Some(&vec![vec!["1", "2"], vec!["3"]]));
+        let errs = res.into_errors();
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs[0].span(), &SimpleSpan::new(4, 5));
+        assert_eq!(errs[0].found(), Some(&' '));
+    }
+
+    #[test]
+    fn interleave_leaves_dangling_separator() {
+        let list = text::int::<_, extra::Err<Simple<char>>>(10)
+            .interleave(just(';'))
+            .map(|(items, seps)| (items.len(), seps.len()));
+
+        assert_eq!(list.parse("").into_result(), Ok((0, 0)));
+        assert_eq!(
+            list.then_ignore(just(';')).parse("1;2;").into_result(),
+            Ok((2, 1))
+        );
+        // The item that was expected after the dangling separator is the furthest error
+        let errs = list.then(end()).parse("1;2;").into_errors();
+        assert_eq!(errs[0].span(), &SimpleSpan::new(4, 4));
+        assert_eq!(errs[0].found(), None);
+    }
+
+    #[test]
+    fn or_else_with_sees_error() {
+        let digit = any::<_, extra::Err<Rich<char>>>()
+            .filter(char::is_ascii_digit)
+            .map(|c| c.to_string())
+            .or_else_with(|err| format!("missing at {}", err.span()));
+        let pair = just('(').ignore_then(digit).then_ignore(just(')'));
+
+        assert_eq!(pair.parse("(7)").into_result(), Ok("7".to_string()));
+        assert_eq!(
+            pair.parse("()").into_result(),
+            Ok("missing at 1..2".to_string())
+        );
+
+        let empty = just::<_, _, extra::Default>('x').or_else_with(|_| 'y');
+        assert_eq!(empty.parse("").into_result(), Ok('y'));
+    }
+
+    #[test]
+    fn try_map_check_skips_mapper() {
+        use core::cell::Cell;
+
+        let mapped = Cell::new(0);
+        let word = text::ascii::ident::<_, extra::Err<Rich<char>>>()
+            .try_map_with(|s: &str, e| {
+                mapped.set(mapped.get() + 1);
+                if s.len() <= 3 {
+                    Ok(s.to_uppercase())
+                } else {
+                    Err(Rich::custom(e.span(), "too long"))
+                }
+            })
+            .with_check(|e| {
+                let s: &str = e.slice();
+                if s.len() <= 3 {
+                    Ok(())
+                } else {
+                    Err(Rich::custom(e.span(), "too long"))
+                }
+            });
+
+        assert!(!word.check("abc").has_errors());
+        assert!(word.check("abcd").has_errors());
+        assert!(!word.ignored().parse("ab").has_errors());
+        assert_eq!(mapped.get(), 0);
+
+        assert_eq!(word.parse("abc").into_result(), Ok("ABC".to_string()));
+        assert_eq!(mapped.get(), 1);
+    }
+
+    #[test]
+    fn to_and_ignored_skip_output() {
+        use core::cell::Cell;
+
+        let calls = Cell::new(0);
+        let digit = any::<_, extra::Err<Simple<char>>>()
+            .filter(char::is_ascii_digit)
+            .map(|c| {
+                calls.set(calls.get() + 1);
+                c
+            });
+        let digits = digit.repeated().collect::<Vec<_>>();
+
+        // None of these generate the output of `digit`
+        assert_eq!(
+            digits
+                .then(just('+'))
+                .then(digits)
+                .to(42)
+                .parse("1+23")
+                .into_result(),
+            Ok(42)
+        );
+        assert_eq!(
+            digits
+                .ignored()
+                .then(digits.ignored())
+                .parse("12")
+                .into_result(),
+            Ok(((), ()))
+        );
+        assert_eq!(digit.ignored().parse("7").into_result(), Ok(()));
+        assert_eq!(calls.get(), 0);
+
+        assert_eq!(digits.parse("12").into_result(), Ok(vec!['1', '2']));
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn borrowed_state() {
+        use crate::inspector::{Profiler, SimpleState};
+
+        #[derive(Default)]
+        struct SymbolTable {
+            names: Vec<String>,
+        }
+
+        fn parser<'src, 'b>() -> impl Parser<
+            'src,
+            &'src str,
+            Vec<usize>,
+            extra::Full<EmptyErr, SimpleState<&'b mut SymbolTable>, ()>,
+        >
+        where
+            'b: 'src,
+        {
+            text::ascii::ident()
+                .map_with(|name: &str, e| {
+                    let state: &mut SimpleState<&mut SymbolTable> = e.state();
+                    let table = &mut *state.0;
+                    table
+                        .names
+                        .iter()
+                        .position(|n| n == name)
+                        .unwrap_or_else(|| {
+                            table.names.push(name.to_string());
+                            table.names.len() - 1
+                        })
+                })
+                .padded()
+                .repeated()
+                .collect()
+        }
+
+        let mut table = SymbolTable::default();
+        let src = String::from("a b a c");
+        assert_eq!(
+            parser()
+                .parse_with_state(src.as_str(), &mut SimpleState(&mut table))
+                .into_result(),
+            Ok(vec![0, 1, 0, 2]),
+        );
+        assert_eq!(table.names, ["a", "b", "c"]);
+
+        // State that borrows from the input can be owned by the caller instead
+        let mut names = SimpleState(Vec::new());
+        text::ascii::ident::<_, extra::Full<EmptyErr, SimpleState<Vec<&str>>, ()>>()
+            .map_with(|name, e| e.state().push(name))
+            .padded()
+            .repeated()
+            .collect::<Vec<_>>()
+            .parse_with_state(src.as_str(), &mut names)
+            .into_result()
+            .unwrap();
+        assert_eq!(names.0, ["a", "b", "a", "c"]);
+
+        // Inspectors can be borrowed too, with their hooks forwarded
+        let mut profiler = Profiler::default();
+        let mut borrowed = &mut profiler;
+        just::<_, _, extra::Full<EmptyErr, &mut Profiler, ()>>('a')
+            .or(just('b'))
+            .parse_with_state("b", &mut borrowed)
+            .into_result()
+            .unwrap();
+        assert_eq!(profiler.tokens(), 2);
+    }
+
+    #[test]
+    fn choice_vec_boxed_merges_errors() {
+        use crate::error::{Rich, RichPattern};
+
+        let mut parsers: Vec<Boxed<&str, char, extra::Err<Rich<char>>>> =
+            vec![just('a').boxed(), just('b').boxed()];
+        parsers.push(just('c').boxed());
+        let parser = choice(parsers);
+
+        assert_eq!(parser.parse("c").into_result(), Ok('c'));
+
+        let errs = parser.parse("d").into_errors();
+        assert_eq!(errs.len(), 1);
+        let expected = errs[0].expected().cloned().collect::<Vec<_>>();
+        for c in ['a', 'b', 'c'] {
+            assert!(expected.as_slice().contains(&RichPattern::Token(c.into())));
+        }
+
+        // Earlier alternatives take priority, even if a later one could consume more
+        let parser = choice(vec![
+            just::<_, _, extra::Default>("a").boxed(),
+            just("ab").boxed(),
+        ]);
+        assert_eq!(parser.lazy().parse("ab").into_result(), Ok("a"));
+    }
+
+    #[test]
+    fn delimiter_spans_nested() {
+        let list = recursive(|list| {
+            list.separated_by(just(','))
+                .collect::<Vec<_>>()
+                .delimited_by(just::<_, _, extra::Err<Rich<char>>>('['), just(']'))
+                .report_unclosed()
+                .with_delimiter_spans()
+                .map(|(_, open, close): (Vec<_>, SimpleSpan, SimpleSpan)| (open.start, close.start))
+        });
+        let spans = list
+            .clone()
+            .separated_by(just(','))
+            .collect::<Vec<_>>()
+            .delimited_by(just('('), just(')'))
+            .with_delimiter_spans();
+
+        assert_eq!(
+            spans.parse("([],[[]])").into_result(),
+            Ok((vec![(1, 2), (4, 7)], (0..1).into(), (8..9).into())),
+        );
+
+        let errs = list.parse("[[]").into_errors();
+        assert_eq!(errs[0].unclosed_delimiter(), Some(&(0..1).into()));
+    }
+
+    #[test]
+    fn intern_only_when_output_needed() {
+        use crate::inspector::{Interner, SimpleState};
+
+        #[derive(Default)]
+        struct Counter(usize);
+
+        impl Interner<&str> for Counter {
+            type Symbol = usize;
+            fn intern(&mut self, _: &str) -> usize {
+                self.0 += 1;
+                self.0
+            }
+        }
+
+        let ident =
+            text::ascii::ident::<_, extra::Full<EmptyErr, SimpleState<Counter>, ()>>().intern();
+
+        let mut state = SimpleState(Counter::default());
+        assert_eq!(
+            ident
+                .padded()
+                .repeated()
+                .collect::<Vec<_>>()
+                .parse_with_state("a b", &mut state)
+                .into_result(),
+            Ok(vec![1, 2]),
+        );
+        assert_eq!(
+            ident
+                .padded()
+                .repeated()
+                .parse_with_state("a b", &mut state)
+                .into_result(),
+            Ok(()),
+        );
+        assert_eq!(state.0 .0, 2);
+    }
+
+    #[test]
+    #[cfg(feature = "memoization")]
+    fn memoize_by_key_separates_contexts() {
+        fn indented<'src>(
+        ) -> impl Parser<'src, &'src str, &'src str, extra::Full<EmptyErr, (), usize>> {
+            just(' ')
+                .repeated()
+                .configure(|cfg, indent: &usize| cfg.exactly(*indent))
+                .ignore_then(text::ascii::ident())
+        }
+
+        fn alternatives<'src, 'b>(
+            line: &'b impl Parser<'src, &'src str, &'src str, extra::Full<EmptyErr, (), usize>>,
+        ) -> impl Parser<'src, &'src str, &'src str> + 'b
+        where
+            'src: 'b,
+        {
+            choice((line.with_ctx(4), line.with_ctx(2)))
+        }
+
+        // Plain memoization reuses the failure from the first context
+        let line = indented().memoized();
+        assert!(alternatives(&line).parse("  foo").has_errors());
+
+        let line = indented().memoize_by_key(|indent: &usize, _| *indent);
+        assert_eq!(alternatives(&line).parse("  foo").into_result(), Ok("foo"));
+
+        // Keys are compared by value, so keys with the same hash still get their own entries
+        #[derive(PartialEq, Eq)]
+        struct Indent(usize);
+
+        impl core::hash::Hash for Indent {
+            fn hash<H: core::hash::Hasher>(&self, _: &mut H) {}
+        }
+
+        let line = indented().memoize_by_key(|indent: &usize, _| Indent(*indent));
+        assert_eq!(alternatives(&line).parse("  foo").into_result(), Ok("foo"));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trip() {
+        let parser = text::int::<_, extra::Err<Rich<char>>>(10)
+            .from_str::<u32>()
+            .unwrapped()
+            .separated_by(just(','))
+            .collect::<Vec<_>>()
+            .delimited_by(just('['), just(']'))
+            .report_unclosed();
+
+        let result = parser.parse("[1,2");
+        let json = serde_json::to_string(&result).unwrap();
+        // Labels are deserialized as owned strings, since `&'static str` can't be borrowed from the JSON
+        let back: ParseResult<Vec<u32>, Rich<char, SimpleSpan, String>> =
+            serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&back).unwrap(), json);
+        assert_eq!(back.output(), result.output());
+        assert_eq!(
+            back.errors().next().unwrap().span(),
+            &SimpleSpan::from(4..4)
+        );
+
+        let span: SimpleSpan =
+            serde_json::from_str(r#"{"start":1,"end":3,"context":null}"#).unwrap();
+        assert_eq!(span, SimpleSpan::from(1..3));
+    }
+
+    #[test]
+    fn when_ctx_gates_on_edition() {
+        fn keyword<'src>(
+        ) -> impl Parser<'src, &'src str, &'src str, extra::Full<Rich<'src, char>, (), u32>> + Clone
+        {
+            when_ctx(
+                |edition: &u32| *edition >= 2018,
+                text::keyword("async").to("<async>"),
+            )
+            .or(text::ascii::ident())
+        }
+
+        let pair = keyword()
+            .then_ignore(just(' '))
+            .then(keyword().with_ctx(2015));
+        assert_eq!(
+            pair.parse_with_ctx("async async", 2018).into_result(),
+            Ok(("<async>", "async")),
+        );
+
+        let gated = when_ctx(
+            |edition: &u32| *edition >= 2018,
+            just::<_, _, extra::Full<Rich<char>, (), u32>>("async"),
+        );
+        let errs = gated.parse_with_ctx("async", 2015).into_errors();
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs[0].span(), &SimpleSpan::from(0..1));
+        assert_eq!(errs[0].found(), Some(&'a'));
+    }
+
+    #[test]
+    fn all_of_matches_and_is() {
+        let word = || text::ascii::ident::<_, extra::Err<Simple<char>>>();
+        // Constraints may consume more or less input than the first parser without affecting the result
+        let chained = word()
+            .and_is(just("ab").not())
+            .and_is(any().repeated().at_least(5));
+        let all = all_of((word(), just("ab").not(), any().repeated().at_least(5)));
+
+        for src in ["abcdef", "xyz", "xyz-uvw", "hello world"] {
+            assert_eq!(
+                all.lazy().parse(src).into_result(),
+                chained.lazy().parse(src).into_result(),
+                "{src}",
+            );
+        }
+        assert_eq!(all.lazy().parse("hello world").into_result(), Ok("hello"));
+        assert_eq!(all_of((word(),)).parse("x").into_result(), Ok("x"));
+    }
+
+    #[test]
+    fn except_excludes_whole_matches_only() {
+        let ident = text::ascii::ident::<_, extra::Err<Simple<char>>>().except(just("let"));
+
+        assert_eq!(ident.parse("letter").into_result(), Ok("letter"));
+        assert_eq!(ident.parse("le").into_result(), Ok("le"));
+
+        let errs = ident.parse("let").into_errors();
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs[0].span(), &SimpleSpan::from(0..3));
+        assert_eq!(errs[0].found(), Some(&'l'));
+
+        // Excluded inputs can still be parsed by other alternatives
+        let term = ident.or(just("let").to("<let>"));
+        assert_eq!(term.parse("let").into_result(), Ok("<let>"));
+    }
+
+    #[test]
+    #[cfg(feature = "label")]
+    fn render_rich_snapshot() {
+        let digits = text::digits::<_, extra::Err<Rich<char>>>(10)
+            .to_slice()
+            .labelled("digits")
+            .as_context();
+        let number = just('-')
+            .or_not()
+            .then(digits)
+            .try_map(|(_, d): (_, &str), span| {
+                if d.len() > 1 && d.starts_with('0') {
+                    Err(Rich::custom(span, "leading zero").with_note("remove the zero"))
+                } else {
+                    Ok(d)
+                }
+            });
+
+        let errs = number.then_ignore(end()).parse("-x").into_errors();
+        assert_eq!(
+            errs[0].render().to_string(),
+            "at: 1..2\nfound: 'x'\nexpected: digits\n",
+        );
+
+        let errs = number.parse("07").into_errors();
+        assert_eq!(
+            errs[0].render().to_string(),
+            "at: 0..2\nmessage: leading zero\nnote: remove the zero\n",
+        );
+
+        let list = number
+            .separated_by(just(','))
+            .collect::<Vec<_>>()
+            .delimited_by(just('['), just(']'))
+            .labelled("list")
+            .as_context();
+        let errs = list.parse("[1,-]").into_errors();
+        assert_eq!(
+            errs[0].render().to_string(),
+            "at: 4..5\nfound: ']'\nexpected: digits\ncontext: list at 0..4\n",
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "excerpt")]
+    fn excerpt_widths() {
+        use crate::excerpt::excerpt;
+
+        let render = |src, span: core::ops::Range<usize>| {
+            let rendered = excerpt(src, span.into(), "msg").to_string();
+            rendered.lines().skip(3).collect::<Vec<_>>().join("\n")
+        };
+
+        // Wide characters take two columns, combining characters take none
+        assert_eq!(render("日本語!", 3..6), "1 | 日本語!\n  |   ^^");
+        assert_eq!(render("e\u{301}x", 3..4), "1 | e\u{301}x\n  |  ^");
+        // Spans over several lines are only underlined up to the end of the first
+        assert_eq!(render("ab\r\ncd", 1..6), "1 | ab\n  |  ^");
+        // Empty spans and spans at the end of input are still visible
+        assert_eq!(render("ab", 1..1), "1 | ab\n  |  ^");
+        assert_eq!(render("ab", 2..2), "1 | ab\n  |   ^");
+
+        let src = "\n".repeat(9) + "\tx";
+        assert_eq!(
+            excerpt(&src, (10..11).into(), "msg")
+                .with_tab_width(2)
+                .to_string(),
+            "msg\n  --> 10:2\n   |\n10 |   x\n   |   ^\n",
+        );
+    }
+
+    #[test]
+    fn parse_partial_returns_rest() {
+        let header = just::<_, _, extra::Err<Simple<u8>>>(b"HDR")
+            .ignore_then(any())
+            .map(|len: u8| len as usize);
+
+        let (result, rest) = header.parse_partial(b"HDR\x02ab".as_slice());
+        assert_eq!(result.into_result(), Ok(2));
+        assert_eq!(rest, b"ab");
+
+        // On failure, nothing is consumed
+        let (result, rest) = header.parse_partial(b"HDX\x02ab".as_slice());
+        assert!(result.has_errors());
+        assert_eq!(rest, b"HDX\x02ab");
+
+        // Recovered errors are reported alongside the rest of the input
+        let item = text::ascii::ident::<_, extra::Err<Simple<char>>>()
+            .then_ignore(just(';'))
+            .recover_with(via_parser(none_of(';').repeated().then(just(';')).to("?")));
+        let (result, rest) = item.parse_partial("1a; b;");
+        assert_eq!(result.output(), Some(&"?"));
+        assert_eq!(result.errors().len(), 1);
+        assert_eq!(rest, " b;");
+    }
+
+    #[test]
+    fn incomplete_errors() {
+        fn block<'src, E: extra::ParserExtra<'src, &'src str>>(
+        ) -> impl Parser<'src, &'src str, Vec<&'src str>, E> {
+            text::ascii::ident()
+                .then_ignore(just(';').padded())
+                .repeated()
+                .collect()
+                .delimited_by(just('{').padded(), just('}'))
+                .report_unclosed()
+        }
+
+        let incomplete =
+            |src| block::<extra::Err<Rich<char>>>().parse(src).into_errors()[0].is_incomplete();
+        assert!(incomplete("{ a; b"));
+        assert!(incomplete("{ a; b;"));
+        assert!(!incomplete("{ a; b }"));
+        assert!(!incomplete("{ a; 1"));
+
+        let incomplete =
+            |src| block::<extra::Err<Simple<char>>>().parse(src).into_errors()[0].is_incomplete();
+        assert!(incomplete("{ a;"));
+        assert!(!incomplete("{ a; ]"));
+
+        // Custom errors are never incomplete, even at the end of input
+        let custom = end::<&str, extra::Err<Rich<char>>>()
+            .try_map(|(), span| Err::<(), _>(Rich::custom(span, "no")));
+        assert!(!custom.parse("").into_errors()[0].is_incomplete());
+        let custom = end::<&str, extra::Err<Simple<char>>>().try_map(|(), span| {
+            Err::<(), _>(<Simple<char> as crate::Error<&str>>::custom(
+                None, span, "no",
+            ))
+        });
+        assert!(!custom.parse("").into_errors()[0].is_incomplete());
+    }
 }
@@ -577,6 +577,274 @@ where
     op_check_and_emit!();
 }
 
+/// A runtime table of infix operators, mapping each operator to its [`Associativity`] and binding power.
+///
+/// Unlike operators declared with [`infix`], the contents of the table can be changed while parsing. By keeping the
+/// table in the parser state, parsers for constructs like Haskell's fixity declarations (`infixl 6 +`) can declare new
+/// operators that take effect for the rest of the input. Operators in the table are parsed with [`infix_table`].
+///
+/// The table implements [`Inspector`] so that it can be used directly as the parser state. Changes made while parsing
+/// are undone when the parser backtracks past them, so a declaration in a branch that later fails does not take effect.
+/// If your parser needs other state too, implement [`Borrow<OperatorTable<K>>`](Borrow) for your state type instead,
+/// and forward its [`Inspector`] hooks to the table.
+#[derive(Clone)]
+pub struct OperatorTable<K> {
+    ops: Vec<(K, Associativity)>,
+    // Every change to `ops`, so that changes can be undone when rewinding. Checkpoints are lengths of this log.
+    undo: Vec<Undo<K>>,
+}
+
+#[derive(Clone)]
+enum Undo<K> {
+    Declared,
+    Redeclared(usize, Associativity),
+    Removed(usize, K, Associativity),
+}
+
+impl<K> Default for OperatorTable<K> {
+    fn default() -> Self {
+        Self {
+            ops: Vec::new(),
+            undo: Vec::new(),
+        }
+    }
+}
+
+impl<K: fmt::Debug> fmt::Debug for OperatorTable<K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OperatorTable")
+            .field("ops", &self.ops)
+            .finish()
+    }
+}
+
+impl<K: PartialEq> PartialEq for OperatorTable<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ops == other.ops
+    }
+}
+
+impl<K: Eq> Eq for OperatorTable<K> {}
+
+impl<K: PartialEq> OperatorTable<K> {
+    /// Create a new, empty operator table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declare an operator with the given associativity, replacing any earlier declaration of the same operator.
+    pub fn declare(&mut self, op: K, associativity: Associativity) {
+        match self.ops.iter().position(|(k, _)| *k == op) {
+            Some(idx) => {
+                let old = core::mem::replace(&mut self.ops[idx].1, associativity);
+                self.undo.push(Undo::Redeclared(idx, old));
+            }
+            None => {
+                self.ops.push((op, associativity));
+                self.undo.push(Undo::Declared);
+            }
+        }
+    }
+
+    /// Remove an operator from the table, returning its associativity if it was declared.
+    pub fn remove(&mut self, op: &K) -> Option<Associativity> {
+        let idx = self.ops.iter().position(|(k, _)| k == op)?;
+        let (op, associativity) = self.ops.remove(idx);
+        self.undo.push(Undo::Removed(idx, op, associativity));
+        Some(associativity)
+    }
+
+    /// Get the associativity of an operator, if it has been declared.
+    pub fn get(&self, op: &K) -> Option<Associativity> {
+        self.ops
+            .iter()
+            .find(|(k, _)| k == op)
+            .map(|(_, assoc)| *assoc)
+    }
+}
+
+impl<K> OperatorTable<K> {
+    fn undo_to(&mut self, len: usize) {
+        for undo in self.undo.drain(len..).rev() {
+            match undo {
+                Undo::Declared => {
+                    self.ops.pop();
+                }
+                Undo::Redeclared(idx, associativity) => self.ops[idx].1 = associativity,
+                Undo::Removed(idx, op, associativity) => self.ops.insert(idx, (op, associativity)),
+            }
+        }
+    }
+}
+
+impl<K: PartialEq> FromIterator<(K, Associativity)> for OperatorTable<K> {
+    fn from_iter<T: IntoIterator<Item = (K, Associativity)>>(iter: T) -> Self {
+        let mut table = Self::new();
+        for (op, associativity) in iter {
+            table.declare(op, associativity);
+        }
+        table
+    }
+}
+
+impl<'src, K, I: Input<'src>> Inspector<'src, I> for OperatorTable<K> {
+    type Checkpoint = usize;
+    #[inline(always)]
+    fn on_token(&mut self, _: &I::Token) {}
+    #[inline(always)]
+    fn on_save<'parse>(&self, _: &input::Cursor<'src, 'parse, I>) -> Self::Checkpoint {
+        self.undo.len()
+    }
+    #[inline(always)]
+    fn on_rewind<'parse>(&mut self, marker: &input::Checkpoint<'src, 'parse, I, Self::Checkpoint>) {
+        self.undo_to(*marker.inspector());
+    }
+}
+
+/// See [`infix_table`].
+pub struct InfixTable<'src, A, F, Atom, Op, I, E> {
+    op_parser: A,
+    fold: F,
+    #[allow(dead_code)]
+    phantom: EmptyPhantom<&'src (Atom, Op, I, E)>,
+}
+
+impl<A: Copy, F: Copy, Atom, Op, I, E> Copy for InfixTable<'_, A, F, Atom, Op, I, E> {}
+impl<A: Clone, F: Clone, Atom, Op, I, E> Clone for InfixTable<'_, A, F, Atom, Op, I, E> {
+    fn clone(&self) -> Self {
+        Self {
+            op_parser: self.op_parser.clone(),
+            fold: self.fold.clone(),
+            phantom: EmptyPhantom::new(),
+        }
+    }
+}
+
+/// Specify binary infix operators for a pratt parser whose associativity and binding power are looked up in an
+/// [`OperatorTable`] held by the parser state.
+///
+/// The operator parser is run first, and its output is used as the key into the table. If the operator has not been
+/// declared, the operator is not parsed and the expression ends, just as if the operator parser had failed. Because
+/// the table is consulted every time an operator is parsed, declarations made earlier in the input (for example, by a
+/// [`Parser::map_with`] that calls [`OperatorTable::declare`] on the state) apply to everything that follows them.
+///
+/// The fold function (the last argument) has the same signature as for [`infix`].
+///
+/// Keep in mind that closures like the one given to [`Parser::map_with`] only run when the output of the parser is
+/// needed, so a declaration whose output is discarded (for example, with [`Parser::ignore_then`]) will not update the
+/// table.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, pratt::*};
+/// type Extra = extra::Full<EmptyErr, OperatorTable<char>, ()>;
+///
+/// let op = one_of::<_, _, Extra>("+-*^").padded();
+/// let expr = text::int(10)
+///     .from_str::<i64>()
+///     .unwrapped()
+///     .padded()
+///     .pratt(infix_table(op, |l: i64, op, r, _| match op {
+///         '+' => l + r,
+///         '-' => l - r,
+///         '*' => l * r,
+///         _ => l.pow(r as u32),
+///     }));
+///
+/// // `infixl 6 +`, `infixl 6 -`, and `infixl 7 *`
+/// let fixity = text::ascii::keyword("infixl")
+///     .ignore_then(text::int(10).from_str::<u16>().unwrapped().padded())
+///     .then(one_of("+-*^"))
+///     .map_with(|(power, op), e| {
+///         let table: &mut OperatorTable<char> = e.state();
+///         table.declare(op, left(power));
+///     });
+/// // Each statement is either a fixity declaration or an expression
+/// let program = fixity
+///     .map(|()| None)
+///     .or(expr.clone().map(Some))
+///     .then_ignore(just(';').padded())
+///     .repeated()
+///     .collect::<Vec<_>>();
+///
+/// let mut table = OperatorTable::new();
+/// assert_eq!(
+///     program
+///         .parse_with_state("infixl 6 +; infixl 7 *; 1 + 2 * 3; infixl 5 *; 1 + 2 * 3;", &mut table)
+///         .into_result(),
+///     Ok(vec![None, None, Some(7), None, Some(9)]),
+/// );
+///
+/// // `^` was never declared, so it isn't treated as an operator
+/// assert!(expr.parse_with_state("2 ^ 3", &mut table).has_errors());
+/// ```
+pub const fn infix_table<'src, A, F, Atom, Op, I, E>(
+    op_parser: A,
+    fold: F,
+) -> InfixTable<'src, A, F, Atom, Op, I, E>
+where
+    F: Fn(Atom, Op, Atom, &mut MapExtra<'src, '_, I, E>) -> Atom,
+{
+    InfixTable {
+        op_parser,
+        fold,
+        phantom: EmptyPhantom::new(),
+    }
+}
+
+impl<'src, I, O, E, A, F, Op> Operator<'src, I, O, E> for InfixTable<'src, A, F, O, Op, I, E>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    E::State: Borrow<OperatorTable<Op>>,
+    A: Parser<'src, I, Op, E>,
+    F: Fn(O, Op, O, &mut MapExtra<'src, '_, I, E>) -> O,
+    Op: PartialEq,
+{
+    #[inline]
+    fn do_parse_infix<'parse, M: Mode>(
+        &self,
+        inp: &mut InputRef<'src, 'parse, I, E>,
+        pre_expr: &input::Cursor<'src, 'parse, I>,
+        pre_op: &input::Checkpoint<'src, 'parse, I, <E::State as Inspector<'src, I>>::Checkpoint>,
+        lhs: M::Output<O>,
+        min_power: u32,
+        f: &impl Fn(&mut InputRef<'src, 'parse, I, E>, u32) -> PResult<M, O>,
+    ) -> Result<M::Output<O>, M::Output<O>>
+    where
+        Self: Sized,
+    {
+        // The operator is always needed to look up its associativity, even when checking
+        let op = match self.op_parser.go::<Emit>(inp) {
+            Ok(op) => op,
+            Err(()) => {
+                inp.rewind(pre_op.clone());
+                return Err(lhs);
+            }
+        };
+        let table: &OperatorTable<Op> = (*inp.state()).borrow();
+        let associativity = match table.get(&op) {
+            Some(associativity) if associativity.left_power() >= min_power => associativity,
+            _ => {
+                inp.rewind(pre_op.clone());
+                return Err(lhs);
+            }
+        };
+        match f(inp, associativity.right_power()) {
+            Ok(rhs) => Ok(M::combine(lhs, rhs, |lhs, rhs| {
+                (self.fold)(lhs, op, rhs, &mut MapExtra::new(pre_expr, inp))
+            })),
+            Err(()) => {
+                inp.rewind(pre_op.clone());
+                Err(lhs)
+            }
+        }
+    }
+
+    op_check_and_emit!();
+}
+
 /// See [`prefix`].
 pub struct Prefix<'src, A, F, Atom, Op, I, E> {
     op_parser: A,
@@ -1099,11 +1367,11 @@ mod tests {
         expr_parser().then_ignore(end())
     }
 
-    fn parse(input: &str) -> ParseResult<String, Simple<char>> {
+    fn parse(input: &str) -> ParseResult<String, Simple<'_, char>> {
         complete_parser().parse(input)
     }
 
-    fn parse_partial(input: &str) -> ParseResult<String, Simple<char>> {
+    fn parse_partial(input: &str) -> ParseResult<String, Simple<'_, char>> {
         expr_parser().lazy().parse(input)
     }
 
@@ -1250,4 +1518,113 @@ mod tests {
             Ok("(((§(1 + (-(~(2!)))))$) * 3)".to_string()),
         )
     }
+
+    #[test]
+    fn operator_table_undone_on_backtrack() {
+        type Extra = extra::Full<EmptyErr, OperatorTable<char>, ()>;
+
+        let fixity = text::ascii::keyword::<_, _, Extra>("infixl")
+            .ignore_then(text::int(10).from_str::<u16>().unwrapped().padded())
+            .then(one_of("+-"))
+            .map_with(|(power, op), e| {
+                let table: &mut OperatorTable<char> = e.state();
+                table.declare(op, left(power));
+            });
+        // The first branch declares the operator before failing on the missing `;`, so the second branch must not see
+        // the declaration
+        let stmt = fixity
+            .then_ignore(just(';'))
+            .map(|()| true)
+            .or(text::ascii::keyword("infixl")
+                .then(any().repeated())
+                .to(false));
+
+        let mut table = OperatorTable::new();
+        table.declare('-', right(1));
+        assert_eq!(
+            stmt.parse_with_state("infixl 6 +", &mut table)
+                .into_result(),
+            Ok(false)
+        );
+        assert_eq!(
+            stmt.parse_with_state("infixl 6 -", &mut table)
+                .into_result(),
+            Ok(false)
+        );
+        assert_eq!(table, [('-', right(1))].into_iter().collect());
+
+        assert_eq!(
+            stmt.parse_with_state("infixl 6 +;", &mut table)
+                .into_result(),
+            Ok(true)
+        );
+        assert_eq!(table.get(&'+'), Some(left(6)));
+    }
+
+    #[test]
+    fn operator_table_in_compound_state() {
+        #[derive(Default)]
+        struct State {
+            ops: OperatorTable<char>,
+        }
+
+        impl<'src, I: Input<'src>> Inspector<'src, I> for State {
+            type Checkpoint = <OperatorTable<char> as Inspector<'src, I>>::Checkpoint;
+            fn on_token(&mut self, token: &I::Token) {
+                Inspector::<I>::on_token(&mut self.ops, token)
+            }
+            fn on_save<'parse>(&self, cursor: &input::Cursor<'src, 'parse, I>) -> Self::Checkpoint {
+                self.ops.on_save(cursor)
+            }
+            fn on_rewind<'parse>(
+                &mut self,
+                marker: &input::Checkpoint<'src, 'parse, I, Self::Checkpoint>,
+            ) {
+                self.ops.on_rewind(marker)
+            }
+        }
+
+        impl Borrow<OperatorTable<char>> for State {
+            fn borrow(&self) -> &OperatorTable<char> {
+                &self.ops
+            }
+        }
+
+        let atom = text::int::<_, extra::Full<EmptyErr, State, ()>>(10)
+            .from_str()
+            .unwrapped()
+            .map(Expr::Literal);
+        let parser = atom
+            .pratt(infix_table(one_of("+-*"), |l, op, r, _| match op {
+                '+' => i(Expr::Add, l, r),
+                '-' => i(Expr::Sub, l, r),
+                _ => i(Expr::Mul, l, r),
+            }))
+            .map(|x| x.to_string());
+
+        let mut state = State {
+            ops: [('+', left(1)), ('-', left(1)), ('*', right(2))]
+                .into_iter()
+                .collect(),
+        };
+        assert_eq!(
+            parser
+                .parse_with_state("1-2-3*4*5", &mut state)
+                .into_result(),
+            Ok("((1 - 2) - (3 * (4 * 5)))".to_string()),
+        );
+
+        // Redeclaring an operator replaces it
+        state.ops.declare('-', right(1));
+        state.ops.declare('*', left(0));
+        assert_eq!(
+            parser
+                .parse_with_state("1-2-3*4*5", &mut state)
+                .into_result(),
+            Ok("(((1 - (2 - 3)) * 4) * 5)".to_string()),
+        );
+
+        assert_eq!(state.ops.remove(&'*'), Some(left(0)));
+        assert!(parser.parse_with_state("1*2", &mut state).has_errors());
+    }
 }
@@ -13,6 +13,7 @@
 //! - [`any`]: parses any single input
 //! - [`one_of`]: parses any one of a sequence of inputs
 //! - [`none_of`]: parses any input that does not appear in a sequence of inputs
+//! - [`range`]: parses any input within an inclusive range of inputs
 //! - [`end`]: parses the end of input (i.e: if there any more inputs, this parse fails)
 
 use super::*;
@@ -165,6 +166,25 @@ where
         Self::go_cfg::<M>(self, inp, JustCfg::default())
     }
 
+    #[inline]
+    fn fails_on_peek(&self, inp: &mut InputRef<'src, '_, I, E>) -> bool {
+        let first = match self.seq.seq_iter().next() {
+            Some(first) => first,
+            // An empty sequence always matches
+            None => return false,
+        };
+        let at = inp.cursor().inner().clone();
+        match inp.token_at(&at) {
+            Some(tok) if first.borrow() == &*tok => false,
+            found => {
+                // The same error as `go_cfg`, generated without consuming the token
+                let span = inp.span_of_token_at(&at);
+                inp.add_alt(Some(Some(T::to_maybe_ref(first))), found, span);
+                true
+            }
+        }
+    }
+
     go_extra!(T);
 }
 
@@ -184,6 +204,12 @@ where
         cfg: Self::Config,
     ) -> PResult<M, T> {
         let seq = cfg.seq.as_ref().unwrap_or(&self.seq);
+
+        // Fast path: compare the whole sequence at once if the input supports it
+        if inp.match_seq(seq) {
+            return Ok(M::bind(|| seq.clone()));
+        }
+
         for next in seq.seq_iter() {
             let before = inp.save();
             match inp.next_maybe_inner() {
@@ -228,6 +254,10 @@ impl<T: Clone, I, E> Clone for OneOf<T, I, E> {
 ///
 /// The output type of this parser is `I`, the input that was found.
 ///
+/// For large sets of tokens, consider using a [`CharSet`](crate::container::CharSet) or
+/// [`ByteSet`](crate::container::ByteSet) (or the [`one_of!`](crate::one_of) macro, which builds one), which check
+/// membership without scanning the whole sequence.
+///
 /// # Examples
 ///
 /// ```
@@ -282,6 +312,98 @@ where
     go_extra!(I::Token);
 }
 
+/// See [`range`].
+pub struct TokenRange<T, I, E> {
+    start: T,
+    end: T,
+    #[allow(dead_code)]
+    phantom: EmptyPhantom<(E, I)>,
+}
+
+impl<T: Copy, I, E> Copy for TokenRange<T, I, E> {}
+impl<T: Clone, I, E> Clone for TokenRange<T, I, E> {
+    fn clone(&self) -> Self {
+        Self {
+            start: self.start.clone(),
+            end: self.end.clone(),
+            phantom: EmptyPhantom::new(),
+        }
+    }
+}
+
+/// A parser that accepts any single input within an inclusive range of inputs, such as `'a'..='z'` or `b'0'..=b'9'`.
+///
+/// Unlike an equivalent [`any`] combined with [`Parser::filter`], the range is checked by a pair of comparisons, and
+/// errors describe the range that was expected (i.e: `expected 'a'..='z'`) when the error type supports it.
+///
+/// The output type of this parser is `I::Token`, the input that was found.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, error::Simple};
+/// let lower = range::<_, extra::Err<Rich<char>>>('a'..='z')
+///     .repeated()
+///     .at_least(1)
+///     .to_slice();
+///
+/// assert_eq!(lower.parse("hello").into_result(), Ok("hello"));
+/// assert_eq!(
+///     lower.parse("Hello").into_errors()[0].to_string(),
+///     "found H expected 'a'..='z'",
+/// );
+///
+/// let digit = range::<_, extra::Err<Simple<u8>>>(b'0'..=b'9');
+///
+/// assert_eq!(digit.parse(b"7").into_result(), Ok(b'7'));
+/// assert!(digit.parse(b"x").has_errors());
+/// ```
+pub fn range<'src, I, E>(range: RangeInclusive<I::Token>) -> TokenRange<I::Token, I, E>
+where
+    I: ValueInput<'src>,
+    E: ParserExtra<'src, I>,
+    I::Token: PartialOrd + Clone,
+{
+    let (start, end) = range.into_inner();
+    TokenRange {
+        start,
+        end,
+        phantom: EmptyPhantom::new(),
+    }
+}
+
+impl<'src, I, E> Parser<'src, I, I::Token, E> for TokenRange<I::Token, I, E>
+where
+    I: ValueInput<'src>,
+    E: ParserExtra<'src, I>,
+    I::Token: PartialOrd + Clone,
+{
+    #[inline]
+    fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, I::Token> {
+        let before = inp.save();
+        match inp.next_inner() {
+            Some(tok) if self.start <= tok && tok <= self.end => Ok(M::bind(|| tok)),
+            found => {
+                let err_span = inp.span_since(before.cursor());
+                inp.rewind(before);
+                let at = inp.cursor().inner;
+                inp.add_alt_err(
+                    &at,
+                    E::Error::expected_range_found(
+                        MaybeRef::Val(self.start.clone()),
+                        MaybeRef::Val(self.end.clone()),
+                        found.map(|f| f.into()),
+                        err_span,
+                    ),
+                );
+                Err(())
+            }
+        }
+    }
+
+    go_extra!(I::Token);
+}
+
 /// See [`none_of`].
 pub struct NoneOf<T, I, E> {
     seq: T,
@@ -303,6 +425,8 @@ impl<T: Clone, I, E> Clone for NoneOf<T, I, E> {
 ///
 /// The output type of this parser is `I`, the input that was found.
 ///
+/// See also the [`none_of!`](crate::none_of) macro.
+///
 /// # Examples
 ///
 /// ```
@@ -417,6 +541,509 @@ where
     go_extra!(O);
 }
 
+/// See [`lazy`].
+#[derive(Clone)]
+pub struct Deferred<F, P> {
+    f: F,
+    parser: crate::recursive::OnceCell<P>,
+}
+
+/// A parser that defers the construction of its inner parser until it is first used, caching it thereafter.
+///
+/// This is useful for splitting expensive grammar construction across modules, or for writing mutually-recursive
+/// grammar rules as ordinary functions without having to [`declare`](Recursive::declare) and
+/// [`define`](Recursive::define) them ahead of time. Note that the inner parser is built at most once *per
+/// `lazy` parser*: when a function that builds a parser refers to itself via `lazy`, each level of nesting in the
+/// input builds (and caches) a new instance of the parser. For deeply recursive grammars, [`recursive`] is usually
+/// more efficient.
+///
+/// The output type of this parser is `O`, the same as the inner parser.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// // A list of integers, which may contain nested lists
+/// fn list<'src>() -> impl Parser<'src, &'src str, usize> {
+///     let item = text::int(10)
+///         .to(1)
+///         .or(lazy(|| list().boxed()));
+///
+///     item.separated_by(just(','))
+///         .collect::<Vec<_>>()
+///         .delimited_by(just('['), just(']'))
+///         .map(|xs| xs.iter().sum())
+/// }
+///
+/// assert_eq!(list().parse("[1,[2,3],[[4]]]").into_result(), Ok(4));
+/// ```
+pub fn lazy<'src, F, P, I, O, E>(f: F) -> Deferred<F, P>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    F: Fn() -> P,
+    P: Parser<'src, I, O, E>,
+{
+    Deferred {
+        f,
+        parser: crate::recursive::OnceCell::new(),
+    }
+}
+
+impl<'src, I, O, E, F, P> Parser<'src, I, O, E> for Deferred<F, P>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    F: Fn() -> P,
+    P: Parser<'src, I, O, E>,
+{
+    #[inline]
+    fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, O> {
+        self.parser.get_or_init(&self.f).go::<M>(inp)
+    }
+
+    go_extra!(O);
+}
+
+/// See [`bits`].
+pub struct Bits<I, E> {
+    n: usize,
+    #[allow(dead_code)]
+    phantom: EmptyPhantom<(E, I)>,
+}
+
+impl<I, E> Copy for Bits<I, E> {}
+impl<I, E> Clone for Bits<I, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+/// A parser that accepts a field of `n` bits from an input of bits (such as [`BitInput`](crate::input::BitInput)),
+/// producing the value of the field with the first bit as the most significant.
+///
+/// The output type of this parser is `u64`.
+///
+/// # Panics
+///
+/// Panics if `n` is greater than 64.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, input::BitInput, primitive::bits};
+/// let nibbles = bits::<_, extra::Err<Simple<bool>>>(4).repeated().collect::<Vec<_>>();
+///
+/// assert_eq!(nibbles.parse(BitInput::new(&[0xA5])).into_result(), Ok(vec![0xA, 0x5]));
+/// // The input ends part-way through a field
+/// assert!(bits::<_, extra::Err<Simple<bool>>>(12).parse(BitInput::new(&[0xFF])).has_errors());
+/// ```
+#[track_caller]
+pub fn bits<'src, I, E>(n: usize) -> Bits<I, E>
+where
+    I: ValueInput<'src, Token = bool>,
+    E: ParserExtra<'src, I>,
+{
+    assert!(
+        n <= 64,
+        "bits can parse fields of at most 64 bits, but {n} were requested"
+    );
+    Bits {
+        n,
+        phantom: EmptyPhantom::new(),
+    }
+}
+
+impl<'src, I, E> Parser<'src, I, u64, E> for Bits<I, E>
+where
+    I: ValueInput<'src, Token = bool>,
+    E: ParserExtra<'src, I>,
+{
+    #[inline]
+    fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, u64> {
+        let before = inp.save();
+        let mut value = 0u64;
+        for _ in 0..self.n {
+            match inp.next_inner() {
+                Some(bit) => value = (value << 1) | bit as u64,
+                None => {
+                    let span = inp.span_since(before.cursor());
+                    inp.rewind(before);
+                    inp.add_alt(None, None, span);
+                    return Err(());
+                }
+            }
+        }
+        Ok(M::bind(|| value))
+    }
+
+    go_extra!(u64);
+}
+
+/// See [`nested`].
+pub struct Nested<T, I, E> {
+    open: T,
+    close: T,
+    #[allow(dead_code)]
+    phantom: EmptyPhantom<(I, E)>,
+}
+
+impl<T: Copy, I, E> Copy for Nested<T, I, E> {}
+impl<T: Clone, I, E> Clone for Nested<T, I, E> {
+    fn clone(&self) -> Self {
+        Self {
+            open: self.open.clone(),
+            close: self.close.clone(),
+            phantom: EmptyPhantom::new(),
+        }
+    }
+}
+
+/// A parser that accepts a balanced region of input that begins with `open` and ends with the matching `close`,
+/// respecting any nested pairs of `open` and `close` within it.
+///
+/// Nothing between the delimiters is interpreted, making this useful for skipping over regions whose contents are
+/// irrelevant or are to be parsed later, such as macro bodies or embedded code blocks. If the region is never closed,
+/// the error produced carries the span of the opening delimiter (see [`Error::with_unclosed_delimiter`]).
+///
+/// The output type of this parser is `I::Slice`, the input between (but not including) the outermost delimiters.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// let block = nested::<_, extra::Err<Rich<char>>>('{', '}');
+///
+/// assert_eq!(block.parse("{}").into_result(), Ok(""));
+/// assert_eq!(block.parse("{ a { b } { { c } } }").into_result(), Ok(" a { b } { { c } } "));
+/// assert!(block.parse("{ a } }").has_errors());
+///
+/// let errs = block.parse("{ a { b }").into_errors();
+/// assert_eq!(errs[0].unclosed_delimiter(), Some(&(0..1).into()));
+///
+/// // Skip over the bodies of macros without parsing them
+/// let mac = text::ascii::ident::<_, extra::Err<Rich<char>>>()
+///     .then_ignore(just('!'))
+///     .then(nested('(', ')'));
+///
+/// assert_eq!(mac.parse("println!(\"{}\", (1 + 2))").into_result(), Ok(("println", "\"{}\", (1 + 2)")));
+/// ```
+pub const fn nested<'src, I, E>(open: I::Token, close: I::Token) -> Nested<I::Token, I, E>
+where
+    I: ValueInput<'src> + SliceInput<'src>,
+    I::Token: PartialEq,
+    E: ParserExtra<'src, I>,
+{
+    Nested {
+        open,
+        close,
+        phantom: EmptyPhantom::new(),
+    }
+}
+
+impl<'src, I, E> Parser<'src, I, I::Slice, E> for Nested<I::Token, I, E>
+where
+    I: ValueInput<'src> + SliceInput<'src>,
+    I::Token: PartialEq + Clone,
+    E: ParserExtra<'src, I>,
+{
+    #[inline]
+    fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, I::Slice> {
+        let before = inp.save();
+        match inp.next_inner() {
+            Some(tok) if tok == self.open => {}
+            found => {
+                let err_span = inp.span_since(before.cursor());
+                inp.rewind(before);
+                inp.add_alt(
+                    [Some(MaybeRef::Val(self.open.clone()))],
+                    found.map(|f| f.into()),
+                    err_span,
+                );
+                return Err(());
+            }
+        }
+        let opened_at = inp.span_since(before.cursor());
+
+        let inner_start = inp.cursor();
+        let mut depth = 1usize;
+        loop {
+            let before_tok = inp.save();
+            match inp.next_inner() {
+                Some(tok) if tok == self.close => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Ok(M::bind(|| inp.slice(&inner_start..before_tok.cursor())));
+                    }
+                }
+                Some(tok) if tok == self.open => depth += 1,
+                Some(_) => {}
+                None => {
+                    let err_span = inp.span_since(before_tok.cursor());
+                    let err = E::Error::expected_found(
+                        [Some(MaybeRef::Val(self.close.clone()))],
+                        None,
+                        err_span,
+                    )
+                    .with_unclosed_delimiter(opened_at);
+                    inp.add_alt_err(&before_tok.cursor().inner, err);
+                    return Err(());
+                }
+            }
+        }
+    }
+
+    go_extra!(I::Slice);
+}
+
+/// A parser that accepts a single token for which the given function returns `Some`, producing the returned value.
+///
+/// If the function returns `None`, an error is produced that reports the rejected token as having been found. See
+/// [`Parser::filter_map`] for a version of this that applies to the output of another parser, and [`select!`] for a
+/// more concise way to write functions that match on tokens.
+///
+/// The output type of this parser is `O`, the [`Some`] return value of the function.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// let digit = filter_map::<_, _, extra::Err<Rich<char>>, _>(|c: char| c.to_digit(10));
+///
+/// assert_eq!(digit.parse("7").into_result(), Ok(7));
+/// let errs = digit.parse("x").into_errors();
+/// assert_eq!(errs[0].found(), Some(&'x'));
+/// assert_eq!(errs[0].span(), &(0..1).into());
+/// ```
+pub const fn filter_map<'src, I, O, E, F>(f: F) -> FilterMap<Any<I, E>, I::Token, F>
+where
+    I: ValueInput<'src>,
+    E: ParserExtra<'src, I>,
+    F: Fn(I::Token) -> Option<O>,
+{
+    FilterMap {
+        parser: any(),
+        mapper: f,
+        phantom: EmptyPhantom::new(),
+    }
+}
+
+/// See [`skip_while`].
+pub struct SkipWhile<F, I, E> {
+    pred: F,
+    #[allow(dead_code)]
+    phantom: EmptyPhantom<(I, E)>,
+}
+
+impl<F: Copy, I, E> Copy for SkipWhile<F, I, E> {}
+impl<F: Clone, I, E> Clone for SkipWhile<F, I, E> {
+    fn clone(&self) -> Self {
+        Self {
+            pred: self.pred.clone(),
+            phantom: EmptyPhantom::new(),
+        }
+    }
+}
+
+/// A parser that skips over (and ignores) any number of tokens that satisfy the given predicate.
+///
+/// This is more efficient than the equivalent `any().filter(pred).repeated()` since tokens are inspected in-place and
+/// no intermediate state needs to be saved. This parser never fails.
+///
+/// The output type of this parser is `()`.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// let spaces = skip_while::<_, extra::Err<Simple<char>>, _>(|c: &char| *c == ' ');
+/// let word = text::ascii::ident().then_ignore(spaces);
+///
+/// assert_eq!(word.repeated().collect::<Vec<_>>().parse("a  b c ").into_result(), Ok(vec!["a", "b", "c"]));
+/// assert_eq!(spaces.to_span().parse("").into_result(), Ok((0..0).into()));
+/// ```
+pub const fn skip_while<'src, I, E, F>(pred: F) -> SkipWhile<F, I, E>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    F: Fn(&I::Token) -> bool,
+{
+    SkipWhile {
+        pred,
+        phantom: EmptyPhantom::new(),
+    }
+}
+
+impl<'src, I, E, F> Parser<'src, I, (), E> for SkipWhile<F, I, E>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    F: Fn(&I::Token) -> bool,
+{
+    #[inline]
+    fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, ()> {
+        inp.skip_while(&self.pred);
+        Ok(M::bind(|| ()))
+    }
+
+    go_extra!(());
+}
+
+/// See [`current_column`].
+pub struct CurrentColumn<I, E> {
+    tab_width: usize,
+    #[allow(dead_code)]
+    phantom: EmptyPhantom<(I, E)>,
+}
+
+impl<I, E> Copy for CurrentColumn<I, E> {}
+impl<I, E> Clone for CurrentColumn<I, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<I, E> CurrentColumn<I, E> {
+    /// Set the number of columns between tab stops. A tab advances the column to the next tab stop.
+    ///
+    /// A tab width of `1` (the default) treats tabs like any other token.
+    pub fn with_tab_width(self, tab_width: usize) -> Self {
+        assert!(tab_width > 0, "tab width must be greater than zero");
+        Self { tab_width, ..self }
+    }
+}
+
+/// A parser that produces the column of the current position in the input, counting from `1`, without consuming
+/// anything. This parser never fails.
+///
+/// This is useful for layout rules, like requiring continuation lines to be indented further than the line they
+/// continue. See [`InputRef::current_column`] for how columns are counted.
+///
+/// The output type of this parser is `usize`.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// let word = current_column::<_, extra::Err<Simple<char>>>()
+///     .with_tab_width(4)
+///     .then(text::ascii::ident())
+///     .padded();
+/// let words = word.repeated().collect::<Vec<_>>();
+///
+/// assert_eq!(
+///     words.parse("if x\n\tthen\n  else").into_result(),
+///     Ok(vec![(1, "if"), (4, "x"), (5, "then"), (3, "else")]),
+/// );
+/// ```
+pub const fn current_column<'src, I, E>() -> CurrentColumn<I, E>
+where
+    I: StrInput<'src>,
+    I::Token: Char,
+    E: ParserExtra<'src, I>,
+{
+    CurrentColumn {
+        tab_width: 1,
+        phantom: EmptyPhantom::new(),
+    }
+}
+
+impl<'src, I, E> Parser<'src, I, usize, E> for CurrentColumn<I, E>
+where
+    I: StrInput<'src>,
+    I::Token: Char,
+    E: ParserExtra<'src, I>,
+{
+    #[inline]
+    fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, usize> {
+        let col = inp.current_column(self.tab_width);
+        Ok(M::bind(|| col))
+    }
+
+    go_extra!(usize);
+}
+
+/// See [`skip_until`].
+pub struct SkipUntil<A, OA, I, E> {
+    until: A,
+    #[allow(dead_code)]
+    phantom: EmptyPhantom<(OA, I, E)>,
+}
+
+impl<A: Copy, OA, I, E> Copy for SkipUntil<A, OA, I, E> {}
+impl<A: Clone, OA, I, E> Clone for SkipUntil<A, OA, I, E> {
+    fn clone(&self) -> Self {
+        Self {
+            until: self.until.clone(),
+            phantom: EmptyPhantom::new(),
+        }
+    }
+}
+
+/// A parser that skips over (and ignores) tokens until the given pattern would match, without consuming the pattern
+/// itself.
+///
+/// If the end of input is reached without the pattern matching, this parser fails with the error produced by the
+/// pattern at the end of the input. Unlike [`recovery::skip_until`], this is a regular parser rather than an error
+/// recovery strategy, so it is not included in the [`prelude`].
+///
+/// The output type of this parser is `()`.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, primitive::skip_until};
+/// let comment = just::<_, _, extra::Err<Simple<char>>>("/*")
+///     .then(skip_until(just("*/")))
+///     .then(just("*/"))
+///     .to_slice();
+///
+/// assert_eq!(comment.parse("/* a * b / c */").into_result(), Ok("/* a * b / c */"));
+/// assert!(comment.parse("/* unterminated").has_errors());
+/// ```
+pub const fn skip_until<'src, A, OA, I, E>(until: A) -> SkipUntil<A, OA, I, E>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    A: Parser<'src, I, OA, E>,
+{
+    SkipUntil {
+        until,
+        phantom: EmptyPhantom::new(),
+    }
+}
+
+impl<'src, A, OA, I, E> Parser<'src, I, (), E> for SkipUntil<A, OA, I, E>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    A: Parser<'src, I, OA, E>,
+{
+    #[inline]
+    fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, ()> {
+        let old_alt = inp.take_alt();
+        loop {
+            let before = inp.save();
+            let res = self.until.go::<Check>(inp);
+            inp.rewind(before);
+            if res.is_ok() {
+                inp.errors.alt = old_alt;
+                break Ok(M::bind(|| ()));
+            }
+            if inp.next_maybe_inner().is_none() {
+                // Keep the error from the final attempt, at the end of input
+                if let Some(old_alt) = old_alt {
+                    inp.add_alt_err(&old_alt.pos, old_alt.err);
+                }
+                break Err(());
+            }
+            inp.errors.alt = None;
+        }
+    }
+
+    go_extra!(());
+}
+
 /// See [`select!`].
 pub struct Select<F, I, O, E> {
     filter: F,
@@ -756,6 +1383,82 @@ where
     }
 }
 
+/// See [`when_ctx`].
+pub struct WhenCtx<A, F> {
+    pub(crate) parser: A,
+    pub(crate) pred: F,
+}
+
+impl<A: Copy, F: Copy> Copy for WhenCtx<A, F> {}
+impl<A: Clone, F: Clone> Clone for WhenCtx<A, F> {
+    fn clone(&self) -> Self {
+        WhenCtx {
+            parser: self.parser.clone(),
+            pred: self.pred.clone(),
+        }
+    }
+}
+
+impl<'src, I, O, E, A, F> Parser<'src, I, O, E> for WhenCtx<A, F>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    A: Parser<'src, I, O, E>,
+    F: Fn(&E::Context) -> bool,
+{
+    #[inline]
+    fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, O> {
+        if (self.pred)(inp.ctx()) {
+            self.parser.go::<M>(inp)
+        } else {
+            let before = inp.save();
+            let found = inp.next_maybe_inner();
+            let span = inp.span_since(before.cursor());
+            inp.rewind(before);
+            inp.add_alt(None, found.map(|f| f.into()), span);
+            Err(())
+        }
+    }
+
+    go_extra!(O);
+}
+
+/// Only attempt the given parser if the [context](extra::ParserExtra::Context) satisfies a predicate, failing without
+/// consuming input otherwise.
+///
+/// This is useful for grammars with several editions or optional extensions: rather than duplicating the rules that
+/// differ between versions, constructs can be gated on a value in the context, and the context chosen with
+/// [`Parser::parse_with_ctx`] (or overridden for part of the grammar with [`Parser::with_ctx`]).
+///
+/// The output type of this parser is the output type of the given parser.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// #[derive(Clone, Copy, PartialEq, PartialOrd)]
+/// enum Edition { E2015, E2018 }
+///
+/// // `.await` syntax only exists from the 2018 edition onwards
+/// let await_ = when_ctx(|ed: &Edition| *ed >= Edition::E2018, just(".await"));
+/// let expr = text::ascii::ident::<_, extra::Context<Edition>>()
+///     .then(await_.or_not().map(|a| a.is_some()))
+///     .then_ignore(end());
+///
+/// assert_eq!(expr.parse_with_ctx("foo.await", Edition::E2018).into_result(), Ok(("foo", true)));
+/// assert_eq!(expr.parse_with_ctx("foo", Edition::E2015).into_result(), Ok(("foo", false)));
+/// assert!(expr.parse_with_ctx("foo.await", Edition::E2015).has_errors());
+/// ```
+pub const fn when_ctx<'src, A, O, I, E, F>(pred: F, parser: A) -> WhenCtx<A, F>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    A: Parser<'src, I, O, E>,
+    F: Fn(&E::Context) -> bool,
+{
+    WhenCtx { parser, pred }
+}
+
 /// See [`fn@todo`].
 pub struct Todo<I, O, E> {
     location: Location<'static>,
@@ -822,7 +1525,7 @@ where
 /// See [`choice`].
 #[derive(Copy, Clone)]
 pub struct Choice<T> {
-    parsers: T,
+    pub(crate) parsers: T,
 }
 
 /// Parse using a tuple of many parsers, producing the output of the first to successfully parse.
@@ -871,6 +1574,31 @@ pub struct Choice<T> {
 ///     Ok(vec![If, Int(56), For, Ident("foo"), While, Int(42), Fn, Ident("bar")]),
 /// );
 /// ```
+///
+/// # Runtime-sized choices
+///
+/// As well as tuples, `choice` accepts arrays, slices, and [`Vec`]s of parsers. Combined with [`Parser::boxed`], this
+/// allows the set of alternatives to be decided at runtime, such as when a plugin system registers extra syntax.
+///
+/// The alternatives are tried in order, and the first to succeed wins: later parsers are never attempted, even if they
+/// would have consumed more input. If every alternative fails, the errors they produced are merged in the usual way,
+/// so the resulting error lists everything that was expected at the furthest point reached. An empty list of parsers
+/// always fails without consuming any input.
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// let mut stmts: Vec<Boxed<&str, &str, extra::Err<Simple<char>>>> = vec![
+///     text::ascii::keyword("let").to_slice().boxed(),
+///     text::ascii::keyword("fn").to_slice().boxed(),
+/// ];
+///
+/// // A plugin adds a new kind of statement
+/// stmts.push(text::ascii::keyword("macro").to_slice().boxed());
+///
+/// let stmt = choice(stmts);
+/// assert_eq!(stmt.parse("macro").into_result(), Ok("macro"));
+/// assert!(stmt.parse("struct").has_errors());
+/// ```
 pub const fn choice<T>(parsers: T) -> Choice<T> {
     Choice { parsers }
 }
@@ -1112,3 +1840,120 @@ impl_group_for_tuple! {
     Y_ OY
     Z_ OZ
 }
+
+/// See [`all_of`].
+pub struct AllOf<T, O> {
+    parsers: T,
+    #[allow(dead_code)]
+    phantom: EmptyPhantom<O>,
+}
+
+impl<T: Copy, O> Copy for AllOf<T, O> {}
+impl<T: Clone, O> Clone for AllOf<T, O> {
+    fn clone(&self) -> Self {
+        Self {
+            parsers: self.parsers.clone(),
+            phantom: EmptyPhantom::new(),
+        }
+    }
+}
+
+/// Parse using the first of a tuple of parsers, but only succeed if every other parser in the tuple also succeeds
+/// when run from the same position.
+///
+/// The output and the input consumed are those of the first parser: the others act as lookahead constraints, and
+/// their outputs are discarded. Like [`Parser::and_is`], the constraints are allowed to consume more or less input
+/// than the first parser. `all_of((a, b, c))` is equivalent to `a.and_is(b).and_is(c)`, but reads better when there
+/// are several constraints on the same piece of input.
+///
+/// The output type of this parser is the output type of the first parser in the tuple.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// // An identifier that isn't a keyword and isn't the start of a path like `foo::bar`
+/// let local = all_of((
+///     text::ascii::ident::<_, extra::Err<Simple<char>>>(),
+///     choice((text::keyword("let"), text::keyword("fn"))).not(),
+///     text::ascii::ident().then(just("::")).not(),
+/// ));
+///
+/// assert_eq!(local.parse("foo").into_result(), Ok("foo"));
+/// assert_eq!(local.parse("letter").into_result(), Ok("letter"));
+/// assert!(local.parse("let").has_errors());
+/// assert!(local.parse("foo::bar").has_errors());
+/// ```
+pub const fn all_of<T, O>(parsers: T) -> AllOf<T, O> {
+    AllOf {
+        parsers,
+        phantom: EmptyPhantom::new(),
+    }
+}
+
+macro_rules! impl_all_of_for_tuple {
+    () => {};
+    ($head:ident $ohead:ident $($X:ident $O:ident)*) => {
+        impl_all_of_for_tuple!($($X $O)*);
+        impl_all_of_for_tuple!(~ $head $ohead $($X $O)*);
+    };
+    (~ $Head:ident $OHead:ident $($X:ident $O:ident)*) => {
+        #[allow(unused_variables, non_snake_case)]
+        impl<'src, I, E, $Head, $OHead, $($X,)* $($O,)*> Parser<'src, I, $OHead, E>
+            for AllOf<($Head, $($X,)*), ($OHead, $($O,)*)>
+        where
+            I: Input<'src>,
+            E: ParserExtra<'src, I>,
+            $Head: Parser<'src, I, $OHead, E>,
+            $($X: Parser<'src, I, $O, E>),*
+        {
+            #[inline]
+            fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, $OHead> {
+                let AllOf { parsers: ($Head, $($X,)*), .. } = self;
+
+                let before = inp.save();
+                let out = $Head.go::<M>(inp)?;
+                let after = inp.save();
+
+                $(
+                    inp.rewind(before.clone());
+                    $X.go::<Check>(inp)?;
+                )*
+
+                inp.rewind(after);
+                Ok(out)
+            }
+
+            go_extra!($OHead);
+        }
+    };
+}
+
+impl_all_of_for_tuple! {
+    A_ OA
+    B_ OB
+    C_ OC
+    D_ OD
+    E_ OE
+    F_ OF
+    G_ OG
+    H_ OH
+    I_ OI
+    J_ OJ
+    K_ OK
+    L_ OL
+    M_ OM
+    N_ ON
+    O_ OO
+    P_ OP
+    Q_ OQ
+    R_ OR
+    S_ OS
+    T_ OT
+    U_ OU
+    V_ OV
+    W_ OW
+    X_ OX
+    Y_ OY
+    Z_ OZ
+}
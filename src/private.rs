@@ -4,12 +4,18 @@ use super::*;
 pub(crate) struct Located<T, E> {
     pub(crate) pos: T,
     pub(crate) err: E,
+    pub(crate) severity: Severity,
 }
 
 impl<T, E> Located<T, E> {
     #[inline]
     pub fn at(pos: T, err: E) -> Self {
-        Self { pos, err }
+        Self::with_severity(pos, err, Severity::Error)
+    }
+
+    #[inline]
+    pub fn with_severity(pos: T, err: E, severity: Severity) -> Self {
+        Self { pos, err, severity }
     }
 }
 
@@ -54,6 +54,249 @@ where
     }
 }
 
+/// See [`insert_missing`].
+#[must_use]
+#[derive(Copy, Clone)]
+pub struct InsertMissing<O>(O);
+
+impl<O> Sealed for InsertMissing<O> {}
+impl<'src, I, O, E> Strategy<'src, I, O, E> for InsertMissing<O>
+where
+    I: Input<'src>,
+    O: Clone,
+    E: ParserExtra<'src, I>,
+{
+    fn recover<M: Mode, P: Parser<'src, I, O, E>>(
+        &self,
+        inp: &mut InputRef<'src, '_, I, E>,
+        _parser: &P,
+    ) -> PResult<M, O> {
+        let alt = inp.take_alt().unwrap(); // Can't fail!
+        inp.emit(None, alt.err);
+        Ok(M::bind(|| self.0.clone()))
+    }
+}
+
+/// A recovery strategy that behaves as if the input that the parser expected was present, producing the given output
+/// without consuming any input.
+///
+/// The error that the parser generated (such as "expected `;`") is still emitted, and parsing continues from the
+/// position at which the parser failed. This is useful for recovering from missing punctuation, like a forgotten
+/// semicolon or closing delimiter.
+///
+/// Because this strategy always succeeds, it should only be used on parsers that accept a single token (or a short,
+/// fixed sequence of tokens). It should not be used on a parser that is repeated, since recovering without consuming
+/// input would cause the repetition to never end.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, error::Simple};
+/// let stmt = text::ascii::ident::<_, extra::Err<Simple<char>>>()
+///     .padded()
+///     .then_ignore(just(';').recover_with(insert_missing(';')));
+/// let stmts = stmt.repeated().collect::<Vec<_>>();
+///
+/// let res = stmts.parse("a; b c;");
+/// // The missing semicolon after `b` was reported...
+/// assert_eq!(res.errors().len(), 1);
+/// assert_eq!(res.errors().next().unwrap().span(), &SimpleSpan::new(5, 6));
+/// // ...but parsing continued as if it were present
+/// assert_eq!(res.output(), Some(&vec!["a", "b", "c"]));
+/// ```
+pub fn insert_missing<O>(output: O) -> InsertMissing<O> {
+    InsertMissing(output)
+}
+
+/// See [`skip_one`].
+#[must_use]
+#[derive(Copy, Clone)]
+pub struct SkipOne {
+    at_most: usize,
+}
+
+impl SkipOne {
+    /// Allow up to the given number of tokens to be skipped, one at a time, before giving up on recovery.
+    ///
+    /// By default, only a single token may be skipped.
+    pub fn repeated_at_most(self, at_most: usize) -> Self {
+        Self { at_most }
+    }
+}
+
+impl Sealed for SkipOne {}
+impl<'src, I, O, E> Strategy<'src, I, O, E> for SkipOne
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+{
+    fn recover<M: Mode, P: Parser<'src, I, O, E>>(
+        &self,
+        inp: &mut InputRef<'src, '_, I, E>,
+        parser: &P,
+    ) -> PResult<M, O> {
+        let alt = inp.take_alt().unwrap(); // Can't fail!
+        for _ in 0..self.at_most {
+            if inp.next_maybe_inner().is_none() {
+                break;
+            }
+
+            let before = inp.save();
+            if let Some(out) = parser.go::<M>(inp).ok().filter(|_| {
+                inp.errors
+                    .secondary_errors_since(before.err_count)
+                    .is_empty()
+            }) {
+                inp.emit(None, alt.err);
+                return Ok(out);
+            } else {
+                inp.errors.alt.take();
+                inp.rewind(before);
+            }
+        }
+        inp.errors.alt = Some(alt);
+        Err(())
+    }
+}
+
+/// A recovery strategy that deletes unexpected tokens: it skips a single token and then retries the parser.
+///
+/// If the parser still fails, further tokens may be skipped (one at a time, retrying the parser after each) up to the
+/// limit given by [`SkipOne::repeated_at_most`]. If the parser succeeds, the error that it originally generated is
+/// emitted. If recovery fails, the original error is reported as if no recovery was attempted.
+///
+/// Retries must succeed without needing to recover from errors themselves.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, error::Simple};
+/// let item = text::int::<_, extra::Err<Simple<char>>>(10)
+///     .recover_with(skip_one().repeated_at_most(2));
+/// let list = item.separated_by(just(',')).collect::<Vec<_>>();
+///
+/// // Stray characters are skipped...
+/// let res = list.parse("1,#2,@!3");
+/// assert_eq!(res.errors().len(), 2);
+/// assert_eq!(res.output(), Some(&vec!["1", "2", "3"]));
+/// // ...but only up to the limit
+/// assert!(list.parse("1,???2").into_result().is_err());
+/// ```
+pub fn skip_one() -> SkipOne {
+    SkipOne { at_most: 1 }
+}
+
+/// A parser context that declares a set of synchronization points: tokens at which error recovery can safely resume
+/// parsing, such as `;`, `}`, or keywords that begin a statement.
+///
+/// Declaring synchronization points once, as part of the context, means that they don't need to be repeated at every
+/// call site that performs recovery. Recovery strategies like [`skip_to_sync`] consult the synchronization points of
+/// the current context. Because contexts can be overridden for part of a parser with [`Parser::with_ctx`], different
+/// regions of a grammar may use different synchronization points.
+///
+/// This trait is implemented for arrays, slices, and [`Vec`]s of tokens. Implement it for your own context type to
+/// combine synchronization points with other configuration.
+pub trait SyncPoints<T> {
+    /// Returns true if the given token is a synchronization point.
+    fn is_sync_point(&self, token: &T) -> bool;
+}
+
+impl<T: PartialEq, const N: usize> SyncPoints<T> for [T; N] {
+    fn is_sync_point(&self, token: &T) -> bool {
+        self.contains(token)
+    }
+}
+
+impl<T: PartialEq> SyncPoints<T> for [T] {
+    fn is_sync_point(&self, token: &T) -> bool {
+        self.contains(token)
+    }
+}
+
+impl<T: PartialEq> SyncPoints<T> for Vec<T> {
+    fn is_sync_point(&self, token: &T) -> bool {
+        self.as_slice().contains(token)
+    }
+}
+
+impl<T, S: SyncPoints<T> + ?Sized> SyncPoints<T> for &S {
+    fn is_sync_point(&self, token: &T) -> bool {
+        (**self).is_sync_point(token)
+    }
+}
+
+/// See [`skip_to_sync`].
+#[must_use]
+#[derive(Copy, Clone)]
+pub struct SkipToSync<F> {
+    fallback: F,
+}
+
+impl<F> Sealed for SkipToSync<F> {}
+impl<'src, I, O, E, F> Strategy<'src, I, O, E> for SkipToSync<F>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    E::Context: SyncPoints<I::Token>,
+    F: Fn() -> O,
+{
+    fn recover<M: Mode, P: Parser<'src, I, O, E>>(
+        &self,
+        inp: &mut InputRef<'src, '_, I, E>,
+        _parser: &P,
+    ) -> PResult<M, O> {
+        let alt = inp.take_alt().unwrap(); // Can't fail!
+        let mut skipped = false;
+        loop {
+            match inp.peek_maybe() {
+                Some(tok) if !inp.ctx().is_sync_point(&tok) => {
+                    inp.next_maybe_inner();
+                    skipped = true;
+                }
+                _ => break,
+            }
+        }
+
+        if skipped {
+            inp.emit(None, alt.err);
+            Ok(M::bind(|| (self.fallback)()))
+        } else {
+            inp.errors.alt = Some(alt);
+            Err(())
+        }
+    }
+}
+
+/// A recovery strategy that skips input until the next synchronization point declared by the parser's context (see
+/// [`SyncPoints`]), or the end of the input, and then generates a fallback output.
+///
+/// The synchronization point itself is not consumed, so that the parser that follows (or an enclosing parser, such as
+/// a block waiting for its closing `}`) may use it. At least one token must be skipped: if the parser failed at a
+/// synchronization point, recovery fails too.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, error::Simple};
+/// type Extra = extra::Full<Simple<'static, char>, (), [char; 2]>;
+///
+/// let stmt = text::ascii::ident::<_, Extra>()
+///     .map(Some)
+///     .padded()
+///     .recover_with(skip_to_sync(|| None));
+/// let block = stmt
+///     .separated_by(just(';'))
+///     .collect::<Vec<_>>()
+///     .delimited_by(just('{'), just('}'));
+///
+/// let res = block.parse_with_ctx("{a; 1 + 2; c}", [';', '}']);
+/// assert_eq!(res.errors().len(), 1);
+/// assert_eq!(res.output(), Some(&vec![Some("a"), None, Some("c")]));
+/// ```
+pub fn skip_to_sync<F>(fallback: F) -> SkipToSync<F> {
+    SkipToSync { fallback }
+}
+
 /// See [`Parser::recover_with`].
 #[derive(Copy, Clone)]
 pub struct RecoverWith<A, S> {
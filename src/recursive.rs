@@ -10,7 +10,8 @@
 
 use super::*;
 
-struct OnceCell<T>(core::cell::Cell<Option<T>>);
+// A minimal `OnceCell`, since `core::cell::OnceCell` is newer than our MSRV
+pub(crate) struct OnceCell<T>(core::cell::Cell<Option<T>>);
 impl<T> OnceCell<T> {
     pub fn new() -> Self {
         Self(core::cell::Cell::new(None))
@@ -33,6 +34,21 @@ impl<T> OnceCell<T> {
         // neither function is possibly reentrant so there's no way we can invalidate mut xor shared aliasing
         unsafe { (*self.0.as_ptr()).as_ref() }
     }
+    #[inline]
+    pub fn get_or_init(&self, f: impl FnOnce() -> T) -> &T {
+        if self.get().is_none() {
+            let x = f();
+            // `f` might have initialised the cell itself, in which case the first value wins
+            let _ = self.set(x);
+        }
+        self.get().unwrap()
+    }
+}
+
+impl<T: Clone> Clone for OnceCell<T> {
+    fn clone(&self) -> Self {
+        Self(core::cell::Cell::new(self.get().cloned()))
+    }
 }
 
 // TODO: Ensure that this doesn't produce leaks
@@ -51,12 +67,91 @@ pub struct Indirect<'src, 'b, I: Input<'src>, O, Extra: ParserExtra<'src, I>> {
     inner: OnceCell<Box<DynParser<'src, 'b, I, O, Extra>>>,
 }
 
+/// Information used to diagnose misuse of recursive parsers in debug builds.
+#[cfg(debug_assertions)]
+struct RecursiveDebug {
+    location: Location<'static>,
+    name: Cell<Option<&'static str>>,
+    // Where the parser was most recently entered, if it is active, and how many times in a row it has been entered
+    // there without consuming input
+    active: Cell<Option<(ActivePos, usize)>>,
+}
+
+/// How many times a recursive parser may invoke itself in a row without consuming input before it is assumed to be
+/// left-recursive.
+///
+/// Re-entering a parser at the same position isn't always a mistake: the state or context may have changed in between,
+/// bounding the recursion. Only a long run of re-entries is reported, since a grammar that genuinely recurses forever
+/// will reach it quickly.
+#[cfg(debug_assertions)]
+const MAX_REENTRIES: usize = 64;
+
+/// The position at which a recursive parser was entered.
+///
+/// Nested inputs (see [`Parser::nested_in`]) start again from offset zero, so the input being parsed is identified by
+/// its error storage, which is unique to each input for the duration of its parse. Entering a memoized parser adds a
+/// memo, so the number of memos is recorded too: the parser is only stuck if none were added since it was last entered.
+#[cfg(debug_assertions)]
+#[derive(Copy, Clone, PartialEq)]
+struct ActivePos {
+    input: usize,
+    offset: usize,
+    memos: usize,
+}
+
+#[cfg(debug_assertions)]
+impl RecursiveDebug {
+    #[track_caller]
+    fn new() -> Rc<Self> {
+        Rc::new(Self {
+            location: *Location::caller(),
+            name: Cell::new(None),
+            active: Cell::new(None),
+        })
+    }
+}
+
+#[cfg(debug_assertions)]
+impl fmt::Display for RecursiveDebug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.name.get() {
+            Some(name) => write!(f, "Recursive parser `{name}` declared at {}", self.location),
+            None => write!(f, "Recursive parser declared at {}", self.location),
+        }
+    }
+}
+
+/// Marks a recursive parser as active for as long as it lives. See [`Recursive::enter`].
+#[cfg(debug_assertions)]
+struct ActiveGuard<'a> {
+    active: &'a Cell<Option<(ActivePos, usize)>>,
+    prev: Option<(ActivePos, usize)>,
+}
+
+#[cfg(debug_assertions)]
+impl Drop for ActiveGuard<'_> {
+    fn drop(&mut self) {
+        self.active.set(self.prev);
+    }
+}
+
 /// A parser that can be defined in terms of itself by separating its [declaration](Recursive::declare) from its
 /// [definition](Recursive::define).
 ///
 /// Prefer to use [`recursive()`], which exists as a convenient wrapper around both operations, if possible.
+///
+/// In debug builds, misuse of recursive parsers is detected and reported with the location at which the parser was
+/// declared (and its name, if it was given one with [`Recursive::with_name`]) when it is first used to parse:
+///
+/// - Using the parser to parse input before it has been defined will panic.
+/// - A parser that keeps invoking itself without consuming any input in between (i.e: it is left-recursive) will panic
+///   instead of recursing until the stack overflows. Left recursion that passes through a [`Parser::memoized`] parser
+///   is permitted, since memoization breaks the cycle, as is re-entering the parser at the same position a bounded
+///   number of times (for example, when the parser state or context changes on each entry).
 pub struct Recursive<P: ?Sized> {
     inner: RecursiveInner<P>,
+    #[cfg(debug_assertions)]
+    debug: Rc<RecursiveDebug>,
 }
 
 impl<'src, 'b, I: Input<'src>, O, E: ParserExtra<'src, I>> Recursive<Indirect<'src, 'b, I, O, E>> {
@@ -98,11 +193,14 @@ impl<'src, 'b, I: Input<'src>, O, E: ParserExtra<'src, I>> Recursive<Indirect<'s
     ///     Ok(Chain::Link('+', Box::new(Chain::Link('+', Box::new(Chain::End))))),
     /// );
     /// ```
+    #[track_caller]
     pub fn declare() -> Self {
         Recursive {
             inner: RecursiveInner::Owned(Rc::new(Indirect {
                 inner: OnceCell::new(),
             })),
+            #[cfg(debug_assertions)]
+            debug: RecursiveDebug::new(),
         }
     }
 
@@ -121,13 +219,95 @@ impl<'src, 'b, I: Input<'src>, O, E: ParserExtra<'src, I>> Recursive<Indirect<'s
 }
 
 impl<P: ?Sized> Recursive<P> {
+    /// Create a handle to this parser that does not keep it alive, for use in situations where the parser is
+    /// guaranteed to be kept alive elsewhere (avoiding reference cycles).
+    pub(crate) fn downgrade(&self) -> Self {
+        Self {
+            inner: RecursiveInner::Unowned(match &self.inner {
+                RecursiveInner::Owned(x) => Rc::downgrade(x),
+                RecursiveInner::Unowned(x) => x.clone(),
+            }),
+            #[cfg(debug_assertions)]
+            debug: self.debug.clone(),
+        }
+    }
+
     #[inline]
     fn parser(&self) -> Rc<P> {
         match &self.inner {
             RecursiveInner::Owned(x) => x.clone(),
-            RecursiveInner::Unowned(x) => x
-                .upgrade()
-                .expect("Recursive parser used before being defined"),
+            RecursiveInner::Unowned(x) => {
+                x.upgrade().unwrap_or_else(|| self.used_before_definition())
+            }
+        }
+    }
+
+    /// Give this parser a name, used to identify it when misuse is detected in debug builds.
+    ///
+    /// The name is shared with every clone of the parser, including those that were used to define it. In release
+    /// builds, this has no effect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::{prelude::*, recursive::Indirect};
+    /// use std::panic::{catch_unwind, AssertUnwindSafe};
+    ///
+    /// // Oops, the parser is never defined!
+    /// let expr = Recursive::<Indirect<&str, (), extra::Default>>::declare().with_name("expr");
+    ///
+    /// // In debug builds, this panics with "Recursive parser `expr` declared at [...] used before being defined"
+    /// assert!(catch_unwind(AssertUnwindSafe(|| expr.parse(""))).is_err());
+    /// ```
+    pub fn with_name(self, name: &'static str) -> Self {
+        #[cfg(debug_assertions)]
+        self.debug.name.set(Some(name));
+        #[cfg(not(debug_assertions))]
+        let _ = name;
+        self
+    }
+
+    #[cold]
+    fn used_before_definition(&self) -> ! {
+        #[cfg(debug_assertions)]
+        panic!("{} used before being defined", self.debug);
+        #[cfg(not(debug_assertions))]
+        panic!("Recursive parser used before being defined");
+    }
+
+    /// Mark this parser as active at the current input position, panicking if it has already been entered at the same
+    /// position more than [`MAX_REENTRIES`] times in a row (since it is then most likely recursing forever).
+    #[cfg(debug_assertions)]
+    #[inline]
+    fn enter<'src, I, E>(&self, inp: &InputRef<'src, '_, I, E>) -> ActiveGuard<'_>
+    where
+        I: Input<'src>,
+        E: ParserExtra<'src, I>,
+    {
+        let pos = ActivePos {
+            input: &*inp.errors as *const _ as usize,
+            offset: I::cursor_location(&inp.cursor().inner),
+            #[cfg(feature = "memoization")]
+            memos: inp.memos.len(),
+            #[cfg(not(feature = "memoization"))]
+            memos: 0,
+        };
+        let prev = self.debug.active.get();
+        let reentries = match prev {
+            Some((prev_pos, n)) if prev_pos == pos => n + 1,
+            _ => 0,
+        };
+        if reentries > MAX_REENTRIES {
+            panic!(
+                "{} invoked itself without consuming any input (left recursion), which would recurse forever. \
+                 Consider making the grammar right-recursive or using `Parser::memoized`.",
+                self.debug,
+            );
+        }
+        self.debug.active.set(Some((pos, reentries)));
+        ActiveGuard {
+            active: &self.debug.active,
+            prev,
         }
     }
 }
@@ -139,6 +319,8 @@ impl<P: ?Sized> Clone for Recursive<P> {
                 RecursiveInner::Owned(x) => RecursiveInner::Owned(x.clone()),
                 RecursiveInner::Unowned(x) => RecursiveInner::Unowned(x.clone()),
             },
+            #[cfg(debug_assertions)]
+            debug: self.debug.clone(),
         }
     }
 }
@@ -161,12 +343,14 @@ where
 {
     #[inline]
     fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, O> {
+        #[cfg(debug_assertions)]
+        let _active = self.enter(inp);
         recurse(move || {
             M::invoke(
                 self.parser()
                     .inner
                     .get()
-                    .expect("Recursive parser used before being defined")
+                    .unwrap_or_else(|| self.used_before_definition())
                     .as_ref(),
                 inp,
             )
@@ -183,6 +367,8 @@ where
 {
     #[inline]
     fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, O> {
+        #[cfg(debug_assertions)]
+        let _active = self.enter(inp);
         recurse(move || M::invoke(&*self.parser(), inp))
     }
 
@@ -239,6 +425,7 @@ where
 /// ])));
 /// ```
 // INFO: Clone bound not actually needed, but good to be safe for future compat
+#[track_caller]
 pub fn recursive<'src, 'b, I, O, E, A, F>(f: F) -> Recursive<Direct<'src, 'b, I, O, E>>
 where
     I: Input<'src>,
@@ -246,10 +433,15 @@ where
     A: Parser<'src, I, O, E> + Clone + 'b,
     F: FnOnce(Recursive<Direct<'src, 'b, I, O, E>>) -> A,
 {
+    #[cfg(debug_assertions)]
+    let debug = RecursiveDebug::new();
+
     let rc = Rc::new_cyclic(|rc| {
         let rc: rc::Weak<DynParser<'src, 'b, I, O, E>> = rc.clone() as _;
         let parser = Recursive {
             inner: RecursiveInner::Unowned(rc.clone()),
+            #[cfg(debug_assertions)]
+            debug: debug.clone(),
         };
 
         f(parser)
@@ -257,5 +449,7 @@ where
 
     Recursive {
         inner: RecursiveInner::Owned(rc),
+        #[cfg(debug_assertions)]
+        debug,
     }
 }
@@ -0,0 +1,92 @@
+//! A pattern for publishing reusable grammar fragments, such as literals or expressions, that downstream grammars can
+//! import and compose.
+//!
+//! *“Would it save you a lot of time if I just gave up and went mad now?”*
+//!
+//! A fragment is a type implementing [`RuleSet`]. The implementation is generic over the input and the
+//! [`ParserExtra`] type, so the grammar that finally uses the fragment chooses the error, state, and context types,
+//! not the crate that published it. Fragments that build on other fragments take them as type parameters (or fields),
+//! which lets the consumer swap out any part of the grammar.
+//!
+//! The type of the parser a fragment produces is named by [`RuleSet::Parser`]. Parsers built from combinators often
+//! have types that cannot be written out, in which case [`Boxed`] can be used.
+//!
+//! # Examples
+//!
+//! ```
+//! use chumsky::{extra::ParserExtra, prelude::*, rules::RuleSet};
+//!
+//! // In a crate publishing literal rules
+//! #[derive(Copy, Clone, Default)]
+//! pub struct Literals;
+//!
+//! impl<'src, E: ParserExtra<'src, &'src str>> RuleSet<'src, &'src str, E> for Literals {
+//!     type Output = i64;
+//!     type Parser = Boxed<'src, 'src, &'src str, i64, E>;
+//!
+//!     fn parser(self) -> Self::Parser {
+//!         text::int(10).from_str().unwrapped().padded().boxed()
+//!     }
+//! }
+//!
+//! // In a crate publishing expression rules, built on top of any rules for atoms
+//! #[derive(Copy, Clone, Default)]
+//! pub struct Sums<A>(pub A);
+//!
+//! impl<'src, A, E> RuleSet<'src, &'src str, E> for Sums<A>
+//! where
+//!     A: RuleSet<'src, &'src str, E, Output = i64> + 'src,
+//!     E: ParserExtra<'src, &'src str>,
+//! {
+//!     type Output = i64;
+//!     type Parser = Boxed<'src, 'src, &'src str, i64, E>;
+//!
+//!     fn parser(self) -> Self::Parser {
+//!         let atom = self.0.parser();
+//!         atom.clone()
+//!             .foldl(just('+').ignore_then(atom).repeated(), |a, b| a + b)
+//!             .boxed()
+//!     }
+//! }
+//!
+//! // The final grammar picks the error type
+//! fn sum<'src>() -> impl Parser<'src, &'src str, i64, extra::Err<Rich<'src, char>>> {
+//!     Sums(Literals).parser().then_ignore(end())
+//! }
+//!
+//! assert_eq!(sum().parse("1 + 2 + 3").into_result(), Ok(6));
+//! assert_eq!(sum().parse("1 +").into_errors().len(), 1);
+//!
+//! // Rule sets can also be boxed, giving them a nameable type
+//! let boxed: Boxed<&str, i64, extra::Default> = Sums(Literals).boxed();
+//! assert_eq!(boxed.parse("4+5").into_result(), Ok(9));
+//! ```
+
+use super::*;
+
+/// A reusable set of grammar rules, generic over the input and [`ParserExtra`] types chosen by its user.
+///
+/// See the [module-level documentation](self) for more information.
+pub trait RuleSet<'src, I, E>
+where
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+{
+    /// The output of the parser produced by this rule set.
+    type Output;
+
+    /// The type of the parser produced by this rule set.
+    type Parser: Parser<'src, I, Self::Output, E> + Clone;
+
+    /// Create the parser for this rule set.
+    fn parser(self) -> Self::Parser;
+
+    /// Create the parser for this rule set, boxed so that it has a nameable type.
+    fn boxed(self) -> Boxed<'src, 'src, I, Self::Output, E>
+    where
+        Self: Sized,
+        Self::Parser: 'src,
+    {
+        Parser::boxed(self.parser())
+    }
+}
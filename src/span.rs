@@ -80,6 +80,93 @@ pub trait Span {
             self.start().min(other.start())..self.end().max(other.end()),
         )
     }
+
+    /// Find the overlap between two spans, if there is one.
+    ///
+    /// For example, spans like `3..6` and `5..8` will result in an intersected span of `5..6`. Spans that merely touch,
+    /// like `3..5` and `5..8`, intersect at the zero-width span `5..5`. Spans that do not touch, like `3..5` and `7..8`,
+    /// have no intersection.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`Self::Context`]s of both spans are not equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::span::{Span, SimpleSpan};
+    /// let a = SimpleSpan::new(3, 6);
+    ///
+    /// assert_eq!(a.intersect(SimpleSpan::new(5, 8)), Some(SimpleSpan::new(5, 6)));
+    /// assert_eq!(a.intersect(SimpleSpan::new(7, 8)), None);
+    /// ```
+    fn intersect(&self, other: Self) -> Option<Self>
+    where
+        Self::Context: PartialEq + fmt::Debug,
+        Self::Offset: Ord,
+        Self: Sized,
+    {
+        assert_eq!(
+            self.context(),
+            other.context(),
+            "tried to intersect two spans with different contexts"
+        );
+        let start = self.start().max(other.start());
+        let end = self.end().min(other.end());
+        (start <= end).then(|| Self::new(self.context(), start..end))
+    }
+
+    /// Returns true if the given offset falls within this span.
+    ///
+    /// As with [`Range`], the start offset is inclusive and the end offset is exclusive, so zero-width spans contain
+    /// no offsets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::span::{Span, SimpleSpan};
+    /// let span = SimpleSpan::new(3, 6);
+    ///
+    /// assert!(span.contains(&3));
+    /// assert!(span.contains(&5));
+    /// assert!(!span.contains(&6));
+    /// ```
+    fn contains(&self, offset: &Self::Offset) -> bool
+    where
+        Self::Offset: PartialOrd,
+    {
+        self.start() <= *offset && *offset < self.end()
+    }
+
+    /// Returns true if the given span falls entirely within this span.
+    ///
+    /// Unlike [`Span::contains`], the ends of the spans may coincide, so every span contains itself and zero-width
+    /// spans at either end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::span::{Span, SimpleSpan};
+    /// let span = SimpleSpan::new(3, 6);
+    ///
+    /// assert!(span.contains_span(&span));
+    /// assert!(span.contains_span(&SimpleSpan::new(6, 6)));
+    /// assert!(!span.contains_span(&SimpleSpan::new(5, 7)));
+    /// ```
+    fn contains_span(&self, other: &Self) -> bool
+    where
+        Self::Context: PartialEq,
+        Self::Offset: PartialOrd,
+    {
+        self.context() == other.context()
+            && self.start() <= other.start()
+            && other.end() <= self.end()
+    }
+
+    /// Convert this span into a [`Range`] of offsets, discarding its context.
+    fn to_range(&self) -> Range<Self::Offset> {
+        self.start()..self.end()
+    }
 }
 
 /// The most basic implementor of `Span` - akin to `Range`, but `Copy` since it's not also
@@ -118,6 +205,27 @@ impl<T> SimpleSpan<T> {
     }
 }
 
+impl<T, C> SimpleSpan<T, C> {
+    /// Attach a context to this span, such as the source file that it originated from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::span::{Span, SimpleSpan};
+    /// let span = SimpleSpan::from(2..5).with_context("main.rs");
+    ///
+    /// assert_eq!(span.context(), "main.rs");
+    /// assert_eq!(span.to_range(), 2..5);
+    /// ```
+    pub fn with_context<D>(self, context: D) -> SimpleSpan<T, D> {
+        SimpleSpan {
+            start: self.start,
+            end: self.end,
+            context,
+        }
+    }
+}
+
 impl<T> From<Range<T>> for SimpleSpan<T> {
     fn from(range: Range<T>) -> Self {
         SimpleSpan {
@@ -222,3 +330,74 @@ impl<T: Clone> Span for Range<T> {
         self.end.clone()
     }
 }
+
+/// A location within a text input, as tracked by [`LineColInput`](crate::input::LineColInput).
+///
+/// Lines and columns are 1-based. Columns are counted in tokens (i.e: [`char`]s for [`&str`] inputs and bytes for
+/// [`&[u8]`] inputs), with tabs advancing to the next tab stop.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct LineCol {
+    /// The offset of this location from the start of the input, as given by the underlying input's cursor.
+    pub offset: usize,
+    /// The 1-based line number of this location.
+    pub line: usize,
+    /// The 1-based column number of this location.
+    pub col: usize,
+}
+
+impl fmt::Display for LineCol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.col)
+    }
+}
+
+/// A span with line and column information for both its start and end locations.
+///
+/// This is the span type of [`LineColInput`](crate::input::LineColInput), created with [`Input::line_col`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct LineColSpan {
+    /// The start location of the span.
+    pub start: LineCol,
+    /// The end (exclusive) location of the span.
+    pub end: LineCol,
+}
+
+impl LineColSpan {
+    /// Convert this span into a [`SimpleSpan`] of offsets, discarding line and column information.
+    pub fn to_simple(&self) -> SimpleSpan<usize> {
+        SimpleSpan::new(self.start.offset, self.end.offset)
+    }
+}
+
+impl fmt::Debug for LineColSpan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+impl fmt::Display for LineColSpan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+impl Span for LineColSpan {
+    type Context = ();
+    type Offset = LineCol;
+
+    fn new(_context: Self::Context, range: Range<Self::Offset>) -> Self {
+        Self {
+            start: range.start,
+            end: range.end,
+        }
+    }
+    fn context(&self) -> Self::Context {}
+    fn start(&self) -> Self::Offset {
+        self.start
+    }
+    fn end(&self) -> Self::Offset {
+        self.end
+    }
+}
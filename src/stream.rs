@@ -126,6 +126,33 @@ where
     }
 }
 
+// Streams can't lend out borrowed slices of their tokens because the token buffer may grow (and hence reallocate)
+// during parsing, so slices are reconstructed as owned buffers instead.
+impl<'a, I: ExactSizeIterator + 'a> SliceInput<'a> for Stream<I>
+where
+    I::Item: Clone,
+{
+    type Slice = Vec<I::Item>;
+
+    #[inline]
+    fn full_slice(this: &mut Self::Cache) -> Self::Slice {
+        this.tokens.extend(&mut this.iter);
+        this.tokens.clone()
+    }
+
+    #[inline]
+    unsafe fn slice(this: &mut Self::Cache, range: Range<&Self::Cursor>) -> Self::Slice {
+        // Both cursors were produced by `next`, so the tokens between them have already been pulled into the buffer
+        this.tokens[*range.start..*range.end].to_vec()
+    }
+
+    #[inline]
+    unsafe fn slice_from(this: &mut Self::Cache, from: RangeFrom<&Self::Cursor>) -> Self::Slice {
+        this.tokens.extend(&mut this.iter);
+        this.tokens[*from.start..].to_vec()
+    }
+}
+
 /// An input type that uses an iterator to generate tokens.
 ///
 /// This input type supports backtracking by duplicating the iterator. It is recommended that your iterator is very
@@ -200,3 +227,20 @@ fn map_tuple() {
 
     assert_eq!(parser().parse(stream).into_result(), Ok('h'));
 }
+
+#[test]
+fn owned_slices() {
+    let stream = || Stream::from_iter("let x 42;".chars().collect::<Vec<_>>()).exact_size_boxed();
+
+    let ident = any::<_, extra::Err<Simple<char>>>()
+        .filter(char::is_ascii_alphanumeric)
+        .repeated()
+        .at_least(1)
+        .to_slice();
+    let tokens = ident.padded().repeated().collect::<Vec<_>>();
+
+    assert_eq!(
+        tokens.then_ignore(just(';')).parse(stream()).into_result(),
+        Ok(vec![vec!['l', 'e', 't'], vec!['x'], vec!['4', '2']]),
+    );
+}
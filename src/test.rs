@@ -0,0 +1,194 @@
+//! Utilities for testing parsers.
+//!
+//! *“Nothing travels faster than the speed of light with the possible exception of bad news, which obeys its own
+//! special laws.”*
+//!
+//! These helpers make tests of grammars concise and give readable failure messages, rather than requiring each test to
+//! pick apart a [`ParseResult`] by hand. All of them panic on failure, so they can be used directly in `#[test]`
+//! functions.
+//!
+//! Only available with the `std` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! # use chumsky::prelude::*;
+//! use chumsky::test::{assert_fails_with_expected, assert_parses_to};
+//!
+//! let boolean = just::<_, _, extra::Err<Rich<char>>>("true")
+//!     .to(true)
+//!     .or(just("false").to(false));
+//!
+//! assert_parses_to(&boolean, "true", true);
+//! assert_fails_with_expected(&boolean, "maybe", ["'t'", "'f'"]);
+//! ```
+
+use super::*;
+use std::{env, fs, path::Path};
+
+/// The environment variable that, when set, causes [`assert_errors_snapshot`] to update snapshots instead of checking
+/// them.
+pub const BLESS_VAR: &str = "CHUMSKY_BLESS";
+
+/// Assert that the parser successfully parses the input, producing the expected output.
+///
+/// # Panics
+///
+/// Panics if parsing fails (listing the errors) or produces a different output.
+#[track_caller]
+pub fn assert_parses_to<'src, P, I, O, E>(parser: &P, input: I, expected: O)
+where
+    P: Parser<'src, I, O, E>,
+    I: Input<'src>,
+    O: PartialEq + fmt::Debug,
+    E: ParserExtra<'src, I>,
+    E::Error: fmt::Debug,
+    E::State: Default,
+    E::Context: Default,
+{
+    match parser.parse(input).into_result() {
+        Ok(output) => assert_eq!(output, expected, "parser produced unexpected output"),
+        Err(errs) => {
+            panic!("expected input to parse successfully, but it failed with errors: {errs:#?}")
+        }
+    }
+}
+
+/// Assert that the parser fails to parse the input, returning the errors for further inspection.
+///
+/// # Panics
+///
+/// Panics if parsing succeeds, showing the output.
+#[track_caller]
+pub fn assert_fails<'src, P, I, O, E>(parser: &P, input: I) -> Vec<E::Error>
+where
+    P: Parser<'src, I, O, E>,
+    I: Input<'src>,
+    O: fmt::Debug,
+    E: ParserExtra<'src, I>,
+    E::State: Default,
+    E::Context: Default,
+{
+    match parser.parse(input).into_result() {
+        Ok(output) => {
+            panic!("expected input to fail to parse, but it produced output: {output:#?}")
+        }
+        Err(errs) => errs,
+    }
+}
+
+/// Assert that the parser fails to parse the input, and that the first error expected exactly the given patterns (in
+/// any order).
+///
+/// Patterns are compared by their [`Display`](fmt::Display) form, so tokens are written quoted (`"'a'"`), labels are
+/// written as-is (`"digit"`), and the end of input is written `"end of input"`.
+///
+/// # Panics
+///
+/// Panics if parsing succeeds, or if the first error expected different patterns.
+#[track_caller]
+pub fn assert_fails_with_expected<'src, 'p, P, I, O, E, T, S, L>(
+    parser: &P,
+    input: I,
+    expected: impl IntoIterator<Item = &'p str>,
+) where
+    P: Parser<'src, I, O, E>,
+    I: Input<'src>,
+    O: fmt::Debug,
+    E: ParserExtra<'src, I, Error = Rich<'src, T, S, L>>,
+    E::State: Default,
+    E::Context: Default,
+    T: fmt::Display + 'src,
+    S: 'src,
+    L: fmt::Display + 'src,
+{
+    let errs = assert_fails(parser, input);
+    let mut found = errs[0]
+        .expected()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    let mut expected = expected.into_iter().map(String::from).collect::<Vec<_>>();
+    found.sort();
+    expected.sort();
+    assert_eq!(
+        found, expected,
+        "parser failed with unexpected expected patterns"
+    );
+}
+
+/// Render the errors produced when parsing the input, one per line.
+///
+/// If the input parses successfully, the rendering is empty.
+pub fn render_errors<'src, P, I, O, E>(parser: &P, input: I) -> String
+where
+    P: Parser<'src, I, O, E>,
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    E::Error: fmt::Display,
+    E::State: Default,
+    E::Context: Default,
+{
+    parser
+        .parse(input)
+        .into_errors()
+        .into_iter()
+        .map(|err| format!("{err}\n"))
+        .collect()
+}
+
+/// Assert that the errors produced when parsing the input (as rendered by [`render_errors`]) match the contents of a
+/// snapshot file.
+///
+/// When the [`BLESS_VAR`] environment variable is set, the snapshot file is written (or overwritten) instead, which is
+/// how snapshots are created and updated. Relative paths are resolved from the working directory, which is the root of
+/// the package when run via `cargo test`.
+///
+/// # Panics
+///
+/// Panics if the rendered errors differ from the snapshot, or if the snapshot cannot be read or written.
+#[track_caller]
+pub fn assert_errors_snapshot<'src, P, I, O, E>(parser: &P, input: I, path: impl AsRef<Path>)
+where
+    P: Parser<'src, I, O, E>,
+    I: Input<'src>,
+    E: ParserExtra<'src, I>,
+    E::Error: fmt::Display,
+    E::State: Default,
+    E::Context: Default,
+{
+    check_snapshot(
+        &render_errors(parser, input),
+        path.as_ref(),
+        env::var_os(BLESS_VAR).is_some(),
+    );
+}
+
+/// Compare rendered errors against a snapshot file, or write them to it if `bless` is set.
+#[track_caller]
+pub(crate) fn check_snapshot(rendered: &str, path: &Path, bless: bool) {
+    if bless {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).unwrap_or_else(|err| {
+                panic!(
+                    "failed to create snapshot directory {}: {err}",
+                    dir.display()
+                )
+            });
+        }
+        fs::write(path, rendered)
+            .unwrap_or_else(|err| panic!("failed to write snapshot {}: {err}", path.display()));
+    } else {
+        let snapshot = fs::read_to_string(path).unwrap_or_else(|err| {
+            panic!(
+                "failed to read snapshot {} ({err}), set {BLESS_VAR}=1 to create it",
+                path.display()
+            )
+        });
+        assert_eq!(
+            rendered,
+            snapshot,
+            "errors differ from snapshot {}, set {BLESS_VAR}=1 to update it",
+            path.display()
+        );
+    }
+}
@@ -5,8 +5,13 @@
 //!
 //! The parsers in this module are generic over both Unicode ([`char`]) and ASCII ([`u8`]) characters. Most parsers take
 //! a type parameter, `C`, that can be either [`u8`] or [`char`] in order to handle either case.
+//!
+//! When used with byte inputs (such as `&[u8]`), parsers only ever accept ASCII characters: bytes outside of the ASCII
+//! range are never considered to be whitespace, digits, or part of an identifier. This makes them suitable for parsing
+//! ASCII-ish text embedded within binary data.
 
 use crate::prelude::*;
+use alloc::borrow::Cow;
 
 use super::*;
 
@@ -86,15 +91,17 @@ impl Char for &Grapheme {
         }
     }
 
+    // Only the first code point of a cluster decides whether it may be part of an identifier: the rest are extending
+    // characters (like combining marks or variation selectors) that belong to it. See `unicode::StrictXid` for a
+    // policy that checks every code point.
     fn is_ident_start(&self) -> bool {
-        let (first, rest) = self.split();
-        let is_start = unicode_ident::is_xid_start(first) || first == '_';
-        is_start && rest.chars().all(unicode_ident::is_xid_continue)
+        let (first, _) = self.split();
+        unicode_ident::is_xid_start(first) || first == '_'
     }
 
     fn is_ident_continue(&self) -> bool {
-        let mut iter = self.as_str().chars();
-        iter.all(unicode_ident::is_xid_continue)
+        let (first, _) = self.split();
+        unicode_ident::is_xid_continue(first)
     }
 }
 
@@ -150,7 +157,8 @@ impl Char for u8 {
     }
 
     fn is_whitespace(&self) -> bool {
-        self.is_ascii_whitespace()
+        // `u8::is_ascii_whitespace` omits vertical tab, which we consider to be a newline
+        self.is_ascii_whitespace() || *self == b'\x0B'
     }
 
     fn is_newline(&self) -> bool {
@@ -176,12 +184,14 @@ impl Char for u8 {
         Some(*self)
     }
 
+    // Bytes are treated as ASCII: bytes outside of the ASCII range are never part of an identifier (rather than being
+    // interpreted as Latin-1 characters, which would split multi-byte UTF-8 sequences)
     fn is_ident_start(&self) -> bool {
-        (*self as char).is_ident_start()
+        self.is_ascii_alphabetic() || *self == b'_'
     }
 
     fn is_ident_continue(&self) -> bool {
-        (*self as char).is_ident_continue()
+        self.is_ascii_alphanumeric() || *self == b'_'
     }
 }
 
@@ -198,6 +208,7 @@ where
     I::Token: Char,
     A: Parser<'src, I, O, E>,
 {
+    #[inline(always)]
     fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, O> {
         inp.skip_while(|c| c.is_whitespace());
         let out = self.parser.go::<M>(inp)?;
@@ -208,9 +219,209 @@ where
     go_extra!(O);
 }
 
+/// A parser that accepts (and ignores) any number of whitespace characters after another pattern.
+#[derive(Copy, Clone)]
+pub struct Lexeme<A> {
+    pub(crate) parser: A,
+}
+
+impl<'src, I, O, E, A> Parser<'src, I, O, E> for Lexeme<A>
+where
+    I: ValueInput<'src>,
+    E: ParserExtra<'src, I>,
+    I::Token: Char,
+    A: Parser<'src, I, O, E>,
+{
+    #[inline(always)]
+    fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, O> {
+        let out = self.parser.go::<M>(inp)?;
+        inp.skip_while(|c| c.is_whitespace());
+        Ok(out)
+    }
+
+    go_extra!(O);
+}
+
+/// A parser that accepts (and ignores) any number of whitespace characters. See [`whitespace`] and
+/// [`inline_whitespace`].
+///
+/// This parser skips over whitespace directly rather than repeatedly invoking an inner parser. It is also an
+/// [`IterParser`] that produces `()` for each whitespace character, so methods like [`IterParser::count`] may be used
+/// on it.
+pub struct Whitespace<I, E> {
+    at_least: usize,
+    at_most: u64,
+    inline: bool,
+    #[allow(dead_code)]
+    phantom: EmptyPhantom<(I, E)>,
+}
+
+impl<I, E> Copy for Whitespace<I, E> {}
+impl<I, E> Clone for Whitespace<I, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<I, E> Whitespace<I, E> {
+    /// Require that at least the given number of whitespace characters are present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let sep = text::whitespace::<_, extra::Err<Simple<char>>>().at_least(1);
+    ///
+    /// assert_eq!(sep.parse(" \t").into_result(), Ok(()));
+    /// assert!(sep.parse("").has_errors());
+    /// ```
+    pub fn at_least(self, at_least: usize) -> Self {
+        Self { at_least, ..self }
+    }
+
+    /// Require that at most the given number of whitespace characters are present.
+    pub fn at_most(self, at_most: usize) -> Self {
+        Self {
+            at_most: at_most as u64,
+            ..self
+        }
+    }
+
+    /// Require that exactly the given number of whitespace characters are present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let indent = text::inline_whitespace::<_, extra::Err<Simple<char>>>()
+    ///     .exactly(4)
+    ///     .ignore_then(text::ident());
+    ///
+    /// assert_eq!(indent.parse("    foo").into_result(), Ok("foo"));
+    /// assert!(indent.parse("  foo").has_errors());
+    /// assert!(indent.parse("      foo").has_errors());
+    /// ```
+    pub fn exactly(self, exactly: usize) -> Self {
+        Self {
+            at_least: exactly,
+            at_most: exactly as u64,
+            ..self
+        }
+    }
+
+    #[inline(always)]
+    fn accepts<C: Char>(&self, c: &C) -> bool {
+        if self.inline {
+            c.is_inline_whitespace()
+        } else {
+            c.is_whitespace()
+        }
+    }
+}
+
+impl<'src, I, E> Whitespace<I, E>
+where
+    I: StrInput<'src>,
+    I::Token: Char + 'src,
+    E: ParserExtra<'src, I>,
+{
+    /// Generate an error for the non-whitespace token found at the current position.
+    #[inline]
+    fn expected_whitespace(&self, inp: &mut InputRef<'src, '_, I, E>) {
+        let before = inp.save();
+        let found = inp.next_inner();
+        let err_span = inp.span_since(before.cursor());
+        inp.rewind(before);
+        inp.add_alt(None, found.map(|f| f.into()), err_span);
+    }
+}
+
+impl<'src, I, E> Parser<'src, I, (), E> for Whitespace<I, E>
+where
+    I: StrInput<'src>,
+    I::Token: Char + 'src,
+    E: ParserExtra<'src, I>,
+{
+    #[inline]
+    fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, ()> {
+        let mut count = 0u64;
+        inp.skip_while(|c| {
+            let accepts = count < self.at_most && self.accepts(c);
+            count += accepts as u64;
+            accepts
+        });
+        if count >= self.at_least as u64 {
+            Ok(M::bind(|| ()))
+        } else {
+            self.expected_whitespace(inp);
+            Err(())
+        }
+    }
+
+    go_extra!(());
+}
+
+impl<'src, I, E> IterParser<'src, I, (), E> for Whitespace<I, E>
+where
+    I: StrInput<'src>,
+    I::Token: Char + 'src,
+    E: ParserExtra<'src, I>,
+{
+    type IterState<M: Mode> = usize;
+
+    #[inline(always)]
+    fn make_iter<M: Mode>(
+        &self,
+        _inp: &mut InputRef<'src, '_, I, E>,
+    ) -> PResult<Emit, Self::IterState<M>> {
+        Ok(0)
+    }
+
+    #[inline(always)]
+    fn next<M: Mode>(
+        &self,
+        inp: &mut InputRef<'src, '_, I, E>,
+        count: &mut Self::IterState<M>,
+    ) -> IPResult<M, ()> {
+        self.next_cfg::<M>(inp, count, &RepeatedCfg::default())
+    }
+}
+
+impl<'src, I, E> ConfigIterParser<'src, I, (), E> for Whitespace<I, E>
+where
+    I: StrInput<'src>,
+    I::Token: Char + 'src,
+    E: ParserExtra<'src, I>,
+{
+    type Config = RepeatedCfg;
+
+    #[inline]
+    fn next_cfg<M: Mode>(
+        &self,
+        inp: &mut InputRef<'src, '_, I, E>,
+        count: &mut Self::IterState<M>,
+        cfg: &Self::Config,
+    ) -> IPResult<M, ()> {
+        let at_most = cfg.at_most.map(|x| x as u64).unwrap_or(self.at_most);
+        let at_least = cfg.at_least.unwrap_or(self.at_least);
+
+        if (*count as u64) < at_most && inp.peek().map_or(false, |c| self.accepts(&c)) {
+            inp.skip();
+            *count += 1;
+            Ok(Some(M::bind(|| ())))
+        } else if *count >= at_least {
+            Ok(None)
+        } else {
+            self.expected_whitespace(inp);
+            Err(())
+        }
+    }
+}
+
 /// A parser that accepts (and ignores) any number of whitespace characters.
 ///
-/// This parser is a `Parser::Repeated` and so methods such as `at_least()` can be called on it.
+/// Use [`Whitespace::at_least`] to require some whitespace, such as a separator between two words. As an
+/// [`IterParser`], it produces `()` for each whitespace character, including newlines.
 ///
 /// The output type of this parser is `()`.
 ///
@@ -224,21 +435,28 @@ where
 /// assert_eq!(whitespace.parse("\t \n  \r ").into_result(), Ok(()));
 /// // ...including none at all!
 /// assert_eq!(whitespace.parse("").into_result(), Ok(()));
+/// // The number of whitespace characters can be counted
+/// assert_eq!(whitespace.count().parse(" \t\n").into_result(), Ok(3));
 /// ```
-pub fn whitespace<'src, I, E>() -> Repeated<impl Parser<'src, I, (), E> + Copy, (), I, E>
+#[must_use]
+pub const fn whitespace<'src, I, E>() -> Whitespace<I, E>
 where
     I: StrInput<'src>,
     I::Token: Char + 'src,
     E: ParserExtra<'src, I>,
 {
-    select! { c if (c as I::Token).is_whitespace() => () }
-        .ignored()
-        .repeated()
+    Whitespace {
+        at_least: 0,
+        at_most: !0,
+        inline: false,
+        phantom: EmptyPhantom::new(),
+    }
 }
 
 /// A parser that accepts (and ignores) any number of inline whitespace characters.
 ///
-/// This parser is a `Parser::Repeated` and so methods such as `at_least()` can be called on it.
+/// Use [`Whitespace::exactly`] to match a fixed indentation, such as four spaces at the start of a line. As an
+/// [`IterParser`], it produces `()` for each space or tab, stopping at the end of the line.
 ///
 /// The output type of this parser is `()`.
 ///
@@ -255,15 +473,19 @@ where
 /// // ... but not newlines
 /// assert!(inline_whitespace.at_least(1).parse("\n\r").has_errors());
 /// ```
-pub fn inline_whitespace<'src, I, E>() -> Repeated<impl Parser<'src, I, (), E> + Copy, (), I, E>
+#[must_use]
+pub const fn inline_whitespace<'src, I, E>() -> Whitespace<I, E>
 where
     I: StrInput<'src>,
     I::Token: Char + 'src,
     E: ParserExtra<'src, I>,
 {
-    select! { c if (c as I::Token).is_inline_whitespace() => () }
-        .ignored()
-        .repeated()
+    Whitespace {
+        at_least: 0,
+        at_most: !0,
+        inline: true,
+        phantom: EmptyPhantom::new(),
+    }
 }
 
 /// A parser that accepts (and ignores) any newline characters or character sequences.
@@ -295,6 +517,11 @@ where
 /// assert_eq!(newline.parse("\u{0085}").into_result(), Ok(()));
 /// assert_eq!(newline.parse("\u{2028}").into_result(), Ok(()));
 /// assert_eq!(newline.parse("\u{2029}").into_result(), Ok(()));
+///
+/// // Byte inputs are supported too
+/// let newline = text::newline::<_, extra::Err<Simple<u8>>>();
+///
+/// assert_eq!(newline.parse(b"\r\n".as_slice()).into_result(), Ok(()));
 /// ```
 #[must_use]
 pub fn newline<'src, I, E>() -> impl Parser<'src, I, (), E> + Copy
@@ -302,106 +529,1378 @@ where
     I: ValueInput<'src>,
     I::Token: Char + 'src,
     E: ParserExtra<'src, I>,
-    &'src str: OrderedSeq<'src, I::Token>,
 {
-    just("\r\n")
+    let cr = any().filter(|c: &I::Token| c.to_ascii() == Some(b'\r'));
+    let lf = any().filter(|c: &I::Token| c.to_ascii() == Some(b'\n'));
+    cr.ignore_then(lf.or_not())
         .ignored()
         .or(any().filter(I::Token::is_newline).ignored())
 }
 
-/// A parser that accepts one or more ASCII digits.
+/// A parser that accepts the rest of the current line, up to but excluding the next newline (or the end of the
+/// input).
 ///
-/// The output type of this parser is `I::Slice` (i.e: [`&str`] when `I` is [`&str`], and [`&[u8]`]
-/// when `I::Slice` is [`&[u8]`]).
+/// The newline itself is not consumed. The output type of this parser is `I::Slice` (i.e: [`&str`] when `I` is
+/// [`&str`]).
 ///
-/// The `radix` parameter functions identically to [`char::is_digit`]. If in doubt, choose `10`.
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// let key = text::ascii::ident::<_, extra::Err<Simple<char>>>();
+/// let comment = just("//").ignore_then(text::rest_of_line());
+///
+/// assert_eq!(comment.parse("// hello, world").into_result(), Ok(" hello, world"));
+/// assert_eq!(
+///     comment.then_ignore(text::newline()).then(key).parse("// note\r\nkey").into_result(),
+///     Ok((" note", "key")),
+/// );
+/// ```
+#[must_use]
+pub fn rest_of_line<'src, I, E>() -> impl Parser<'src, I, <I as SliceInput<'src>>::Slice, E> + Copy
+where
+    I: StrInput<'src>,
+    I::Token: Char + 'src,
+    E: ParserExtra<'src, I>,
+{
+    any()
+        .filter(|c: &I::Token| !c.is_newline())
+        .repeated()
+        .to_slice()
+}
+
+/// A parser that accepts a whole line, producing its contents without the line ending.
+///
+/// The line ending, which may be any of the newlines recognised by [`newline`] or the end of the input, is consumed.
+/// The output type of this parser is `I::Slice` (i.e: [`&str`] when `I` is [`&str`]).
 ///
 /// # Examples
 ///
 /// ```
 /// # use chumsky::prelude::*;
-/// let digits = text::digits::<_, extra::Err<Simple<char>>>(10).to_slice();
+/// let two_lines = text::line::<_, extra::Err<Simple<char>>>().then(text::line());
 ///
-/// assert_eq!(digits.parse("0").into_result(), Ok("0"));
-/// assert_eq!(digits.parse("1").into_result(), Ok("1"));
-/// assert_eq!(digits.parse("01234").into_result(), Ok("01234"));
-/// assert_eq!(digits.parse("98345").into_result(), Ok("98345"));
-/// // A string of zeroes is still valid. Use `int` if this is not desirable.
-/// assert_eq!(digits.parse("0000").into_result(), Ok("0000"));
-/// assert!(digits.parse("").has_errors());
+/// assert_eq!(two_lines.parse("first\r\nsecond").into_result(), Ok(("first", "second")));
+/// assert_eq!(two_lines.parse("first\n\n").into_result(), Ok(("first", "")));
 /// ```
 #[must_use]
-pub fn digits<'src, I, E>(
-    radix: u32,
-) -> Repeated<impl Parser<'src, I, <I as Input<'src>>::Token, E> + Copy, I::Token, I, E>
+pub fn line<'src, I, E>() -> impl Parser<'src, I, <I as SliceInput<'src>>::Slice, E> + Copy
+where
+    I: StrInput<'src>,
+    I::Token: Char + 'src,
+    E: ParserExtra<'src, I>,
+{
+    rest_of_line().then_ignore(newline().or(end()))
+}
+
+/// A parser that asserts that the next token cannot continue an identifier, or that there is no next token.
+///
+/// No input is consumed. This is useful for turning a parser like `just("if")` into a proper keyword match in ad hoc
+/// places (so that it no longer matches the start of `iffy`), without the full [`keyword`] machinery. Identifier
+/// characters are those accepted by [`Char::is_ident_continue`].
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// let kw_if = just::<_, _, extra::Err<Simple<char>>>("if").then_ignore(text::word_boundary());
+///
+/// assert_eq!(kw_if.parse("if").into_result(), Ok("if"));
+/// assert_eq!(kw_if.lazy().parse("if(x)").into_result(), Ok("if"));
+/// // 'if' was found, but only as the start of a larger identifier
+/// assert!(kw_if.lazy().parse("iffy").has_errors());
+/// ```
+#[must_use]
+pub fn word_boundary<'src, I, E>() -> impl Parser<'src, I, (), E> + Copy
 where
     I: ValueInput<'src>,
     I::Token: Char + 'src,
     E: ParserExtra<'src, I>,
 {
-    any()
-        // Use try_map over filter to get a better error on failure
-        .try_map(move |c: I::Token, span| {
-            if c.is_digit(radix) {
-                Ok(c)
-            } else {
-                Err(Error::expected_found([], Some(MaybeRef::Val(c)), span))
-            }
-        })
+    any().filter(|c: &I::Token| c.is_ident_continue()).not()
+}
+
+/// A parser that applies `item` to each line of the input, where `item` must accept the whole contents of the line.
+///
+/// Lines may end with any of the newlines recognised by [`newline`]. A trailing newline at the end of the input does
+/// not begin another line.
+///
+/// This parser is a `Parser::Repeated` and so methods such as `collect()` and `at_least()` can be called on it.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// let entry = text::ascii::ident::<_, extra::Err<Simple<char>>>()
+///     .then_ignore(just(": "))
+///     .then(text::int(10).from_str::<u32>().unwrapped());
+/// let log = text::lines(entry).collect::<Vec<_>>();
+///
+/// assert_eq!(
+///     log.parse("warnings: 3\r\nerrors: 0\n").into_result(),
+///     Ok(vec![("warnings", 3), ("errors", 0)]),
+/// );
+/// // Each line must be accepted in its entirety
+/// assert!(log.parse("warnings: 3 (new)\nerrors: 0").has_errors());
+/// ```
+pub fn lines<'src, I, O, E, P>(item: P) -> Repeated<impl Parser<'src, I, O, E> + Clone, O, I, E>
+where
+    I: StrInput<'src>,
+    I::Token: Char + 'src,
+    E: ParserExtra<'src, I>,
+    P: Parser<'src, I, O, E> + Clone,
+{
+    end()
+        .not()
+        .ignore_then(item)
+        .then_ignore(newline().or(end()))
         .repeated()
-        .at_least(1)
 }
 
-/// A parser that accepts a non-negative integer.
+/// The column after `tok`, given the column at which it begins. Tabs advance the column to the next tab stop.
+#[inline]
+pub(crate) fn next_column<C: Char>(col: usize, tok: &C, tab_width: usize) -> usize {
+    if tok.is_newline() {
+        1
+    } else if tok.to_ascii() == Some(b'\t') {
+        (col - 1) / tab_width * tab_width + tab_width + 1
+    } else {
+        col + 1
+    }
+}
+
+/// A parser that accepts a header followed by an indented block of items. See [`offside`].
+pub struct Offside<H, B, HO, BO, I, E> {
+    header: H,
+    item: B,
+    tab_width: usize,
+    #[allow(dead_code)]
+    phantom: EmptyPhantom<(HO, BO, I, E)>,
+}
+
+impl<H: Copy, B: Copy, HO, BO, I, E> Copy for Offside<H, B, HO, BO, I, E> {}
+impl<H: Clone, B: Clone, HO, BO, I, E> Clone for Offside<H, B, HO, BO, I, E> {
+    fn clone(&self) -> Self {
+        Self {
+            header: self.header.clone(),
+            item: self.item.clone(),
+            tab_width: self.tab_width,
+            phantom: EmptyPhantom::new(),
+        }
+    }
+}
+
+impl<H, B, HO, BO, I, E> Offside<H, B, HO, BO, I, E> {
+    /// Set the number of columns between tab stops. A tab advances the column to the next tab stop.
+    ///
+    /// A tab width of `1` (the default) treats tabs like any other whitespace.
+    pub fn with_tab_width(self, tab_width: usize) -> Self {
+        assert!(tab_width > 0, "tab width must be greater than zero");
+        Self { tab_width, ..self }
+    }
+}
+
+/// A parser that accepts a header followed by a block of items laid out according to the offside rule, as in Haskell,
+/// F# or Python.
 ///
-/// An integer is defined as a non-empty sequence of ASCII digits, where the first digit is non-zero or the sequence
-/// has length one.
+/// Each item of the block must begin at a column greater than that at which the header begins. The block ends at the
+/// first non-whitespace token that is not indented past the header (or at the end of the input), so whitespace between
+/// items, including newlines, is skipped. Whitespace after the last item is left alone. Items may begin on the same
+/// line as the header. Columns are counted as by [`current_column`](crate::primitive::current_column).
 ///
-/// The output type of this parser is `I::Slice` (i.e: [`&str`] when `I` is [`&str`], and [`&[u8]`]
-/// when `I::Slice` is [`&[u8]`]).
+/// Since `offside` can be nested by using it as the item parser of another `offside` (via [`recursive`]), this is
+/// enough to parse indentation-sensitive syntax without a separate layout pass.
 ///
-/// The `radix` parameter functions identically to [`char::is_digit`]. If in doubt, choose `10`.
+/// The output type of this parser is `(HO, Vec<BO>)`: the output of the header and those of the items in the block.
 ///
 /// # Examples
 ///
 /// ```
 /// # use chumsky::prelude::*;
-/// let dec = text::int::<_, extra::Err<Simple<char>>>(10);
+/// let name = text::ascii::ident::<_, extra::Err<Simple<char>>>();
+/// let section = text::offside(name.then_ignore(just(':')), name);
+/// let sections = section.padded().repeated().collect::<Vec<_>>();
 ///
-/// assert_eq!(dec.parse("0").into_result(), Ok("0"));
-/// assert_eq!(dec.parse("1").into_result(), Ok("1"));
-/// assert_eq!(dec.parse("1452").into_result(), Ok("1452"));
-/// // No leading zeroes are permitted!
-/// assert!(dec.parse("04").has_errors());
+/// assert_eq!(
+///     sections.parse("fruit:\n  apple\n  pear\nveg: leek\n    kale\n").into_result(),
+///     Ok(vec![("fruit", vec!["apple", "pear"]), ("veg", vec!["leek", "kale"])]),
+/// );
+/// // An item must begin past the header's column to be part of its block
+/// assert!(section.parse("fruit:\napple").has_errors());
+/// ```
+pub fn offside<'src, H, B, HO, BO, I, E>(header: H, item: B) -> Offside<H, B, HO, BO, I, E>
+where
+    I: StrInput<'src>,
+    I::Token: Char,
+    E: ParserExtra<'src, I>,
+    H: Parser<'src, I, HO, E>,
+    B: Parser<'src, I, BO, E>,
+{
+    Offside {
+        header,
+        item,
+        tab_width: 1,
+        phantom: EmptyPhantom::new(),
+    }
+}
+
+impl<'src, H, B, HO, BO, I, E> Parser<'src, I, (HO, Vec<BO>), E> for Offside<H, B, HO, BO, I, E>
+where
+    I: StrInput<'src>,
+    I::Token: Char,
+    E: ParserExtra<'src, I>,
+    H: Parser<'src, I, HO, E>,
+    B: Parser<'src, I, BO, E>,
+{
+    #[inline]
+    fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, (HO, Vec<BO>)> {
+        let header_col = inp.current_column(self.tab_width);
+        let header = self.header.go::<M>(inp)?;
+        let mut items = M::bind(Vec::new);
+        loop {
+            let before = inp.save();
+
+            // The column is tracked while skipping whitespace so that the input only needs to be rescanned from the
+            // start of the line if no newline was skipped
+            let mut line_col = None;
+            let item_col = loop {
+                match inp.peek() {
+                    Some(c) if c.is_whitespace() => {
+                        line_col = if c.is_newline() {
+                            Some(1)
+                        } else {
+                            line_col.map(|col| next_column(col, &c, self.tab_width))
+                        };
+                        inp.skip();
+                    }
+                    Some(_) => {
+                        break line_col.unwrap_or_else(|| inp.current_column(self.tab_width))
+                    }
+                    None => break 0,
+                }
+            };
+            if item_col <= header_col {
+                inp.rewind(before);
+                break;
+            }
+
+            #[cfg(debug_assertions)]
+            let start = inp.cursor();
+            let item = self.item.go::<M>(inp)?;
+            M::combine_mut(&mut items, item, |items: &mut Vec<_>, item| {
+                items.push(item)
+            });
+
+            #[cfg(debug_assertions)]
+            debug_assert!(
+                start != inp.cursor(),
+                "found offside item making no progress"
+            );
+        }
+        Ok(M::combine(header, items, |header, items| (header, items)))
+    }
+
+    go_extra!((HO, Vec<BO>));
+}
+
+/// A parser that accepts one or more ASCII digits.
 ///
-/// let hex = text::int::<_, extra::Err<Simple<char>>>(16);
+/// The output type of this parser is `I::Slice` (i.e: [`&str`] when `I` is [`&str`], and [`&[u8]`]
+/// when `I::Slice` is [`&[u8]`]).
+///
+/// The `radix` parameter functions identically to [`char::is_digit`]. If in doubt, choose `10`.
+///
+/// # Examples
 ///
-/// assert_eq!(hex.parse("2A").into_result(), Ok("2A"));
-/// assert_eq!(hex.parse("d").into_result(), Ok("d"));
-/// assert_eq!(hex.parse("b4").into_result(), Ok("b4"));
-/// assert!(hex.parse("0B").has_errors());
 /// ```
+/// # use chumsky::prelude::*;
+/// let digits = text::digits::<_, extra::Err<Simple<char>>>(10).to_slice();
 ///
+/// assert_eq!(digits.parse("0").into_result(), Ok("0"));
+/// assert_eq!(digits.parse("1").into_result(), Ok("1"));
+/// assert_eq!(digits.parse("01234").into_result(), Ok("01234"));
+/// assert_eq!(digits.parse("98345").into_result(), Ok("98345"));
+/// // A string of zeroes is still valid. Use `int` if this is not desirable.
+/// assert_eq!(digits.parse("0000").into_result(), Ok("0000"));
+/// assert!(digits.parse("").has_errors());
+/// ```
 #[must_use]
-pub fn int<'src, I, E>(radix: u32) -> impl Parser<'src, I, <I as SliceInput<'src>>::Slice, E> + Copy
+pub fn digits<'src, I, E>(radix: u32) -> Repeated<Digit<I, E>, I::Token, I, E>
 where
-    I: StrInput<'src>,
+    I: ValueInput<'src>,
     I::Token: Char + 'src,
     E: ParserExtra<'src, I>,
 {
-    any()
-        // Use try_map over filter to get a better error on failure
-        .try_map(move |c: I::Token, span| {
-            if c.is_digit(radix) && c != I::Token::digit_zero() {
-                Ok(c)
-            } else {
-                Err(Error::expected_found([], Some(MaybeRef::Val(c)), span))
+    Digit {
+        radix,
+        phantom: EmptyPhantom::new(),
+    }
+    .repeated()
+    .at_least(1)
+}
+
+/// A parser that accepts a single digit of the given radix. See [`digits`].
+pub struct Digit<I, E> {
+    radix: u32,
+    #[allow(dead_code)]
+    phantom: EmptyPhantom<(I, E)>,
+}
+
+impl<I, E> Copy for Digit<I, E> {}
+impl<I, E> Clone for Digit<I, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'src, I, E> Parser<'src, I, I::Token, E> for Digit<I, E>
+where
+    I: ValueInput<'src>,
+    I::Token: Char,
+    E: ParserExtra<'src, I>,
+{
+    #[inline]
+    fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, I::Token> {
+        let before = inp.save();
+        match inp.next_inner() {
+            Some(c) if c.is_digit(self.radix) => Ok(M::bind(|| c)),
+            found => {
+                let err_span = inp.span_since(before.cursor());
+                inp.rewind(before);
+                inp.add_alt(None, found.map(|f| f.into()), err_span);
+                Err(())
+            }
+        }
+    }
+
+    go_extra!(I::Token);
+}
+
+/// A primitive integer type that [`Repeated::to_int`] can accumulate digits into.
+///
+/// This trait is sealed and is implemented for all of Rust's built-in integer types.
+pub trait Int: Copy + Sealed {
+    #[doc(hidden)]
+    const ZERO: Self;
+
+    #[doc(hidden)]
+    fn push_digit(self, radix: u32, digit: u32) -> Option<Self>;
+
+    #[doc(hidden)]
+    fn push_negative_digit(self, radix: u32, digit: u32) -> Option<Self>;
+}
+
+macro_rules! impl_int {
+    ($($t:ty),*) => {
+        $(
+            impl Int for $t {
+                const ZERO: Self = 0;
+
+                #[inline(always)]
+                fn push_digit(self, radix: u32, digit: u32) -> Option<Self> {
+                    self.checked_mul(<$t>::try_from(radix).ok()?)?
+                        .checked_add(<$t>::try_from(digit).ok()?)
+                }
+
+                #[inline(always)]
+                fn push_negative_digit(self, radix: u32, digit: u32) -> Option<Self> {
+                    self.checked_mul(<$t>::try_from(radix).ok()?)?
+                        .checked_sub(<$t>::try_from(digit).ok()?)
+                }
+            }
+        )*
+    };
+}
+
+// `u8` is already sealed by virtue of implementing `Char`
+impl Sealed for u16 {}
+impl Sealed for u32 {}
+impl Sealed for u64 {}
+impl Sealed for u128 {}
+impl Sealed for usize {}
+impl Sealed for i8 {}
+impl Sealed for i16 {}
+impl Sealed for i32 {}
+impl Sealed for i64 {}
+impl Sealed for i128 {}
+impl Sealed for isize {}
+
+impl_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl<'src, I, E> Repeated<Digit<I, E>, I::Token, I, E>
+where
+    I: ValueInput<'src>,
+    I::Token: Char,
+    E: ParserExtra<'src, I>,
+{
+    /// Accumulate the digits into an integer as they are parsed, without first collecting them into a slice.
+    ///
+    /// If the value does not fit within `N`, an error is produced spanning the entire sequence of digits.
+    ///
+    /// The output type of this parser is `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let byte = text::digits::<_, extra::Err<Simple<char>>>(10).to_int::<u8>();
+    ///
+    /// assert_eq!(byte.parse("0").into_result(), Ok(0));
+    /// assert_eq!(byte.parse("0042").into_result(), Ok(42));
+    /// assert_eq!(byte.parse("255").into_result(), Ok(255));
+    /// // Too large to fit within a `u8`
+    /// assert!(byte.parse("256").has_errors());
+    ///
+    /// let hex = text::digits::<_, extra::Err<Simple<char>>>(16).to_int::<u32>();
+    ///
+    /// assert_eq!(hex.parse("DEADbeef").into_result(), Ok(0xDEADBEEF));
+    /// ```
+    pub fn to_int<N: Int>(self) -> ToInt<Self, N> {
+        ToInt {
+            digits: self,
+            phantom: EmptyPhantom::new(),
+        }
+    }
+}
+
+/// See [`Repeated::to_int`].
+pub struct ToInt<A, N> {
+    digits: A,
+    #[allow(dead_code)]
+    phantom: EmptyPhantom<N>,
+}
+
+impl<A: Copy, N> Copy for ToInt<A, N> {}
+impl<A: Clone, N> Clone for ToInt<A, N> {
+    fn clone(&self) -> Self {
+        Self {
+            digits: self.digits.clone(),
+            phantom: EmptyPhantom::new(),
+        }
+    }
+}
+
+impl<'src, I, E, N> Parser<'src, I, N, E> for ToInt<Repeated<Digit<I, E>, I::Token, I, E>, N>
+where
+    I: ValueInput<'src>,
+    I::Token: Char,
+    E: ParserExtra<'src, I>,
+    N: Int,
+{
+    #[inline]
+    fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, N> {
+        let radix = self.digits.parser.radix;
+        let start = inp.save();
+        let mut value = Some(N::ZERO);
+        let mut count = 0;
+        loop {
+            if count as u64 >= self.digits.at_most {
+                break;
+            }
+            let before = inp.save();
+            match inp.next_inner() {
+                Some(c) if c.is_digit(radix) => {
+                    // `is_digit` guarantees that the character is ASCII
+                    let digit = c
+                        .to_ascii()
+                        .and_then(|c| (c as char).to_digit(radix))
+                        .unwrap_or(0);
+                    value = value.and_then(|v| v.push_digit(radix, digit));
+                    count += 1;
+                }
+                found => {
+                    let err_span = inp.span_since(before.cursor());
+                    inp.rewind(before);
+                    if count < self.digits.at_least {
+                        inp.add_alt(None, found.map(|f| f.into()), err_span);
+                        return Err(());
+                    }
+                    break;
+                }
+            }
+        }
+
+        match value {
+            Some(value) => Ok(M::bind(|| value)),
+            None => {
+                let err_span = inp.span_since(start.cursor());
+                let err = E::Error::expected_found(None, None, err_span);
+                inp.add_alt_err(&start.cursor().inner, err);
+                Err(())
+            }
+        }
+    }
+
+    go_extra!(N);
+}
+
+/// A parser that accepts an integer. See [`int`] and [`signed_int`].
+pub struct Integer<I, E> {
+    radix: u32,
+    signed: bool,
+    leading_zeros: bool,
+    underscores: bool,
+    #[allow(dead_code)]
+    phantom: EmptyPhantom<(I, E)>,
+}
+
+impl<I, E> Copy for Integer<I, E> {}
+impl<I, E> Clone for Integer<I, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<I, E> Integer<I, E> {
+    /// Allow leading zeros, as in `007`. Disabled by default.
+    ///
+    /// When disabled, a zero followed by more digits produces an error (spanning the whole integer) explaining that
+    /// leading zeros are not permitted.
+    pub fn leading_zeros(self, leading_zeros: bool) -> Self {
+        Self {
+            leading_zeros,
+            ..self
+        }
+    }
+
+    /// Allow `_` to separate digits, as in `1_000_000`. Disabled by default.
+    ///
+    /// Underscores may only appear after the first digit, and are not considered to be part of the integer if no digits
+    /// follow them. They are ignored by [`Integer::to_int`].
+    pub fn underscores(self, underscores: bool) -> Self {
+        Self {
+            underscores,
+            ..self
+        }
+    }
+
+    /// Compute the value of the integer as it is parsed, without first collecting it into a slice.
+    ///
+    /// If the value does not fit within `N`, an error is produced spanning the entire integer.
+    ///
+    /// The output type of this parser is `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let int = text::signed_int::<_, extra::Err<Simple<char>>>(10)
+    ///     .underscores(true)
+    ///     .to_int::<i32>();
+    ///
+    /// assert_eq!(int.parse("1_000_000").into_result(), Ok(1_000_000));
+    /// assert_eq!(int.parse("-2_147_483_648").into_result(), Ok(i32::MIN));
+    /// // Too large to fit within an `i32`
+    /// assert!(int.parse("2_147_483_648").has_errors());
+    /// ```
+    pub fn to_int<N: Int>(self) -> ToInt<Self, N> {
+        ToInt {
+            digits: self,
+            phantom: EmptyPhantom::new(),
+        }
+    }
+}
+
+impl<'src, I, E> Integer<I, E>
+where
+    I: StrInput<'src>,
+    I::Token: Char + 'src,
+    E: ParserExtra<'src, I>,
+{
+    /// Scan the integer, passing the value of each digit to `digit` along with whether the integer is negative.
+    #[inline]
+    fn scan(
+        &self,
+        inp: &mut InputRef<'src, '_, I, E>,
+        mut digit: impl FnMut(bool, u32),
+    ) -> PResult<Emit, ()> {
+        let radix = self.radix;
+        // `is_digit` guarantees that the character is ASCII
+        let value = |c: &I::Token| {
+            c.to_ascii()
+                .and_then(|c| (c as char).to_digit(radix))
+                .unwrap_or(0)
+        };
+
+        let before = inp.save();
+        let mut negative = false;
+        let mut before_first = before.clone();
+        if self.signed {
+            match inp.next_inner().and_then(|c| c.to_ascii()) {
+                Some(b'-') => {
+                    negative = true;
+                    before_first = inp.save();
+                }
+                Some(b'+') => before_first = inp.save(),
+                _ => inp.rewind(before.clone()),
+            }
+        }
+
+        let first = match inp.next_inner() {
+            Some(c) if c.is_digit(radix) => c,
+            found => {
+                let err_span = inp.span_since(before_first.cursor());
+                inp.rewind(before_first);
+                inp.add_alt(
+                    [Some(I::Token::digit_zero().into())],
+                    found.map(|f| f.into()),
+                    err_span,
+                );
+                inp.rewind(before);
+                return Err(());
+            }
+        };
+        digit(negative, value(&first));
+
+        // The digit after the first, if any
+        let mut second = None;
+        if self.underscores {
+            // Underscores that aren't followed by a digit aren't part of the integer
+            let mut underscores = None;
+            loop {
+                let before_tok = inp.save();
+                match inp.next_inner() {
+                    Some(c) if c.is_digit(radix) => {
+                        digit(negative, value(&c));
+                        second.get_or_insert(c);
+                        underscores = None;
+                    }
+                    Some(c) if c.to_ascii() == Some(b'_') => {
+                        if underscores.is_none() {
+                            underscores = Some(before_tok);
+                        }
+                    }
+                    _ => {
+                        inp.rewind(underscores.unwrap_or(before_tok));
+                        break;
+                    }
+                }
+            }
+        } else {
+            inp.skip_while(|c| {
+                let is_digit = c.is_digit(radix);
+                if is_digit {
+                    digit(negative, value(c));
+                    second.get_or_insert(*c);
+                }
+                is_digit
+            });
+        }
+
+        if !self.leading_zeros && second.is_some() && first == I::Token::digit_zero() {
+            let err_span = inp.span_since(before.cursor());
+            inp.add_alt_err(
+                &before.cursor().inner,
+                // The digit after the zero is the unexpected one
+                E::Error::custom(
+                    second.map(MaybeRef::Val),
+                    err_span,
+                    "leading zeros are not permitted in integer literals",
+                ),
+            );
+            inp.rewind(before);
+            return Err(());
+        }
+
+        Ok(())
+    }
+}
+
+/// A parser that accepts a non-negative integer.
+///
+/// An integer is defined as a non-empty sequence of ASCII digits, where the first digit is non-zero or the sequence
+/// has length one. Use [`Integer::leading_zeros`] to accept integers like `007` too, [`Integer::underscores`] to
+/// accept integers like `1_000`, and [`Integer::to_int`] to compute the value of the integer.
+///
+/// The output type of this parser is `I::Slice` (i.e: [`&str`] when `I` is [`&str`], and [`&[u8]`]
+/// when `I::Slice` is [`&[u8]`]).
+///
+/// The `radix` parameter functions identically to [`char::is_digit`]. If in doubt, choose `10`.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// let dec = text::int::<_, extra::Err<Rich<char>>>(10);
+///
+/// assert_eq!(dec.parse("0").into_result(), Ok("0"));
+/// assert_eq!(dec.parse("1").into_result(), Ok("1"));
+/// assert_eq!(dec.parse("1452").into_result(), Ok("1452"));
+/// // No leading zeroes are permitted!
+/// let errs = dec.parse("04").into_errors();
+/// assert_eq!(errs[0].to_string(), "leading zeros are not permitted in integer literals");
+/// assert_eq!(errs[0].span(), &(0..2).into());
+///
+/// let hex = text::int::<_, extra::Err<Simple<char>>>(16);
+///
+/// assert_eq!(hex.parse("2A").into_result(), Ok("2A"));
+/// assert_eq!(hex.parse("d").into_result(), Ok("d"));
+/// assert_eq!(hex.parse("b4").into_result(), Ok("b4"));
+/// assert!(hex.parse("0B").has_errors());
+///
+/// // Some formats do permit leading zeros
+/// let permissive = text::int::<_, extra::Err<Simple<char>>>(10).leading_zeros(true);
+///
+/// assert_eq!(permissive.parse("0042").into_result(), Ok("0042"));
+/// ```
+///
+#[must_use]
+pub const fn int<'src, I, E>(radix: u32) -> Integer<I, E>
+where
+    I: StrInput<'src>,
+    I::Token: Char + 'src,
+    E: ParserExtra<'src, I>,
+{
+    Integer {
+        radix,
+        signed: false,
+        leading_zeros: false,
+        underscores: false,
+        phantom: EmptyPhantom::new(),
+    }
+}
+
+/// A parser that accepts an integer with an optional sign (`-` or `+`).
+///
+/// This is like [`int`], except that a sign may come before the digits. The sign is included in the output.
+///
+/// The output type of this parser is `I::Slice` (i.e: [`&str`] when `I` is [`&str`], and [`&[u8]`]
+/// when `I::Slice` is [`&[u8]`]).
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// let int = text::signed_int::<_, extra::Err<Simple<char>>>(10).underscores(true);
+///
+/// assert_eq!(int.parse("-1_024").into_result(), Ok("-1_024"));
+/// assert_eq!(int.parse("+7").into_result(), Ok("+7"));
+/// assert_eq!(int.parse("0").into_result(), Ok("0"));
+/// // The no-leading-zeros rule still applies after the sign
+/// assert!(int.parse("-01").has_errors());
+/// assert!(int.parse("-").has_errors());
+/// // Underscores may only separate digits
+/// assert!(int.parse("_1").has_errors());
+/// assert_eq!(int.then_ignore(just('_')).parse("1_").into_result(), Ok("1"));
+/// ```
+#[must_use]
+pub const fn signed_int<'src, I, E>(radix: u32) -> Integer<I, E>
+where
+    I: StrInput<'src>,
+    I::Token: Char + 'src,
+    E: ParserExtra<'src, I>,
+{
+    Integer {
+        radix,
+        signed: true,
+        leading_zeros: false,
+        underscores: false,
+        phantom: EmptyPhantom::new(),
+    }
+}
+
+impl<'src, I, E> Parser<'src, I, <I as SliceInput<'src>>::Slice, E> for Integer<I, E>
+where
+    I: StrInput<'src>,
+    I::Token: Char + 'src,
+    E: ParserExtra<'src, I>,
+{
+    #[inline]
+    fn go<M: Mode>(
+        &self,
+        inp: &mut InputRef<'src, '_, I, E>,
+    ) -> PResult<M, <I as SliceInput<'src>>::Slice> {
+        let before = inp.cursor();
+        self.scan(inp, |_, _| {})?;
+        Ok(M::bind(|| inp.slice_since(&before..)))
+    }
+
+    go_extra!(<I as SliceInput<'src>>::Slice);
+}
+
+impl<'src, I, E, N> Parser<'src, I, N, E> for ToInt<Integer<I, E>, N>
+where
+    I: StrInput<'src>,
+    I::Token: Char + 'src,
+    E: ParserExtra<'src, I>,
+    N: Int,
+{
+    #[inline]
+    fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, N> {
+        let before = inp.cursor();
+        let radix = self.digits.radix;
+        let mut value = Some(N::ZERO);
+        self.digits.scan(inp, |negative, digit| {
+            value = value.and_then(|v| {
+                if negative {
+                    v.push_negative_digit(radix, digit)
+                } else {
+                    v.push_digit(radix, digit)
+                }
+            });
+        })?;
+
+        match value {
+            Some(value) => Ok(M::bind(|| value)),
+            None => {
+                let err_span = inp.span_since(&before);
+                let err = E::Error::expected_found(None, None, err_span);
+                inp.add_alt_err(&before.inner, err);
+                Err(())
+            }
+        }
+    }
+
+    go_extra!(N);
+}
+
+/// The spans of the components of a floating point number. See [`Float::spanned_parts`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FloatSpans<S> {
+    /// The span of the integral digits, if any.
+    pub int: Option<S>,
+    /// The span of the fractional digits (not including the `.`), if any.
+    pub frac: Option<S>,
+    /// The span of the exponent (not including the `e`, but including its sign), if any.
+    pub exp: Option<S>,
+}
+
+/// A parser that accepts a floating point number. See [`float`].
+pub struct Float<F, I, E> {
+    leading_dot: bool,
+    trailing_dot: bool,
+    exponent: bool,
+    inf_nan: bool,
+    #[allow(dead_code)]
+    phantom: EmptyPhantom<(F, I, E)>,
+}
+
+impl<F, I, E> Copy for Float<F, I, E> {}
+impl<F, I, E> Clone for Float<F, I, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F, I, E> Float<F, I, E> {
+    /// Allow the integral digits to be omitted, as in `.5`. Disabled by default.
+    pub fn leading_dot(self, leading_dot: bool) -> Self {
+        Self {
+            leading_dot,
+            ..self
+        }
+    }
+
+    /// Allow the fractional digits to be omitted after a `.`, as in `5.`. Disabled by default.
+    ///
+    /// When disabled, a `.` that is not followed by digits is not considered to be part of the number.
+    pub fn trailing_dot(self, trailing_dot: bool) -> Self {
+        Self {
+            trailing_dot,
+            ..self
+        }
+    }
+
+    /// Allow an exponent, as in `5e-3`. Enabled by default.
+    pub fn exponent(self, exponent: bool) -> Self {
+        Self { exponent, ..self }
+    }
+
+    /// Allow the keywords `inf`, `infinity`, and `nan` (case-insensitive, as with [`f64::from_str`]). Disabled by
+    /// default.
+    pub fn inf_nan(self, inf_nan: bool) -> Self {
+        Self { inf_nan, ..self }
+    }
+
+    /// Output the spans of the components of the number alongside its value.
+    ///
+    /// The output type of this parser is `(F, FloatSpans<I::Span>)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::{prelude::*, text::FloatSpans};
+    /// let float = text::float::<f64, _, extra::Err<Simple<char>>>().spanned_parts();
+    ///
+    /// assert_eq!(
+    ///     float.parse("12.5e-3").into_result(),
+    ///     Ok((
+    ///         12.5e-3,
+    ///         FloatSpans {
+    ///             int: Some((0..2).into()),
+    ///             frac: Some((3..4).into()),
+    ///             exp: Some((5..7).into()),
+    ///         },
+    ///     )),
+    /// );
+    /// ```
+    pub fn spanned_parts(self) -> SpannedFloat<F, I, E> {
+        SpannedFloat { float: self }
+    }
+}
+
+impl<'src, F, I, E> Float<F, I, E>
+where
+    F: FromStr,
+    I: StrInput<'src>,
+    I::Token: Char,
+    I::Slice: AsRef<[u8]>,
+    E: ParserExtra<'src, I>,
+{
+    #[inline]
+    fn eat(inp: &mut InputRef<'src, '_, I, E>, f: impl Fn(u8) -> bool) -> bool {
+        let before = inp.save();
+        match inp.next_inner() {
+            Some(c) if c.to_ascii().map_or(false, f) => true,
+            _ => {
+                inp.rewind(before);
+                false
+            }
+        }
+    }
+
+    #[inline]
+    fn eat_keyword(inp: &mut InputRef<'src, '_, I, E>, kw: &[u8]) -> bool {
+        let before = inp.save();
+        if kw
+            .iter()
+            .all(|k| Self::eat(inp, |c| c.eq_ignore_ascii_case(k)))
+        {
+            true
+        } else {
+            inp.rewind(before);
+            false
+        }
+    }
+
+    #[inline]
+    fn eat_digits(inp: &mut InputRef<'src, '_, I, E>) -> bool {
+        let before = inp.save();
+        inp.skip_while(|c| c.is_digit(10));
+        *before.cursor() != inp.cursor()
+    }
+
+    #[inline]
+    fn fail(inp: &mut InputRef<'src, '_, I, E>) -> PResult<Emit, FloatSpans<I::Span>> {
+        let before = inp.save();
+        let found = inp.next_inner();
+        let err_span = inp.span_since(before.cursor());
+        inp.rewind(before);
+        inp.add_alt(None, found.map(|f| f.into()), err_span);
+        Err(())
+    }
+
+    fn scan(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<Emit, FloatSpans<I::Span>> {
+        let mut spans = FloatSpans {
+            int: None,
+            frac: None,
+            exp: None,
+        };
+
+        if self.inf_nan
+            && (Self::eat_keyword(inp, b"nan")
+                || (Self::eat_keyword(inp, b"inf") && {
+                    Self::eat_keyword(inp, b"inity");
+                    true
+                }))
+        {
+            return Ok(spans);
+        }
+
+        let int_start = inp.cursor();
+        if Self::eat_digits(inp) {
+            spans.int = Some(inp.span_since(&int_start));
+        } else if !self.leading_dot {
+            return Self::fail(inp);
+        }
+
+        let before_dot = inp.save();
+        if Self::eat(inp, |c| c == b'.') {
+            let frac_start = inp.cursor();
+            if Self::eat_digits(inp) {
+                spans.frac = Some(inp.span_since(&frac_start));
+            } else if spans.int.is_none() {
+                return Self::fail(inp);
+            } else if !self.trailing_dot {
+                inp.rewind(before_dot);
+            }
+        } else if spans.int.is_none() {
+            return Self::fail(inp);
+        }
+
+        if self.exponent && Self::eat(inp, |c| c == b'e' || c == b'E') {
+            let exp_start = inp.cursor();
+            Self::eat(inp, |c| c == b'+' || c == b'-');
+            if !Self::eat_digits(inp) {
+                return Self::fail(inp);
+            }
+            spans.exp = Some(inp.span_since(&exp_start));
+        }
+
+        Ok(spans)
+    }
+
+    #[inline]
+    fn go_spans<M: Mode>(
+        &self,
+        inp: &mut InputRef<'src, '_, I, E>,
+    ) -> PResult<M, (F, FloatSpans<I::Span>)> {
+        let before = inp.save();
+        let spans = self.scan(inp)?;
+        M::choose(
+            inp,
+            |inp| {
+                let slice = inp.slice_since(before.cursor()..);
+                // The scanned input is entirely ASCII, and so must be valid UTF-8
+                match core::str::from_utf8(slice.as_ref())
+                    .ok()
+                    .and_then(|s| F::from_str(s).ok())
+                {
+                    Some(value) => Ok((value, spans)),
+                    None => {
+                        let err_span = inp.span_since(before.cursor());
+                        inp.add_alt_err(
+                            &before.cursor().inner,
+                            E::Error::expected_found(None, None, err_span),
+                        );
+                        Err(())
+                    }
+                }
+            },
+            |_| Ok(()),
+        )
+    }
+}
+
+impl<'src, F, I, E> Parser<'src, I, F, E> for Float<F, I, E>
+where
+    F: FromStr,
+    I: StrInput<'src>,
+    I::Token: Char,
+    I::Slice: AsRef<[u8]>,
+    E: ParserExtra<'src, I>,
+{
+    #[inline]
+    fn go<M: Mode>(&self, inp: &mut InputRef<'src, '_, I, E>) -> PResult<M, F> {
+        self.go_spans::<M>(inp)
+            .map(|out| M::map(out, |(value, _)| value))
+    }
+
+    go_extra!(F);
+}
+
+/// See [`Float::spanned_parts`].
+pub struct SpannedFloat<F, I, E> {
+    float: Float<F, I, E>,
+}
+
+impl<F, I, E> Copy for SpannedFloat<F, I, E> {}
+impl<F, I, E> Clone for SpannedFloat<F, I, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'src, F, I, E> Parser<'src, I, (F, FloatSpans<I::Span>), E> for SpannedFloat<F, I, E>
+where
+    F: FromStr,
+    I: StrInput<'src>,
+    I::Token: Char,
+    I::Slice: AsRef<[u8]>,
+    E: ParserExtra<'src, I>,
+{
+    #[inline]
+    fn go<M: Mode>(
+        &self,
+        inp: &mut InputRef<'src, '_, I, E>,
+    ) -> PResult<M, (F, FloatSpans<I::Span>)> {
+        self.float.go_spans::<M>(inp)
+    }
+
+    go_extra!((F, FloatSpans<I::Span>));
+}
+
+/// A parser that accepts a non-negative decimal floating point number, such as `1`, `4.25`, or `6.02e23`.
+///
+/// By default, the accepted syntax is that of a JSON number without its sign: the integral digits are required, the
+/// fractional digits are optional, and an exponent is permitted. Use [`Float::leading_dot`], [`Float::trailing_dot`],
+/// [`Float::exponent`], and [`Float::inf_nan`] to adjust this, and [`Float::spanned_parts`] to get the spans of the
+/// components of the number.
+///
+/// Leading zeroes are accepted. Signs are not: use something like `just('-').or_not()` if you need them.
+///
+/// The output type of this parser is `F`, which is parsed from the accepted input with [`FromStr`].
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// let float = text::float::<f64, _, extra::Err<Simple<char>>>();
+///
+/// assert_eq!(float.parse("1").into_result(), Ok(1.0));
+/// assert_eq!(float.parse("4.25").into_result(), Ok(4.25));
+/// assert_eq!(float.parse("6.02e23").into_result(), Ok(6.02e23));
+/// assert_eq!(float.parse("1E-3").into_result(), Ok(0.001));
+/// assert!(float.parse(".5").has_errors());
+/// assert!(float.parse("5.").has_errors());
+/// assert!(float.parse("1e").has_errors());
+/// assert!(float.parse("inf").has_errors());
+///
+/// let rusty = text::float::<f32, _, extra::Err<Simple<char>>>()
+///     .leading_dot(true)
+///     .trailing_dot(true)
+///     .inf_nan(true);
+///
+/// assert_eq!(rusty.parse(".5").into_result(), Ok(0.5));
+/// assert_eq!(rusty.parse("5.").into_result(), Ok(5.0));
+/// assert_eq!(rusty.parse("Infinity").into_result(), Ok(f32::INFINITY));
+/// assert!(rusty.parse("NaN").into_result().unwrap().is_nan());
+///
+/// // Without `trailing_dot`, a `.` that isn't followed by digits is left alone
+/// let method = text::float::<f64, _, extra::Err<Simple<char>>>()
+///     .then_ignore(just(".sqrt()"));
+///
+/// assert_eq!(method.parse("4.sqrt()").into_result(), Ok(4.0));
+/// ```
+#[must_use]
+pub fn float<'src, F, I, E>() -> Float<F, I, E>
+where
+    F: FromStr,
+    I: StrInput<'src>,
+    I::Token: Char,
+    I::Slice: AsRef<[u8]>,
+    E: ParserExtra<'src, I>,
+{
+    Float {
+        leading_dot: false,
+        trailing_dot: false,
+        exponent: true,
+        inf_nan: false,
+        phantom: EmptyPhantom::new(),
+    }
+}
+
+enum Piece<'src> {
+    Run(&'src str),
+    Escaped(char),
+}
+
+/// A parser that accepts a string made up of runs of normal characters and escape sequences, decoding the escape
+/// sequences as it goes.
+///
+/// - `normal` accepts a single character that may appear unescaped. It must not accept `escape_char`.
+/// - `escape_char` begins an escape sequence, like `\`.
+/// - `escape_map` accepts the rest of an escape sequence, after `escape_char`, and produces the character that it
+///   stands for. This is often a [`choice`] of [`just`]s, but may be any parser (to handle sequences like `\u{1F600}`).
+///
+/// The output type of this parser is [`Cow<str>`](Cow). Runs of normal characters are taken from the input as
+/// slices, so when the string contains no escape sequences, the output borrows from the input and no allocation is
+/// performed. Only strings containing escape sequences are copied.
+///
+/// This parser accepts an empty string. It does not accept any delimiters, so these usually need to be added with
+/// [`Parser::delimited_by`].
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// # use std::borrow::Cow;
+/// let string = text::escaped::<_, _, _, extra::Err<Simple<char>>>(
+///     none_of("\\\""),
+///     '\\',
+///     choice((just('\\'), just('"'), just('n').to('\n'))),
+/// )
+/// .delimited_by(just('"'), just('"'));
+///
+/// assert_eq!(string.parse(r#""Mostly harmless""#).into_result(), Ok(Cow::Borrowed("Mostly harmless")));
+/// assert_eq!(string.parse(r#""Share and\nEnjoy""#).into_result(), Ok(Cow::Owned("Share and\nEnjoy".to_string())));
+/// assert_eq!(string.parse(r#""""#).into_result(), Ok(Cow::Borrowed("")));
+/// assert!(string.parse(r#""\q""#).has_errors());
+/// ```
+pub fn escaped<'src, N, NO, M, E>(
+    normal: N,
+    escape_char: char,
+    escape_map: M,
+) -> impl Parser<'src, &'src str, Cow<'src, str>, E> + Clone
+where
+    N: Parser<'src, &'src str, NO, E> + Clone,
+    M: Parser<'src, &'src str, char, E> + Clone,
+    E: ParserExtra<'src, &'src str>,
+{
+    let run = normal
+        .ignored()
+        .repeated()
+        .at_least(1)
+        .to_slice()
+        .map(Piece::Run);
+    let escape = just(escape_char)
+        .ignore_then(escape_map)
+        .map(Piece::Escaped);
+
+    empty()
+        .to(Cow::Borrowed(""))
+        .foldl(run.or(escape).repeated(), |text, piece| {
+            match (text, piece) {
+                (Cow::Borrowed(""), Piece::Run(run)) => Cow::Borrowed(run),
+                (mut text, Piece::Run(run)) => {
+                    text.to_mut().push_str(run);
+                    text
+                }
+                (mut text, Piece::Escaped(c)) => {
+                    text.to_mut().push(c);
+                    text
+                }
+            }
+        })
+}
+
+/// A parser that splits a line of text into words, following the quoting rules of POSIX shells.
+///
+/// The output type of this parser is `Vec<(Cow<str>, SimpleSpan)>`: the text of each word, with quoting removed, and
+/// the span of the input that it was parsed from. Words are borrowed from the input whenever quoting did not need to
+/// be removed from within them.
+///
+/// The rules followed are:
+///
+/// - Words are separated by unquoted whitespace.
+/// - Characters within single quotes are taken literally.
+/// - Within double quotes, a backslash only escapes `$`, `` ` ``, `"`, `\` and newlines. Other backslashes are taken
+///   literally.
+/// - Outside quotes, a backslash escapes any character.
+/// - An escaped newline is removed entirely, joining the lines on either side.
+/// - A `#` at the start of a word begins a comment, which runs to the end of the line.
+/// - Quoted and unquoted parts of a word that are not separated by whitespace are joined together, so `a"b c"'d'` is
+///   the single word `ab cd`.
+///
+/// Unterminated quotes and a backslash at the end of the input are errors. No expansion (of variables, globs, etc.)
+/// is performed.
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::prelude::*;
+/// # use std::borrow::Cow;
+/// let words = text::shell_words::<extra::Err<Simple<char>>>();
+///
+/// assert_eq!(
+///     words.parse(r#"cp "Don't Panic.txt" towel\ 2 # be prepared"#).into_result(),
+///     Ok(vec![
+///         (Cow::Borrowed("cp"), (0..2).into()),
+///         (Cow::Borrowed("Don't Panic.txt"), (3..20).into()),
+///         (Cow::Owned("towel 2".to_string()), (21..29).into()),
+///     ]),
+/// );
+/// assert!(words.parse("echo 'unterminated").has_errors());
+/// ```
+pub fn shell_words<'src, E>(
+) -> impl Parser<'src, &'src str, Vec<(Cow<'src, str>, SimpleSpan)>, E> + Clone
+where
+    E: ParserExtra<'src, &'src str>,
+{
+    let line_continuation = just("\\\n").to("");
+
+    let single_quoted = none_of('\'')
+        .repeated()
+        .to_slice()
+        .delimited_by(just('\''), just('\''));
+
+    let double_quoted = choice((
+        line_continuation,
+        just('\\').ignore_then(one_of("$`\"\\").to_slice()),
+        just('\\').to_slice(),
+        none_of("\"\\").repeated().at_least(1).to_slice(),
+    ))
+    .repeated()
+    .collect::<Cow<str>>()
+    .delimited_by(just('"'), just('"'));
+
+    let unquoted = choice((
+        line_continuation,
+        just('\\').ignore_then(any().to_slice()),
+        none_of(" \t\r\n\\'\"").repeated().at_least(1).to_slice(),
+    ));
+
+    // Each part of a word is usually a slice of the input, so words only need to be copied if they have several parts
+    let word = just('#').not().ignore_then(
+        choice((
+            single_quoted.map(Cow::Borrowed),
+            double_quoted,
+            unquoted.map(Cow::Borrowed),
+        ))
+        .repeated()
+        .at_least(1)
+        .collect::<Cow<str>>(),
+    );
+
+    let comment = just('#').then(none_of("\r\n").repeated()).ignored();
+    let separator =
+        choice((one_of(" \t\r\n").ignored(), just("\\\n").ignored(), comment)).repeated();
+
+    separator.ignore_then(
+        word.map_with(|word, e| (word, e.span()))
+            .then_ignore(separator)
+            .repeated()
+            .collect(),
+    )
+}
+
+/// Panic with a helpful message if `keyword` is not an identifier, since a keyword parser could then never match it.
+#[cfg(debug_assertions)]
+#[track_caller]
+fn validate_keyword(
+    keyword: &[u8],
+    is_start: impl Fn(char) -> bool,
+    start: &str,
+    is_continue: impl Fn(char) -> bool,
+    cont: &str,
+) {
+    let Ok(keyword) = core::str::from_utf8(keyword) else {
+        panic!("Keyword must be valid UTF-8, not {keyword:?}");
+    };
+    let mut cs = keyword.chars();
+    let Some(c) = cs.next() else {
+        panic!("Keyword must have at least one character");
+    };
+    assert!(
+        is_start(c),
+        "The first character of a keyword must be {start}, not {c:?}"
+    );
+    for c in cs {
+        assert!(
+            is_continue(c),
+            "Trailing characters of a keyword must be {cont}, not {c:?}"
+        );
+    }
+}
+
+/// Accept an identifier matching `keyword`, reporting the keyword that was expected if a different identifier is found.
+fn keyword_of<'src, I, S, E>(
+    ident: impl Parser<'src, I, <I as SliceInput<'src>>::Slice, E> + Clone + 'src,
+    keyword: S,
+) -> impl Parser<'src, I, <I as SliceInput<'src>>::Slice, E> + Clone + 'src
+where
+    I: StrInput<'src>,
+    I::Slice: PartialEq + AsRef<[u8]>,
+    I::Token: Char + 'src,
+    S: Borrow<I::Slice> + Clone + 'src,
+    E: ParserExtra<'src, I> + 'src,
+{
+    any()
+        .rewind()
+        .then(ident)
+        .try_map(move |(first, s): (I::Token, I::Slice), span| {
+            if &s == keyword.borrow() {
+                Ok(())
+            } else {
+                // Identifiers are always valid UTF-8 (even in byte inputs), so a keyword that isn't could never match
+                let keyword = core::str::from_utf8(keyword.borrow().as_ref()).unwrap_or_default();
+                Err(Error::expected_keyword_found(
+                    keyword,
+                    Some(MaybeRef::Val(first)),
+                    span,
+                ))
             }
         })
-        // This error never appears due to `repeated` so can use `filter`
-        .then(select! { c if (c as I::Token).is_digit(radix) => () }.repeated())
-        .ignored()
-        .or(just(I::Token::digit_zero()).ignored())
         .to_slice()
 }
 
@@ -415,25 +1914,83 @@ pub mod ascii {
     /// [`&[u8]`]).
     ///
     /// An identifier is defined as an ASCII alphabetic character or an underscore followed by any number of alphanumeric
-    /// characters or underscores. The regex pattern for it is `[a-zA-Z_][a-zA-Z0-9_]*`.
+    /// characters or underscores. The regex pattern for it is `[a-zA-Z_][a-zA-Z0-9_]*`. Use [`ident_with`] for other
+    /// kinds of identifier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let ident = text::ascii::ident::<_, extra::Err<Simple<char>>>();
+    ///
+    /// assert_eq!(ident.parse("foo_bar2").into_result(), Ok("foo_bar2"));
+    /// assert!(ident.parse("2foo").has_errors());
+    /// ```
     #[must_use]
     pub fn ident<'src, I, E>() -> impl Parser<'src, I, <I as SliceInput<'src>>::Slice, E> + Copy
     where
         I: StrInput<'src>,
         I::Token: Char + 'src,
         E: ParserExtra<'src, I>,
+    {
+        ident_with(
+            |c| c.is_ascii_alphabetic() || c == '_',
+            |c| c.is_ascii_alphanumeric() || c == '_',
+        )
+    }
+
+    /// Like [`ident`], but with the characters that may begin and continue the identifier decided by the given
+    /// predicates.
+    ///
+    /// The predicates are only ever given ASCII characters: other characters are never part of the identifier.
+    ///
+    /// The output type of this parser is [`SliceInput::Slice`] (i.e: [`&str`] when `I` is [`&str`], and [`&[u8]`] when `I` is
+    /// [`&[u8]`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// // Lisp allows hyphens and question marks within symbols
+    /// let symbol = text::ascii::ident_with::<_, extra::Err<Simple<char>>, _, _>(
+    ///     |c| c.is_ascii_alphabetic(),
+    ///     |c| c.is_ascii_alphanumeric() || c == '-' || c == '?',
+    /// );
+    ///
+    /// assert_eq!(symbol.parse("string-empty?").into_result(), Ok("string-empty?"));
+    /// assert!(symbol.parse("-x").has_errors());
+    ///
+    /// // ML allows primes at the end of identifiers
+    /// let ml = text::ascii::ident_with::<_, extra::Err<Simple<char>>, _, _>(
+    ///     |c| c.is_ascii_alphabetic() || c == '_',
+    ///     |c| c.is_ascii_alphanumeric() || c == '_' || c == '\'',
+    /// );
+    ///
+    /// assert_eq!(ml.parse("x''").into_result(), Ok("x''"));
+    /// ```
+    #[must_use]
+    pub fn ident_with<'src, I, E, S, C>(
+        start: S,
+        cont: C,
+    ) -> impl Parser<'src, I, <I as SliceInput<'src>>::Slice, E> + Copy
+    where
+        I: StrInput<'src>,
+        I::Token: Char + 'src,
+        E: ParserExtra<'src, I>,
+        S: Fn(char) -> bool + Copy,
+        C: Fn(char) -> bool + Copy,
     {
         any()
             // Use try_map over filter to get a better error on failure
-            .try_map(|c: I::Token, span| {
-                if c.to_ascii().map(|i| i.is_ascii_alphabetic() || i == b'_').unwrap_or(false) {
+            .try_map(move |c: I::Token, span| {
+                if c.to_ascii().map(|i| start(i as char)).unwrap_or(false) {
                     Ok(c)
                 } else {
                     Err(Error::expected_found([], Some(MaybeRef::Val(c)), span))
                 }
             })
             .then(
-                select! { c if (c as I::Token).to_ascii().map(|i| i.is_ascii_alphabetic() || i == b'_').unwrap_or(false) => () }
+                select! { c if (c as I::Token).to_ascii().map(|i| cont(i as char)).unwrap_or(false) => () }
                     .repeated(),
             )
             .to_slice()
@@ -444,6 +2001,11 @@ pub mod ascii {
     /// The output type of this parser is `I::Slice` (i.e: [`&str`] when `I` is [`&str`], and [`&[u8]`]
     /// when `I::Slice` is [`&[u8]`]).
     ///
+    /// # Panics
+    ///
+    /// With debug assertions enabled, this panics if `keyword` is not itself an identifier, since it could then never
+    /// be matched.
+    ///
     /// # Examples
     ///
     /// ```
@@ -457,6 +2019,12 @@ pub mod ascii {
     /// assert_eq!(def.clone().lazy().parse("def(foo, bar)").into_result(), Ok("def"));
     /// // 'def' was found, but only as part of a larger identifier, so this fails to parse
     /// assert!(def.lazy().parse("define").has_errors());
+    ///
+    /// // Errors report the keyword that was expected, spanning the identifier that was found instead
+    /// let def = text::ascii::keyword::<_, _, extra::Err<Rich<char>>>("def");
+    /// let errs = def.parse("define").into_errors();
+    /// assert_eq!(errs[0].span(), &(0..6).into());
+    /// assert_eq!(errs[0].to_string(), "found d expected keyword `def`");
     /// ```
     #[track_caller]
     pub fn keyword<'src, I, S, E>(
@@ -464,36 +2032,20 @@ pub mod ascii {
     ) -> impl Parser<'src, I, <I as SliceInput<'src>>::Slice, E> + Clone + 'src
     where
         I: StrInput<'src>,
-        I::Slice: PartialEq,
+        I::Slice: PartialEq + AsRef<[u8]>,
         I::Token: Char + fmt::Debug + 'src,
         S: Borrow<I::Slice> + Clone + 'src,
         E: ParserExtra<'src, I> + 'src,
     {
-        /*
         #[cfg(debug_assertions)]
-        {
-            let mut cs = keyword.seq_iter();
-            if let Some(c) = cs.next() {
-                let c = c.borrow().to_char();
-                assert!(c.is_ascii_alphabetic() || c == '_', "The first character of a keyword must be ASCII alphabetic or an underscore, not {:?}", c);
-            } else {
-                panic!("Keyword must have at least one character");
-            }
-            for c in cs {
-                let c = c.borrow().to_char();
-                assert!(c.is_ascii_alphanumeric() || c == '_', "Trailing characters of a keyword must be ASCII alphanumeric or an underscore, not {:?}", c);
-            }
-        }
-        */
-        ident()
-            .try_map(move |s: I::Slice, span| {
-                if &s == keyword.borrow() {
-                    Ok(())
-                } else {
-                    Err(Error::expected_found(None, None, span))
-                }
-            })
-            .to_slice()
+        validate_keyword(
+            keyword.borrow().as_ref(),
+            |c| c.is_ascii_alphabetic() || c == '_',
+            "ASCII alphabetic or an underscore",
+            |c| c.is_ascii_alphanumeric() || c == '_',
+            "ASCII alphanumeric or an underscore",
+        );
+        keyword_of(ident(), keyword)
     }
 }
 
@@ -526,12 +2078,12 @@ pub mod unicode {
         }
 
         /// Gets an iterator over code points.
-        pub fn code_points(&self) -> Chars {
+        pub fn code_points(&self) -> Chars<'_> {
             self.inner.chars()
         }
 
         /// Gets an iterator over bytes.
-        pub fn bytes(&self) -> Bytes {
+        pub fn bytes(&self) -> Bytes<'_> {
             self.inner.bytes()
         }
 
@@ -586,17 +2138,17 @@ pub mod unicode {
         }
 
         /// Gets an iterator over graphemes.
-        pub fn iter(&self) -> GraphemesIter {
+        pub fn iter(&self) -> GraphemesIter<'_> {
             self.into_iter()
         }
 
         /// Gets an iterator over code points.
-        pub fn code_points(&self) -> Chars {
+        pub fn code_points(&self) -> Chars<'_> {
             self.inner.chars()
         }
 
         /// Gets an iterator over bytes.
-        pub fn bytes(&self) -> Bytes {
+        pub fn bytes(&self) -> Bytes<'_> {
             self.inner.bytes()
         }
 
@@ -677,7 +2229,16 @@ pub mod unicode {
     }
 
     impl Sealed for &'_ Graphemes {}
-    impl<'src> StrInput<'src> for &'src Graphemes {}
+    impl<'src> StrInput<'src> for &'src Graphemes {
+        #[inline]
+        unsafe fn line_start(cache: &mut Self::Cache, cursor: usize) -> usize {
+            cache.as_str()[..cursor]
+                .grapheme_indices(true)
+                .rev()
+                .find(|(_, g)| Grapheme::new(g).is_newline())
+                .map_or(0, |(i, g)| i + g.len())
+        }
+    }
 
     impl<'src> Input<'src> for &'src Graphemes {
         type Cursor = usize;
@@ -807,29 +2368,129 @@ pub mod unicode {
         }
     }
 
+    /// Rules deciding which characters may appear in an identifier. See [`ident_with_policy`].
+    ///
+    /// This is mostly useful for inputs of [`Graphemes`], where a single token may contain several code points.
+    pub trait IdentPolicy<C> {
+        /// Returns true if `c` may begin an identifier.
+        fn is_ident_start(&self, c: &C) -> bool;
+
+        /// Returns true if `c` may appear in an identifier after its first character.
+        fn is_ident_continue(&self, c: &C) -> bool;
+    }
+
+    /// The default identifier policy, following [`Char::is_ident_start`] and [`Char::is_ident_continue`].
+    ///
+    /// For grapheme clusters, only the first code point of the cluster is checked against `XID_Start` or
+    /// `XID_Continue`, so identifiers may contain characters with any combining marks attached.
+    #[derive(Copy, Clone, Debug, Default)]
+    pub struct Xid;
+
+    impl<C: Char> IdentPolicy<C> for Xid {
+        fn is_ident_start(&self, c: &C) -> bool {
+            c.is_ident_start()
+        }
+
+        fn is_ident_continue(&self, c: &C) -> bool {
+            c.is_ident_continue()
+        }
+    }
+
+    /// An identifier policy that requires every code point of a grapheme cluster to be valid in an identifier.
+    ///
+    /// The first code point of a cluster that begins an identifier must be `XID_Start` (or `_`), and every other code
+    /// point must be `XID_Continue`. This rejects clusters with characters that aren't otherwise valid in identifiers
+    /// attached to them, like enclosing marks (such as `\u{20DD}`, which draws a circle around the preceding
+    /// character).
+    #[derive(Copy, Clone, Debug, Default)]
+    pub struct StrictXid;
+
+    impl IdentPolicy<&Grapheme> for StrictXid {
+        fn is_ident_start(&self, c: &&Grapheme) -> bool {
+            let (first, rest) = c.split();
+            (unicode_ident::is_xid_start(first) || first == '_')
+                && rest.chars().all(unicode_ident::is_xid_continue)
+        }
+
+        fn is_ident_continue(&self, c: &&Grapheme) -> bool {
+            c.code_points().all(unicode_ident::is_xid_continue)
+        }
+    }
+
     /// A parser that accepts an identifier.
     ///
     /// The output type of this parser is [`SliceInput::Slice`] (i.e: [`&str`] when `I` is [`&str`], and [`&[u8]`] when `I` is
     /// [`&[u8]`]).
     ///
     /// An identifier is defined as per "Default Identifiers" in [Unicode Standard Annex #31](https://www.unicode.org/reports/tr31/).
+    /// Use [`ident_with_policy`] to change which characters are accepted.
     #[must_use]
     pub fn ident<'src, I, E>() -> impl Parser<'src, I, <I as SliceInput<'src>>::Slice, E> + Copy
     where
         I: StrInput<'src>,
         I::Token: Char + 'src,
         E: ParserExtra<'src, I>,
+    {
+        ident_with_policy(Xid)
+    }
+
+    /// Like [`ident`], but with the characters that may appear in the identifier decided by the given
+    /// [`IdentPolicy`].
+    ///
+    /// The output type of this parser is [`SliceInput::Slice`] (i.e: [`&str`] when `I` is [`&str`], and [`&[u8]`] when `I` is
+    /// [`&[u8]`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::{prelude::*, text::{Grapheme, Graphemes, IdentPolicy, StrictXid}};
+    /// // Allow emoji, including sequences joined with a zero-width joiner, anywhere in an identifier
+    /// #[derive(Copy, Clone)]
+    /// struct Emoji;
+    ///
+    /// impl IdentPolicy<&Grapheme> for Emoji {
+    ///     fn is_ident_start(&self, c: &&Grapheme) -> bool {
+    ///         c.split().0 >= '\u{1F300}' || text::Char::is_ident_start(c)
+    ///     }
+    ///
+    ///     fn is_ident_continue(&self, c: &&Grapheme) -> bool {
+    ///         c.split().0 >= '\u{1F300}' || text::Char::is_ident_continue(c)
+    ///     }
+    /// }
+    ///
+    /// let fam = Graphemes::new("👩\u{200D}👧_count");
+    /// let emoji = text::ident_with_policy::<_, _, extra::Err<Simple<&Grapheme>>>(Emoji);
+    /// assert_eq!(emoji.parse(fam).into_result().map(|s| s.as_str()), Ok("👩\u{200D}👧_count"));
+    /// assert!(text::ident::<_, extra::Err<Simple<&Grapheme>>>().parse(fam).has_errors());
+    ///
+    /// // By default, a cluster is accepted if its first code point is, so `e` with a combining acute accent is fine
+    /// let decomposed = Graphemes::new("cafe\u{301}");
+    /// assert!(text::ident::<_, extra::Err<Simple<&Grapheme>>>().parse(decomposed).into_result().is_ok());
+    /// // ...whereas `StrictXid` requires every code point of the cluster to be valid in an identifier
+    /// let strict = text::ident_with_policy::<_, _, extra::Err<Simple<&Grapheme>>>(StrictXid);
+    /// assert!(strict.parse(Graphemes::new("cafe\u{301}")).into_result().is_ok());
+    /// assert!(strict.parse(Graphemes::new("a\u{20DD}")).has_errors());
+    /// ```
+    #[must_use]
+    pub fn ident_with_policy<'src, P, I, E>(
+        policy: P,
+    ) -> impl Parser<'src, I, <I as SliceInput<'src>>::Slice, E> + Copy
+    where
+        P: IdentPolicy<I::Token> + Copy,
+        I: StrInput<'src>,
+        I::Token: Char + 'src,
+        E: ParserExtra<'src, I>,
     {
         any()
             // Use try_map over filter to get a better error on failure
-            .try_map(|c: I::Token, span| {
-                if c.is_ident_start() {
+            .try_map(move |c: I::Token, span| {
+                if policy.is_ident_start(&c) {
                     Ok(c)
                 } else {
                     Err(Error::expected_found([], Some(MaybeRef::Val(c)), span))
                 }
             })
-            .then(select! { c if (c as I::Token).is_ident_continue() => () }.repeated())
+            .then(select! { c if policy.is_ident_continue(&c) => () }.repeated())
             .to_slice()
     }
 
@@ -838,6 +2499,11 @@ pub mod unicode {
     /// The output type of this parser is `I::Slice` (i.e: [`&str`] when `I` is [`&str`], and [`&[u8]`]
     /// when `I::Slice` is [`&[u8]`]).
     ///
+    /// # Panics
+    ///
+    /// With debug assertions enabled, this panics if `keyword` is not itself an identifier, since it could then never
+    /// be matched.
+    ///
     /// # Examples
     ///
     /// ```
@@ -858,40 +2524,145 @@ pub mod unicode {
     ) -> impl Parser<'src, I, <I as SliceInput<'src>>::Slice, E> + Clone + 'src
     where
         I: StrInput<'src>,
-        I::Slice: PartialEq,
+        I::Slice: PartialEq + AsRef<[u8]>,
         I::Token: Char + fmt::Debug + 'src,
         S: Borrow<I::Slice> + Clone + 'src,
         E: ParserExtra<'src, I> + 'src,
     {
-        /*
         #[cfg(debug_assertions)]
-        {
-            let mut cs = keyword.seq_iter();
-            if let Some(c) = cs.next() {
-                let c = c.borrow();
-                assert!(
-                    c.is_ident_start(),
-                    "The first character of a keyword must be a valid unicode XID_START, not {:?}",
-                    c
-                );
+        validate_keyword(
+            keyword.borrow().as_ref(),
+            |c| unicode_ident::is_xid_start(c) || c == '_',
+            "a valid unicode XID_START or an underscore",
+            unicode_ident::is_xid_continue,
+            "valid as unicode XID_CONTINUE",
+        );
+        keyword_of(ident(), keyword)
+    }
+
+    #[cfg(feature = "unicode-categories")]
+    pub use unicode_general_category::GeneralCategory;
+
+    /// A parser that accepts a single `char` satisfying the given predicate, reporting the token it found otherwise.
+    fn class<'src, I, E>(pred: impl Fn(char) -> bool + Copy) -> impl Parser<'src, I, char, E> + Copy
+    where
+        I: ValueInput<'src, Token = char>,
+        E: ParserExtra<'src, I>,
+    {
+        any().try_map(move |c: char, span| {
+            if pred(c) {
+                Ok(c)
             } else {
-                panic!("Keyword must have at least one character");
-            }
-            for c in cs {
-                let c = c.borrow();
-                assert!(c.is_ident_continue(), "Trailing characters of a keyword must be valid as unicode XID_CONTINUE, not {:?}", c);
+                Err(Error::expected_found([], Some(MaybeRef::Val(c)), span))
             }
-        }
-        */
-        ident()
-            .try_map(move |s: I::Slice, span| {
-                if &s == keyword.borrow() {
-                    Ok(())
-                } else {
-                    Err(Error::expected_found(None, None, span))
-                }
-            })
-            .to_slice()
+        })
+    }
+
+    /// A parser that accepts a single character with the Unicode `Alphabetic` property (see [`char::is_alphabetic`]).
+    ///
+    /// The output type of this parser is [`char`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let alphabetic = text::alphabetic::<_, extra::Err<Simple<char>>>();
+    ///
+    /// assert_eq!(alphabetic.parse("ж").into_result(), Ok('ж'));
+    /// assert_eq!(alphabetic.parse("Ⅻ").into_result(), Ok('Ⅻ'));
+    /// assert!(alphabetic.parse("7").has_errors());
+    /// ```
+    #[must_use]
+    pub fn alphabetic<'src, I, E>() -> impl Parser<'src, I, char, E> + Copy
+    where
+        I: ValueInput<'src, Token = char>,
+        E: ParserExtra<'src, I>,
+    {
+        class(char::is_alphabetic)
+    }
+
+    /// A parser that accepts a single character with the Unicode `Alphabetic` property or the `Numeric_Type` property
+    /// (see [`char::is_alphanumeric`]).
+    ///
+    /// The output type of this parser is [`char`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let alphanumeric = text::alphanumeric::<_, extra::Err<Simple<char>>>();
+    ///
+    /// assert_eq!(alphanumeric.parse("é").into_result(), Ok('é'));
+    /// assert_eq!(alphanumeric.parse("٣").into_result(), Ok('٣'));
+    /// assert!(alphanumeric.parse("_").has_errors());
+    /// ```
+    #[must_use]
+    pub fn alphanumeric<'src, I, E>() -> impl Parser<'src, I, char, E> + Copy
+    where
+        I: ValueInput<'src, Token = char>,
+        E: ParserExtra<'src, I>,
+    {
+        class(char::is_alphanumeric)
+    }
+
+    /// A parser that accepts a single character in one of the Unicode letter general categories (`Lu`, `Ll`, `Lt`,
+    /// `Lm`, and `Lo`).
+    ///
+    /// Unlike [`alphabetic`], this does not accept letter-like numbers (such as `Ⅻ`) or marks.
+    ///
+    /// The output type of this parser is [`char`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::prelude::*;
+    /// let letter = text::letter::<_, extra::Err<Simple<char>>>();
+    ///
+    /// assert_eq!(letter.parse("ǅ").into_result(), Ok('ǅ'));
+    /// assert!(letter.parse("Ⅻ").has_errors());
+    /// ```
+    #[cfg(feature = "unicode-categories")]
+    #[must_use]
+    pub fn letter<'src, I, E>() -> impl Parser<'src, I, char, E> + Copy
+    where
+        I: ValueInput<'src, Token = char>,
+        E: ParserExtra<'src, I>,
+    {
+        class(|c| {
+            matches!(
+                unicode_general_category::get_general_category(c),
+                GeneralCategory::UppercaseLetter
+                    | GeneralCategory::LowercaseLetter
+                    | GeneralCategory::TitlecaseLetter
+                    | GeneralCategory::ModifierLetter
+                    | GeneralCategory::OtherLetter
+            )
+        })
+    }
+
+    /// A parser that accepts a single character in the given Unicode [`GeneralCategory`].
+    ///
+    /// The output type of this parser is [`char`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chumsky::{prelude::*, text::GeneralCategory};
+    /// let currency = text::category::<_, extra::Err<Simple<char>>>(GeneralCategory::CurrencySymbol);
+    /// let price = currency.then(text::int(10));
+    ///
+    /// assert_eq!(price.parse("€20").into_result(), Ok(('€', "20")));
+    /// assert!(price.parse("£").has_errors());
+    /// assert!(price.parse("20").has_errors());
+    /// ```
+    #[cfg(feature = "unicode-categories")]
+    #[must_use]
+    pub fn category<'src, I, E>(category: GeneralCategory) -> impl Parser<'src, I, char, E> + Copy
+    where
+        I: ValueInput<'src, Token = char>,
+        E: ParserExtra<'src, I>,
+    {
+        class(move |c| unicode_general_category::get_general_category(c) == category)
     }
 }
 
@@ -905,7 +2676,7 @@ mod tests {
     fn make_ascii_kw_parser<'src, I>(s: I::Slice) -> impl Parser<'src, I, ()>
     where
         I: crate::StrInput<'src>,
-        I::Slice: PartialEq + Clone,
+        I::Slice: PartialEq + AsRef<[u8]> + Clone,
         I::Token: crate::Char + fmt::Debug + 'src,
     {
         text::ascii::keyword(s).ignored()
@@ -914,29 +2685,20 @@ mod tests {
     fn make_unicode_kw_parser<'src, I>(s: I::Slice) -> impl Parser<'src, I, ()>
     where
         I: crate::StrInput<'src>,
-        I::Slice: PartialEq + Clone,
+        I::Slice: PartialEq + AsRef<[u8]> + Clone,
         I::Token: crate::Char + fmt::Debug + 'src,
     {
         text::unicode::keyword(s).ignored()
     }
 
     fn test_ok<'src, P: Parser<'src, &'src str, &'src str>>(parser: P, input: &'src str) {
-        assert_eq!(
-            parser.parse(input),
-            ParseResult {
-                output: Some(input),
-                errs: vec![]
-            }
-        );
+        assert_eq!(parser.parse(input), ParseResult::new(Some(input), vec![]));
     }
 
     fn test_err<'src, P: Parser<'src, &'src str, &'src str>>(parser: P, input: &'src str) {
         assert_eq!(
-            parser.parse(input),
-            ParseResult {
-                output: None,
-                errs: vec![EmptyErr::default()]
-            }
+            parser.parse(input).into_output_errors(),
+            (None, vec![EmptyErr::default()])
         );
     }
 
@@ -964,31 +2726,353 @@ mod tests {
         test_err(ident, "");
         test_err(ident, ".");
         test_err(ident, "123");
+
+        let ascii_ident = text::ascii::ident::<&str, extra::Default>();
+        test_ok(ascii_ident, "foo1");
+        test_ok(ascii_ident, "_42");
+        test_ok(ascii_ident, "a1b2");
+        test_err(ascii_ident, "1foo");
+        test_err(ascii_ident, "foo-bar");
     }
 
-    /*
+    // Keywords are only validated in debug builds
+    #[cfg(debug_assertions)]
     #[test]
     #[should_panic]
     fn keyword_numeric() {
         make_ascii_kw_parser::<&str>("42");
     }
 
+    #[cfg(debug_assertions)]
     #[test]
     #[should_panic]
     fn keyword_empty() {
         make_ascii_kw_parser::<&str>("");
     }
 
+    #[cfg(debug_assertions)]
     #[test]
     #[should_panic]
     fn keyword_not_alphanum() {
         make_ascii_kw_parser::<&str>("hi\n");
     }
 
+    #[cfg(debug_assertions)]
     #[test]
     #[should_panic]
     fn keyword_unicode_in_ascii() {
         make_ascii_kw_parser::<&str>("שלום");
     }
-    */
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic]
+    fn keyword_unicode_not_ident() {
+        make_unicode_kw_parser::<&str>("a-b");
+    }
+
+    #[test]
+    fn byte_inputs() {
+        type Err = extra::Err<Simple<'static, u8>>;
+
+        let ws = text::whitespace::<&[u8], Err>();
+        assert_eq!(ws.parse(b" \t\r\n\x0B\x0C").into_result(), Ok(()));
+        let inline_ws = text::inline_whitespace::<&[u8], Err>();
+        assert!(inline_ws.parse(b" \n").has_errors());
+
+        let digits = text::digits::<&[u8], Err>(16).to_slice();
+        assert_eq!(
+            digits.parse(b"00fF".as_slice()).into_result(),
+            Ok(b"00fF".as_slice())
+        );
+        let int = text::int::<&[u8], Err>(10);
+        assert_eq!(
+            int.parse(b"1452".as_slice()).into_result(),
+            Ok(b"1452".as_slice())
+        );
+        assert!(int.parse(b"04".as_slice()).has_errors());
+
+        let ascii_ident = text::ascii::ident::<&[u8], Err>();
+        assert_eq!(
+            ascii_ident.parse(b"_foo".as_slice()).into_result(),
+            Ok(b"_foo".as_slice())
+        );
+        let ident = text::unicode::ident::<&[u8], Err>();
+        assert_eq!(
+            ident.parse(b"foo_1".as_slice()).into_result(),
+            Ok(b"foo_1".as_slice())
+        );
+        // Non-ASCII bytes (here, UTF-8 for 'é') are never part of an identifier
+        assert!(ident.parse("é".as_bytes()).has_errors());
+        assert!(ident.parse("aé".as_bytes()).has_errors());
+
+        let kw = text::ascii::keyword::<&[u8], _, Err>(b"GET".as_slice());
+        assert_eq!(
+            kw.clone()
+                .then_ignore(just(b' '))
+                .parse(b"GET ".as_slice())
+                .into_result(),
+            Ok(b"GET".as_slice())
+        );
+        assert!(kw.parse(b"GETS".as_slice()).has_errors());
+        let kw = text::unicode::keyword::<&[u8], _, Err>(b"HTTP".as_slice());
+        assert!(kw.parse(b"HTTP".as_slice()).into_result().is_ok());
+    }
+
+    #[test]
+    fn digits_to_int() {
+        type Err = extra::Err<Simple<'static, char>>;
+
+        let int = text::digits::<&str, Err>(10).to_int::<i8>();
+        assert_eq!(int.parse("127").into_result(), Ok(127));
+        assert!(int.parse("128").has_errors());
+        assert!(int.parse("").has_errors());
+
+        let octets = text::digits::<&str, Err>(10)
+            .at_most(3)
+            .to_int::<u8>()
+            .separated_by(just('.'))
+            .exactly(4)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            octets.parse("192.168.0.1").into_result(),
+            Ok(vec![192, 168, 0, 1])
+        );
+        assert!(octets.parse("192.168.0.1000").has_errors());
+        assert!(octets.parse("192.168.0.300").has_errors());
+
+        let bytes = text::digits::<&[u8], extra::Err<Simple<'static, u8>>>(2).to_int::<u64>();
+        assert_eq!(bytes.parse(b"1011".as_slice()).into_result(), Ok(0b1011));
+    }
+
+    #[test]
+    fn signed_int_underscores() {
+        type Err = extra::Err<Simple<'static, char>>;
+
+        let int = text::signed_int::<&str, Err>(10).underscores(true);
+        assert_eq!(int.parse("-1_0__0").into_result(), Ok("-1_0__0"));
+        assert_eq!(int.parse("+0").into_result(), Ok("+0"));
+        assert!(int.parse("0_1").has_errors());
+        assert!(int.parse("+_1").has_errors());
+        assert_eq!(
+            int.then(just("__").to_slice()).parse("12__").into_result(),
+            Ok(("12", "__"))
+        );
+        // Without underscores enabled, the separator ends the integer
+        let plain = text::signed_int::<&str, Err>(10);
+        assert_eq!(
+            plain
+                .then(any().repeated().to_slice())
+                .parse("1_0")
+                .into_result(),
+            Ok(("1", "_0"))
+        );
+
+        let value = text::signed_int::<&str, Err>(16)
+            .underscores(true)
+            .to_int::<i8>();
+        assert_eq!(value.parse("-8_0").into_result(), Ok(i8::MIN));
+        assert_eq!(value.parse("7_f").into_result(), Ok(i8::MAX));
+        assert!(value.parse("8_0").has_errors());
+        assert!(value.parse("-8_1").has_errors());
+
+        let bytes = text::int::<&[u8], extra::Err<Simple<'static, u8>>>(10)
+            .underscores(true)
+            .to_int::<u32>();
+        assert_eq!(bytes.parse(b"65_535".as_slice()).into_result(), Ok(65_535));
+    }
+
+    #[test]
+    fn int_errors() {
+        let int = text::int::<&str, extra::Err<Rich<'static, char>>>(10);
+        assert_eq!(
+            int.parse("x").into_errors()[0].to_string(),
+            "found x expected '0'"
+        );
+
+        // The digit after the zero is reported as unexpected
+        let errs = text::int::<&str, extra::Err<Simple<'static, char>>>(10)
+            .parse("007")
+            .into_errors();
+        assert_eq!(errs[0].found(), Some(&'0'));
+        assert_eq!(errs[0].span(), &(0..3).into());
+        assert!(!errs[0].is_incomplete());
+    }
+
+    #[test]
+    fn whitespace_at_least() {
+        let ws = text::whitespace::<&str, extra::Err<Simple<char>>>().at_least(2);
+        assert_eq!(
+            ws.then(just('a')).parse(" \n a").into_result(),
+            Ok(((), 'a'))
+        );
+        let errs = ws.then(just('a')).parse(" a").into_errors();
+        assert_eq!(errs[0].span(), &SimpleSpan::new(1, 2));
+        assert_eq!(errs[0].found(), Some(&'a'));
+        assert!(ws.count().parse(" ").has_errors());
+
+        let inline = text::inline_whitespace::<&[u8], extra::Err<Simple<u8>>>();
+        assert_eq!(
+            inline
+                .count()
+                .then(any())
+                .parse(b" \t\n".as_slice())
+                .into_result(),
+            Ok((2, b'\n'))
+        );
+    }
+
+    #[test]
+    fn newline_token_lookahead() {
+        let newlines = text::newline::<&[u8], extra::Err<Simple<u8>>>()
+            .repeated()
+            .count();
+        assert_eq!(
+            newlines.parse(b"\r\n\r\r\n\n".as_slice()).into_result(),
+            Ok(4)
+        );
+        assert!(newlines.parse(b"\n\r\n ".as_slice()).has_errors());
+
+        let lines = text::lines(text::rest_of_line::<&[u8], extra::Err<Simple<u8>>>());
+        assert_eq!(
+            lines
+                .collect::<Vec<_>>()
+                .parse(b"a\r\nb\rc".as_slice())
+                .into_result(),
+            Ok(vec![b"a".as_slice(), b"b", b"c"])
+        );
+    }
+
+    #[test]
+    fn word_boundary_edges() {
+        let kw = just::<_, _, extra::Err<Rich<char>>>("if").then_ignore(text::word_boundary());
+        assert_eq!(kw.parse("if").into_result(), Ok("if"));
+        assert_eq!(kw.lazy().parse("if x").into_result(), Ok("if"));
+        assert!(kw.lazy().parse("if_").has_errors());
+        assert!(kw.lazy().parse("if2").has_errors());
+
+        let errs = kw.lazy().parse("iffy").into_errors();
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs[0].span(), &SimpleSpan::from(2..3));
+
+        let byte_kw =
+            just::<_, _, extra::Default>(b"if".as_slice()).then_ignore(text::word_boundary());
+        assert!(byte_kw
+            .lazy()
+            .parse(b"if(".as_slice())
+            .into_result()
+            .is_ok());
+        assert!(byte_kw.lazy().parse(b"ifa".as_slice()).has_errors());
+    }
+
+    #[test]
+    fn float_variants() {
+        type Err = extra::Err<Simple<'static, u8>>;
+
+        let float = text::float::<f64, &[u8], Err>()
+            .leading_dot(true)
+            .spanned_parts();
+        assert_eq!(
+            float.parse(b".5E+2".as_slice()).into_result(),
+            Ok((
+                50.0,
+                text::FloatSpans {
+                    int: None,
+                    frac: Some((1..2).into()),
+                    exp: Some((3..5).into()),
+                }
+            ))
+        );
+        assert!(float.parse(b".".as_slice()).has_errors());
+
+        let no_exp = text::float::<f64, &[u8], Err>().exponent(false);
+        assert!(no_exp.parse(b"1e5".as_slice()).has_errors());
+        assert!(no_exp.parse(b"inf".as_slice()).has_errors());
+        assert_eq!(no_exp.parse(b"007.25".as_slice()).into_result(), Ok(7.25));
+    }
+
+    #[test]
+    fn shell_words_quoting() {
+        let words = |src| {
+            text::shell_words::<extra::Err<Simple<char>>>()
+                .parse(src)
+                .into_result()
+                .map(|words| {
+                    words
+                        .into_iter()
+                        .map(|(word, _)| word.into_owned())
+                        .collect::<Vec<_>>()
+                })
+        };
+
+        assert_eq!(words(""), Ok(vec![]));
+        assert_eq!(words("  # only a comment"), Ok(vec![]));
+        assert_eq!(words("a\"b c\"'d'"), Ok(vec!["ab cd".to_string()]));
+        assert_eq!(words("'' \"\""), Ok(vec![String::new(), String::new()]));
+        assert_eq!(words("a#b #c"), Ok(vec!["a#b".to_string()]));
+        assert_eq!(
+            words(r#""\$ \a \\" '\n'"#),
+            Ok(vec![r"$ \a \".to_string(), r"\n".to_string()])
+        );
+        assert_eq!(
+            words("one\\\ntwo \\\n three"),
+            Ok(vec!["onetwo".to_string(), "three".to_string()])
+        );
+        assert!(words("trailing\\").is_err());
+        assert!(words("\"open").is_err());
+    }
+
+    #[test]
+    fn lines_newline_variants() {
+        let lines = text::lines::<_, _, extra::Err<Simple<char>>, _>(text::rest_of_line())
+            .collect::<Vec<_>>();
+
+        assert_eq!(lines.parse("").into_result(), Ok(vec![]));
+        assert_eq!(lines.parse("\n").into_result(), Ok(vec![""]));
+        assert_eq!(
+            lines
+                .parse("a\r\nb\rc\x0Bd\u{2028}e\u{2029}\n")
+                .into_result(),
+            Ok(vec!["a", "b", "c", "d", "e", ""]),
+        );
+        assert_eq!(
+            text::rest_of_line::<_, extra::Err<Simple<u8>>>()
+                .lazy()
+                .parse(b"bytes\r\n".as_slice())
+                .into_result(),
+            Ok(b"bytes".as_slice()),
+        );
+    }
+
+    #[test]
+    fn offside_nested_blocks() {
+        #[derive(Debug, PartialEq)]
+        struct Node<'src>(&'src str, Vec<Node<'src>>);
+
+        let tree = recursive(|tree| {
+            text::offside(text::ascii::ident::<_, extra::Err<Simple<char>>>(), tree)
+                .with_tab_width(4)
+                .map(|(name, children)| Node(name, children))
+        });
+        let forest = tree.padded().repeated().collect::<Vec<_>>();
+
+        assert_eq!(
+            forest.parse("a\n  b\n\tc\n  d\r\ne f\n  g").into_result(),
+            Ok(vec![
+                Node(
+                    "a",
+                    vec![Node("b", vec![Node("c", vec![])]), Node("d", vec![])]
+                ),
+                // `g` is indented past `e`, but not past `f`
+                Node("e", vec![Node("f", vec![]), Node("g", vec![])]),
+            ]),
+        );
+        // Items of a block need not be aligned with each other
+        assert_eq!(
+            forest.parse("a\n  b\n c").into_result(),
+            Ok(vec![Node("a", vec![Node("b", vec![]), Node("c", vec![])])]),
+        );
+        // Anything indented past the header must be a valid item
+        assert!(forest.parse("a\n  b\n  1").has_errors());
+    }
 }
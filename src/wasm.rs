@@ -0,0 +1,117 @@
+//! A small, non-generic API surface for exposing parsers to JavaScript, such as in web-based grammar playgrounds.
+//!
+//! *“Space is big. Really big. You just won't believe how vastly hugely mind-bogglingly big it is.”*
+//!
+//! Tools like `wasm-bindgen` cannot export generic functions or types with lifetimes, so this module provides plain,
+//! non-generic data types that the result of a parse can be converted into with [`parse`].
+//!
+//! Chumsky does not depend on `wasm-bindgen`, so these types are not `#[wasm_bindgen]` types themselves. Instead,
+//! enable the `serde` feature and pass them to JavaScript with a crate such as `serde-wasm-bindgen`, from within a
+//! non-generic function of your own:
+//!
+//! ```ignore
+//! #[wasm_bindgen]
+//! pub fn check(src: &str) -> Result<JsValue, serde_wasm_bindgen::Error> {
+//!     serde_wasm_bindgen::to_value(&chumsky::wasm::parse(&my_parser(), src))
+//! }
+//! ```
+//!
+//! Chumsky itself does not depend on threads, clocks, or any other part of the platform, so it builds for
+//! `wasm32-unknown-unknown` with or without its default features.
+
+use super::*;
+use alloc::{format, string::ToString};
+
+/// A parse error or warning, with its location given as offsets into a JavaScript string.
+///
+/// JavaScript strings are indexed by UTF-16 code unit, so [`Diagnostic::start`] and [`Diagnostic::end`] can be used
+/// with methods like `String.prototype.slice` directly.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// The UTF-16 offset at which the problem starts.
+    pub start: usize,
+    /// The UTF-16 offset at which the problem ends.
+    pub end: usize,
+    /// The severity of the problem.
+    pub severity: Severity,
+    /// A human-readable description of the problem.
+    pub message: String,
+}
+
+/// The outcome of a parse: a debug rendering of the output, if there was one, and any diagnostics produced.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Outcome {
+    /// The output of the parser, formatted with [`Debug`](fmt::Debug), if the parser produced one.
+    pub output: Option<String>,
+    /// Errors, followed by warnings and hints, in the order that they were produced.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl Outcome {
+    /// Convert the result of parsing `src` into an [`Outcome`].
+    pub fn new<T, L>(src: &str, result: ParseResult<T, Rich<'_, char, SimpleSpan, L>>) -> Self
+    where
+        T: fmt::Debug,
+        L: fmt::Display,
+    {
+        let diagnostic = |severity, err: &Rich<'_, char, SimpleSpan, L>| Diagnostic {
+            start: utf16_offset(src, err.span().start),
+            end: utf16_offset(src, err.span().end),
+            severity,
+            message: err.reason().to_string(),
+        };
+        let diagnostics = result
+            .errors()
+            .map(|err| diagnostic(Severity::Error, err))
+            .chain(
+                result
+                    .warnings()
+                    .map(|(severity, err)| diagnostic(severity, err)),
+            )
+            .collect();
+        Self {
+            output: result.output().map(|out| format!("{out:?}")),
+            diagnostics,
+        }
+    }
+
+    /// Returns `true` if parsing produced no errors. Warnings and hints are permitted.
+    pub fn is_ok(&self) -> bool {
+        self.diagnostics
+            .iter()
+            .all(|diag| diag.severity != Severity::Error)
+    }
+}
+
+/// Parse `src` with the given parser, producing an [`Outcome`].
+///
+/// # Examples
+///
+/// ```
+/// # use chumsky::{prelude::*, wasm};
+/// let greeting = just::<_, _, extra::Err<Rich<char>>>("héllo ")
+///     .ignore_then(text::ascii::ident());
+///
+/// let outcome = wasm::parse(&greeting, "héllo world");
+/// assert_eq!(outcome.output.as_deref(), Some("\"world\""));
+///
+/// let outcome = wasm::parse(&greeting, "héllo !");
+/// assert!(!outcome.is_ok());
+/// // `é` is two bytes long, but only one UTF-16 code unit
+/// assert_eq!(outcome.diagnostics[0].start, 6);
+/// ```
+pub fn parse<'src, P, O>(parser: &P, src: &'src str) -> Outcome
+where
+    P: Parser<'src, &'src str, O, extra::Err<Rich<'src, char>>>,
+    O: fmt::Debug,
+{
+    Outcome::new(src, parser.parse(src))
+}
+
+// Convert a byte offset into `src` into a UTF-16 offset, clamping offsets beyond the end of the string.
+fn utf16_offset(src: &str, byte_offset: usize) -> usize {
+    src.get(..byte_offset.min(src.len()))
+        .map_or(0, |prefix| prefix.encode_utf16().count())
+}